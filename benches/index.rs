@@ -0,0 +1,51 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use decorum::R64;
+use nalgebra::Point3;
+use plexus::index::{Flat3, HashLruIndexer, LruIndexer};
+use plexus::prelude::*;
+use plexus::primitive::generate::Position;
+use plexus::primitive::sphere::UvSphere;
+use plexus::primitive::Trigon;
+
+const CAPACITY: usize = 1024;
+
+type E3 = Point3<R64>;
+
+fn sphere() -> Vec<Trigon<E3>> {
+    // Create a stream of triangles from a large sphere.
+    UvSphere::new(256, 256)
+        .polygons::<Position<E3>>()
+        .triangulate()
+        .collect()
+}
+
+fn benchmark(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("index");
+    group.sample_size(10);
+    group.bench_function("lru", move |bencher| {
+        bencher.iter_batched(
+            sphere,
+            |polygons| {
+                polygons
+                    .into_iter()
+                    .index_vertices::<Flat3, _>(LruIndexer::with_capacity(CAPACITY))
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.bench_function("hash-lru", move |bencher| {
+        bencher.iter_batched(
+            sphere,
+            |polygons| {
+                polygons
+                    .into_iter()
+                    .index_vertices::<Flat3, _>(HashLruIndexer::with_capacity(CAPACITY))
+            },
+            BatchSize::LargeInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, benchmark);
+criterion_main!(benches);
@@ -7,16 +7,22 @@
 //!
 //! | Feature        | Default | Encoding | Read | Write |
 //! |----------------|---------|----------|------|-------|
-//! | `encoding-ply` | No      | [PLY]    | Yes  | No    |
+//! | `encoding-obj` | No      | [OBJ]    | Yes  | Yes   |
+//! | `encoding-ply` | No      | [PLY]    | Yes  | Yes   |
+//! | `encoding-stl` | No      | [STL]    | Yes  | Yes   |
 //!
 //! This module provides traits used by all encodings. These traits describe the
 //! outputs and inputs of decoders and encoders, respectively. Generally, these
 //! traits should **not** be used directly. Instead, prefer the conversion
 //! traits exposed for specific encodings, such as `FromPly` when using [PLY].
 //!
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
 //! [PLY]: https://en.wikipedia.org/wiki/ply_(file_format)
+//! [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
 
+pub mod obj;
 pub mod ply;
+pub mod stl;
 
 use std::fmt::Debug;
 
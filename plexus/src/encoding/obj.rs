@@ -0,0 +1,416 @@
+//! [OBJ] encoding.
+//!
+//! This module provides support for the Wavefront [OBJ] format. Only vertex
+//! positions, faces, and (when writing) face normals are supported. Other
+//! elements, such as texture coordinates and normals, are ignored when
+//! reading.
+//!
+//! # Examples
+//!
+//! Reading an [OBJ] file into a [`MeshGraph`]:
+//!
+//! ```rust
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::obj;
+//! use plexus::graph::MeshGraph;
+//!
+//! let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 3\n";
+//! let graph: MeshGraph<Point3<f64>> = obj::from_obj(obj).unwrap();
+//! ```
+//!
+//! Writing a [`MeshGraph`] to an [OBJ] file:
+//!
+//! ```rust,no_run
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::encoding::obj;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cube::Cube;
+//! use plexus::primitive::generate::Position;
+//! use std::fs::File;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+//! obj::to_obj(&graph, File::create("cube.obj").unwrap()).unwrap();
+//! ```
+//!
+//! [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+//!
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+#![cfg(feature = "encoding-obj")]
+
+use num::ToPrimitive;
+use smallvec::SmallVec;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use thiserror::Error;
+use typenum::U3;
+
+use crate::buffer::FromRawBuffers;
+use crate::entity::storage::Key;
+use crate::geometry::{AsPosition, FromGeometry};
+use crate::graph::{
+    ClosedView, FaceNormal, FaceView, GraphData, GraphError, MeshGraph, VertexPosition,
+};
+use crate::primitive::{Topological, UnboundedPolygon};
+
+/// Errors concerning the [OBJ] encoding.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+#[derive(Debug, Error)]
+pub enum ObjError {
+    /// An element could not be parsed.
+    #[error("malformed element")]
+    ElementMalformed,
+    /// A graph could not be constructed from the encoded data, such as when
+    /// a face refers to a vertex that does not exist.
+    #[error("graph construction failed")]
+    Graph(GraphError),
+    /// An I/O operation (read or write via the `Read` and `Write` traits)
+    /// failed.
+    #[error("I/O operation failed")]
+    Io(io::Error),
+}
+
+impl From<GraphError> for ObjError {
+    fn from(error: GraphError) -> Self {
+        ObjError::Graph(error)
+    }
+}
+
+impl From<io::Error> for ObjError {
+    fn from(error: io::Error) -> Self {
+        ObjError::Io(error)
+    }
+}
+
+/// Reads a [`MeshGraph`] from an [OBJ] encoding.
+///
+/// Vertex positions are read from `v` lines and faces are read from `f`
+/// lines. Faces may have any arity. Indices in `f` lines may be 1-based or
+/// negative, in which case they are relative to the most recently read
+/// vertex. Only the position index of each `f` vertex is used; texture
+/// coordinate and normal indices are ignored.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, an element is malformed, a face refers
+/// to a vertex that does not exist, or the faces do not form a consistent
+/// graph.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn from_obj<G, R>(read: R) -> Result<MeshGraph<G>, ObjError>
+where
+    G: GraphData,
+    G::Vertex: FromGeometry<(f64, f64, f64)>,
+    R: Read,
+{
+    let mut positions = Vec::new();
+    let mut faces = Vec::new();
+    for line in BufReader::new(read).lines() {
+        let line = line?;
+        let mut tokens = line.split('#').next().unwrap_or("").split_whitespace();
+        match tokens.next() {
+            Some("v") => {
+                let mut coordinate = || {
+                    tokens
+                        .next()
+                        .and_then(|token| token.parse::<f64>().ok())
+                        .ok_or(ObjError::ElementMalformed)
+                };
+                positions.push((coordinate()?, coordinate()?, coordinate()?));
+            }
+            Some("f") => {
+                let n = positions.len();
+                let indices = tokens
+                    .map(|token| {
+                        let index = token
+                            .split('/')
+                            .next()
+                            .and_then(|index| index.parse::<isize>().ok())
+                            .ok_or(ObjError::ElementMalformed)?;
+                        match index.cmp(&0) {
+                            Ordering::Greater => Some(index.unsigned_abs() - 1),
+                            Ordering::Less => n.checked_sub(index.unsigned_abs()),
+                            Ordering::Equal => None,
+                        }
+                        .ok_or(ObjError::Graph(GraphError::TopologyNotFound))
+                    })
+                    .collect::<Result<SmallVec<[usize; 4]>, _>>()?;
+                faces.push(
+                    UnboundedPolygon::try_from_slice(indices).ok_or(ObjError::ElementMalformed)?,
+                );
+            }
+            _ => {}
+        }
+    }
+    Ok(MeshGraph::from_raw_buffers(faces, positions)?)
+}
+
+/// Writes a [`MeshGraph`] to an [OBJ] encoding.
+///
+/// A `v` line is written for the position of each vertex and an `f` line is
+/// written for each face. Faces are written with their arity, so the output
+/// may contain $n$-gons. Indices in `f` lines are 1-based.
+///
+/// Vertices and faces are written in the order in which they were inserted
+/// into the graph, so the output is deterministic and round trips through
+/// [`from_obj`] are stable.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+///
+/// [`from_obj`]: crate::encoding::obj::from_obj
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn to_obj<G, W>(graph: &MeshGraph<G>, writer: W) -> io::Result<()>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    write_obj(graph, writer, |_| None)
+}
+
+/// Writes a [`MeshGraph`] to an [OBJ] encoding with face normals.
+///
+/// This function behaves like [`to_obj`], but also writes a `vn` line with
+/// the normal of each face. The `f` line of a face refers to its normal for
+/// each of its vertices, such as `f 1//1 2//1 3//1`. Degenerate faces have no
+/// normal and are written without a normal index.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+///
+/// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+///
+/// [`to_obj`]: crate::encoding::obj::to_obj
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn to_obj_with_normals<G, W>(graph: &MeshGraph<G>, writer: W) -> io::Result<()>
+where
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    write_obj(graph, writer, |face| {
+        face.normal()
+            .ok()
+            .map(|normal| into_f64_xyz(VertexPosition::<G>::origin() + normal))
+    })
+}
+
+fn write_obj<G, W, F>(graph: &MeshGraph<G>, mut writer: W, f: F) -> io::Result<()>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+    F: Fn(FaceView<&MeshGraph<G>>) -> Option<(f64, f64, f64)>,
+{
+    // Keys are allocated incrementally, so sorting by key yields the order in
+    // which entities were inserted rather than the arbitrary order of storage.
+    let mut vertices = graph.vertices().collect::<Vec<_>>();
+    vertices.sort_by_key(|vertex| vertex.key().into_inner());
+    let mut faces = graph.faces().collect::<Vec<_>>();
+    faces.sort_by_key(|face| face.key().into_inner());
+    let mut indices = HashMap::with_capacity(vertices.len());
+    for (index, vertex) in vertices.into_iter().enumerate() {
+        let (x, y, z) = into_f64_xyz(*vertex.position());
+        writeln!(writer, "v {} {} {}", x, y, z)?;
+        indices.insert(vertex.key(), index + 1);
+    }
+    let mut n = 0usize;
+    for face in faces {
+        let normal = match f(face) {
+            Some((x, y, z)) => {
+                writeln!(writer, "vn {} {} {}", x, y, z)?;
+                n += 1;
+                Some(n)
+            }
+            None => None,
+        };
+        write!(writer, "f")?;
+        // Write the perimeter beginning with the source of the leading arc of
+        // the face, which is the first vertex of the perimeter from which the
+        // face was inserted.
+        for arc in face.adjacent_arcs() {
+            let index = indices[&arc.source_vertex().key()];
+            match normal {
+                Some(normal) => write!(writer, " {}//{}", index, normal)?,
+                None => write!(writer, " {}", index)?,
+            }
+        }
+        writeln!(writer)?;
+    }
+    Ok(())
+}
+
+fn into_f64_xyz<S>(position: S) -> (f64, f64, f64)
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let (x, y, z) = position.into_xyz();
+    (
+        x.to_f64().unwrap_or(0.0),
+        y.to_f64().unwrap_or(0.0),
+        z.to_f64().unwrap_or(0.0),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use theon::space::{EuclideanSpace, FiniteDimensional};
+    use typenum::U3;
+
+    use crate::encoding::obj::{self, ObjError};
+    use crate::geometry::AsPosition;
+    use crate::graph::{GraphData, GraphError, MeshGraph, VertexPosition};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn encode_graph() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut obj = Vec::new();
+        obj::to_obj(&graph, &mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+
+        assert_eq!(8, obj.lines().filter(|line| line.starts_with("v ")).count());
+        assert_eq!(6, obj.lines().filter(|line| line.starts_with("f ")).count());
+        // Faces of the cube are quadrilaterals.
+        assert!(obj
+            .lines()
+            .filter(|line| line.starts_with("f "))
+            .all(|line| line.split_whitespace().count() == 5));
+    }
+
+    #[test]
+    fn encode_graph_with_method() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut expected = Vec::new();
+        obj::to_obj(&graph, &mut expected).unwrap();
+        let mut obj = Vec::new();
+        graph.to_obj(&mut obj).unwrap();
+
+        assert_eq!(expected, obj);
+    }
+
+    #[test]
+    fn encode_graph_with_normals() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut obj = Vec::new();
+        obj::to_obj_with_normals(&graph, &mut obj).unwrap();
+        let obj = String::from_utf8(obj).unwrap();
+
+        assert_eq!(6, obj.lines().filter(|line| line.starts_with("vn ")).count());
+        assert!(obj
+            .lines()
+            .filter(|line| line.starts_with("f "))
+            .flat_map(|line| line.split_whitespace().skip(1))
+            .all(|index| index.contains("//")));
+    }
+
+    #[test]
+    fn encode_graph_deterministic() {
+        fn encode<G>(graph: &MeshGraph<G>) -> Vec<u8>
+        where
+            G: GraphData,
+            G::Vertex: AsPosition,
+            VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            let mut obj = Vec::new();
+            obj::to_obj(graph, &mut obj).unwrap();
+            obj
+        }
+
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let obj = encode(&graph);
+
+        assert_eq!(obj, encode(&graph));
+        // Round trips preserve the order of vertices and faces.
+        let graph: MeshGraph<Point3<f64>> = obj::from_obj(obj.as_slice()).unwrap();
+        assert_eq!(obj, encode(&graph));
+    }
+
+    #[test]
+    fn decode_graph() {
+        // A square pyramid with faces of mixed arity and relative indices.
+        let obj: &[u8] = b"\
+# Square pyramid.
+v 0 0 0
+v 1 0 0
+v 1 1 0
+v 0 1 0
+v 0.5 0.5 1
+vn 0 0 1
+f 4 3 2 1
+f 1 2 5
+f 2/1 3/2 5/3
+f -3 -2 -1
+f 4//1 1//1 5//1
+";
+        let graph: MeshGraph<Point3<f64>> = obj::from_obj(obj).unwrap();
+
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(8, graph.edge_count());
+        assert_eq!(5, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn reject_malformed_indices() {
+        let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 1 2 4\n";
+        assert!(matches!(
+            obj::from_obj::<Point3<f64>, _>(obj),
+            Err(ObjError::Graph(GraphError::TopologyNotFound))
+        ));
+
+        let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf 0 1 2\n";
+        assert!(matches!(
+            obj::from_obj::<Point3<f64>, _>(obj),
+            Err(ObjError::Graph(GraphError::TopologyNotFound))
+        ));
+
+        let obj: &[u8] = b"v 0 0 0\nv 1 0 0\nv 0 1 0\nf -4 -2 -1\n";
+        assert!(matches!(
+            obj::from_obj::<Point3<f64>, _>(obj),
+            Err(ObjError::Graph(GraphError::TopologyNotFound))
+        ));
+    }
+
+    #[test]
+    fn round_trip() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut obj = Vec::new();
+        obj::to_obj(&graph, &mut obj).unwrap();
+        let graph: MeshGraph<Point3<f64>> = obj::from_obj(obj.as_slice()).unwrap();
+
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+    }
+}
@@ -33,6 +33,29 @@
 //! let (graph, _) = MeshGraph::<E3>::from_ply(encoding, read()).unwrap();
 //! ```
 //!
+//! Writing a [`MeshGraph`] to a binary [PLY] file:
+//!
+//! ```rust,no_run
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::encoding::ply::PlyFormat;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cube::Cube;
+//! use plexus::primitive::generate::Position;
+//! use std::fs::File;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+//! let file = File::create("cube.ply").unwrap();
+//! graph.to_ply(file, PlyFormat::BinaryLittleEndian).unwrap();
+//! ```
+//!
 //! [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
 //!
 //! [`ply-rs`]: https://crates.io/crates/ply-rs
@@ -44,10 +67,12 @@
 #![cfg(feature = "encoding-ply")]
 
 use num::cast;
-use num::NumCast;
+use num::{NumCast, ToPrimitive};
 use ply_rs::parser::Parser;
 use ply_rs::ply::KeyMap;
 use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::io::{self, Read, Write};
 use std::iter::FromIterator;
 use std::marker::PhantomData;
@@ -56,6 +81,8 @@ use thiserror::Error;
 use typenum::{NonZero, Unsigned, U2, U3};
 
 use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
+use crate::geometry::AsPosition;
+use crate::graph::{ClosedView, GraphData, MeshGraph, VertexPosition};
 
 pub use ply_rs::ply::{
     ElementDef as ElementDefinition, Property, PropertyDef as PropertyDefinition, PropertyType,
@@ -298,6 +325,16 @@ where
     }
 }
 
+/// Vertex data with an RGB color.
+///
+/// Implementing this trait allows vertex colors to be written via
+/// [`to_ply_with_color`].
+///
+/// [`to_ply_with_color`]: crate::encoding::ply::to_ply_with_color
+pub trait AsColor {
+    fn as_color(&self) -> [u8; 3];
+}
+
 pub struct PositionEncoding<T> {
     phantom: PhantomData<fn() -> T>,
 }
@@ -409,6 +446,183 @@ where
         .and_then(|(definition, elements)| decoder.decode_face_properties(definition, elements))
 }
 
+/// Format of a [PLY] encoding.
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PlyFormat {
+    /// Human readable text.
+    Ascii,
+    /// Compact binary with little-endian values.
+    BinaryLittleEndian,
+}
+
+/// Writes a [`MeshGraph`] to a [PLY] encoding.
+///
+/// A `vertex` element is written with `x`, `y`, and `z` properties for the
+/// position of each vertex and a `face` element is written with a
+/// `vertex_index` list property for each face. Faces are written with their
+/// arity. Positions are written as `double` and indices as `uint`.
+///
+/// If every vertex has a normal in its data (see
+/// [`GraphData::vertex_normal`]), then `nx`, `ny`, and `nz` properties are
+/// also written as `double` for the normal of each vertex.
+///
+/// # Errors
+///
+/// Returns an error if writing fails, if the arity of a face exceeds 255, or
+/// if there are more vertices than can be indexed by a `uint`.
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+///
+/// [`GraphData::vertex_normal`]: crate::graph::GraphData::vertex_normal
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn to_ply<G, W>(graph: &MeshGraph<G>, writer: W, format: PlyFormat) -> io::Result<()>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    write_ply(graph, writer, format, None::<fn(&G::Vertex) -> [u8; 3]>)
+}
+
+/// Writes a [`MeshGraph`] with vertex colors to a [PLY] encoding.
+///
+/// This function behaves like [`to_ply`], but also writes `red`, `green`, and
+/// `blue` properties for each vertex via [`AsColor`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`to_ply`].
+///
+/// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+///
+/// [`AsColor`]: crate::encoding::ply::AsColor
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`to_ply`]: crate::encoding::ply::to_ply
+pub fn to_ply_with_color<G, W>(
+    graph: &MeshGraph<G>,
+    writer: W,
+    format: PlyFormat,
+) -> io::Result<()>
+where
+    G: GraphData,
+    G::Vertex: AsColor + AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    write_ply(graph, writer, format, Some(<G::Vertex as AsColor>::as_color))
+}
+
+fn write_ply<G, W, F>(
+    graph: &MeshGraph<G>,
+    mut writer: W,
+    format: PlyFormat,
+    color: Option<F>,
+) -> io::Result<()>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+    F: Fn(&G::Vertex) -> [u8; 3],
+{
+    // Face lists are prefixed with a `uchar` count and contain `uint` indices.
+    if graph.faces().any(|face| face.arity() > usize::from(u8::MAX)) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "face arity exceeds encoding",
+        ));
+    }
+    if u32::try_from(graph.vertex_count()).is_err() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "vertex count exceeds encoding",
+        ));
+    }
+    // Normals are only written if all vertices have a normal, because every
+    // vertex element must have the same properties.
+    let normals = graph.vertex_count() > 0
+        && graph
+            .vertices()
+            .all(|vertex| G::vertex_normal(vertex.get()).is_some());
+    writeln!(writer, "ply")?;
+    match format {
+        PlyFormat::Ascii => writeln!(writer, "format ascii 1.0")?,
+        PlyFormat::BinaryLittleEndian => writeln!(writer, "format binary_little_endian 1.0")?,
+    }
+    writeln!(writer, "element vertex {}", graph.vertex_count())?;
+    for name in ["x", "y", "z"] {
+        writeln!(writer, "property double {}", name)?;
+    }
+    if normals {
+        for name in ["nx", "ny", "nz"] {
+            writeln!(writer, "property double {}", name)?;
+        }
+    }
+    if color.is_some() {
+        for name in ["red", "green", "blue"] {
+            writeln!(writer, "property uchar {}", name)?;
+        }
+    }
+    writeln!(writer, "element face {}", graph.face_count())?;
+    writeln!(writer, "property list uchar uint vertex_index")?;
+    writeln!(writer, "end_header")?;
+    let into_f64 = |position: VertexPosition<G>| {
+        let (x, y, z) = position.into_xyz();
+        [x, y, z].map(|x| x.to_f64().unwrap_or(0.0))
+    };
+    let mut indices = HashMap::with_capacity(graph.vertex_count());
+    for (index, vertex) in graph.vertices().enumerate() {
+        let position = into_f64(*vertex.position());
+        let normal = G::vertex_normal(vertex.get())
+            .filter(|_| normals)
+            .map(|normal| into_f64(VertexPosition::<G>::origin() + normal));
+        let color = color.as_ref().map(|color| color(vertex.get()));
+        match format {
+            PlyFormat::Ascii => {
+                write!(writer, "{} {} {}", position[0], position[1], position[2])?;
+                if let Some([x, y, z]) = normal {
+                    write!(writer, " {} {} {}", x, y, z)?;
+                }
+                if let Some([red, green, blue]) = color {
+                    write!(writer, " {} {} {}", red, green, blue)?;
+                }
+                writeln!(writer)?;
+            }
+            PlyFormat::BinaryLittleEndian => {
+                for x in position.iter().chain(normal.iter().flatten()) {
+                    writer.write_all(&x.to_le_bytes())?;
+                }
+                if let Some(color) = color {
+                    writer.write_all(&color)?;
+                }
+            }
+        }
+        indices.insert(vertex.key(), index as u32);
+    }
+    for face in graph.faces() {
+        let arity = face.arity() as u8;
+        match format {
+            PlyFormat::Ascii => {
+                write!(writer, "{}", arity)?;
+                for vertex in face.adjacent_vertices() {
+                    write!(writer, " {}", indices[&vertex.key()])?;
+                }
+                writeln!(writer)?;
+            }
+            PlyFormat::BinaryLittleEndian => {
+                writer.write_all(&[arity])?;
+                for vertex in face.adjacent_vertices() {
+                    writer.write_all(&indices[&vertex.key()].to_le_bytes())?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 fn num_cast_scalar<T, U>(value: T) -> Result<U, PlyError>
 where
     T: NumCast,
@@ -431,12 +645,19 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point3;
+    use decorum::R64;
+    use nalgebra::{Point3, Vector3};
 
     use crate::buffer::MeshBuffer;
-    use crate::encoding::ply::{FromPly, PositionEncoding};
-    use crate::graph::MeshGraph;
-    use crate::primitive::Tetragon;
+    use crate::encoding::ply::{
+        self, AsColor, ElementExt, FromPly, Ply, PlyFormat, PositionEncoding,
+    };
+    use crate::geometry::AsPosition;
+    use crate::graph::{GraphData, MeshGraph};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::{Tetragon, Trigon};
 
     type E3 = Point3<f64>;
 
@@ -461,4 +682,196 @@ mod tests {
         assert_eq!(12, graph.edge_count());
         assert_eq!(6, graph.face_count());
     }
+
+    #[test]
+    fn encode_graph() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let mut ply = Vec::new();
+        ply::to_ply(&graph, &mut ply, PlyFormat::Ascii).unwrap();
+
+        assert!(!std::str::from_utf8(&ply).unwrap().contains("red"));
+        let (graph, _) =
+            MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply.as_slice()).unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn encode_graph_binary() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let mut ply = Vec::new();
+        ply::to_ply(&graph, &mut ply, PlyFormat::BinaryLittleEndian).unwrap();
+
+        // Each vertex is three `double`s and each quadrilateral is a `uchar`
+        // followed by four `uint`s.
+        let header = b"end_header\n";
+        let n = ply
+            .windows(header.len())
+            .position(|window| window == header)
+            .unwrap()
+            + header.len();
+        assert_eq!((8 * 24) + (6 * 17), ply.len() - n);
+
+        let (graph, _) =
+            MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply.as_slice()).unwrap();
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn decode_graph_with_extra_properties() {
+        let ply = |vertices: &str, faces: &str| {
+            format!(
+                "\
+ply
+format ascii 1.0
+element vertex 3
+property float x
+property float y
+property float z
+property uchar red
+property uchar green
+property uchar blue
+element face 1
+property list uchar uint vertex_index
+end_header
+{}{}",
+                vertices, faces
+            )
+        };
+        let vertices = "0 0 0 255 0 0\n1 0 0 255 0 0\n0 1 0 255 0 0\n";
+        let decode = |ply: String| {
+            MeshGraph::<E3>::from_ply(PositionEncoding::<E3>::default(), ply.as_bytes())
+        };
+
+        // Color properties are ignored by `PositionEncoding`.
+        let (graph, _) = decode(ply(vertices, "3 0 1 2\n")).unwrap();
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+
+        // Fewer elements than declared and indices beyond the declared
+        // vertices are rejected.
+        assert!(decode(ply(vertices, "")).is_err());
+        assert!(decode(ply(vertices, "3 0 1 3\n")).is_err());
+    }
+
+    #[test]
+    fn encode_graph_with_color() {
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: E3,
+            color: [u8; 3],
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E3;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl AsColor for Vertex {
+            fn as_color(&self) -> [u8; 3] {
+                self.color
+            }
+        }
+
+        let graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![
+                Vertex {
+                    position: Point3::new(0.0, 0.0, 0.0),
+                    color: [255, 0, 0],
+                },
+                Vertex {
+                    position: Point3::new(1.0, 0.0, 0.0),
+                    color: [255, 0, 0],
+                },
+                Vertex {
+                    position: Point3::new(0.0, 1.0, 0.0),
+                    color: [255, 0, 0],
+                },
+            ],
+        )
+        .unwrap();
+        let mut ply = Vec::new();
+        ply::to_ply_with_color(&graph, &mut ply, PlyFormat::Ascii).unwrap();
+
+        let ply = Ply::parse(ply.as_slice()).unwrap();
+        let vertices = &ply.payload["vertex"];
+        assert_eq!(3, vertices.len());
+        for vertex in vertices {
+            assert_eq!(255u8, vertex.scalar("red").unwrap());
+            assert_eq!(0u8, vertex.scalar("green").unwrap());
+            assert_eq!(0u8, vertex.scalar("blue").unwrap());
+        }
+        assert_eq!(1, ply.payload["face"].len());
+    }
+
+    #[test]
+    fn encode_graph_with_normals() {
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: E3,
+            normal: Vector3<f64>,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+
+            fn vertex_normal(vertex: &Self::Vertex) -> Option<Vector3<f64>> {
+                Some(vertex.normal)
+            }
+        }
+
+        impl AsPosition for Vertex {
+            type Position = E3;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        let vertex = |x, y| Vertex {
+            position: Point3::new(x, y, 0.0),
+            normal: Vector3::z(),
+        };
+        let graph = MeshGraph::<Vertex>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![vertex(0.0, 0.0), vertex(1.0, 0.0), vertex(0.0, 1.0)],
+        )
+        .unwrap();
+        for format in [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian] {
+            let mut ply = Vec::new();
+            graph.to_ply(&mut ply, format).unwrap();
+
+            let ply = Ply::parse(ply.as_slice()).unwrap();
+            let vertices = &ply.payload["vertex"];
+            assert_eq!(3, vertices.len());
+            for vertex in vertices {
+                assert_eq!(0.0f64, vertex.scalar("nx").unwrap());
+                assert_eq!(0.0f64, vertex.scalar("ny").unwrap());
+                assert_eq!(1.0f64, vertex.scalar("nz").unwrap());
+            }
+        }
+
+        // Normals are not written if vertices have no normals.
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<Point3<R64>>>().collect();
+        let mut ply = Vec::new();
+        graph.to_ply(&mut ply, PlyFormat::Ascii).unwrap();
+        assert!(!std::str::from_utf8(&ply).unwrap().contains("nx"));
+    }
 }
@@ -0,0 +1,523 @@
+//! [STL] encoding.
+//!
+//! This module provides support for reading and writing the [STL] format in
+//! both its binary and ASCII variants. [STL] only supports triangles, so faces
+//! with higher arity are triangulated as they are written. The graph itself is
+//! not modified.
+//!
+//! # Examples
+//!
+//! Reading an [STL] file into a [`MeshGraph`]:
+//!
+//! ```rust,no_run
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use nalgebra::Point3;
+//! use plexus::encoding::stl;
+//! use plexus::graph::MeshGraph;
+//! use std::fs::File;
+//!
+//! let graph: MeshGraph<Point3<f64>> = stl::from_stl(File::open("cube.stl").unwrap()).unwrap();
+//! ```
+//!
+//! Writing a [`MeshGraph`] to a binary [STL] file:
+//!
+//! ```rust,no_run
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::encoding::stl::StlFormat;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cube::Cube;
+//! use plexus::primitive::generate::Position;
+//! use std::fs::File;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+//! graph.to_stl(File::create("cube.stl").unwrap(), StlFormat::Binary).unwrap();
+//! ```
+//!
+//! [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+//!
+//! [`MeshGraph`]: crate::graph::MeshGraph
+
+#![cfg(feature = "encoding-stl")]
+
+use num::ToPrimitive;
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use std::convert::{TryFrom, TryInto};
+use std::io::{self, Read, Write};
+use std::str;
+use theon::space::{EuclideanSpace, FiniteDimensional};
+use thiserror::Error;
+use typenum::U3;
+
+use crate::buffer::FromRawBuffers;
+use crate::geometry::{AsPosition, FromGeometry};
+use crate::graph::{fan, FaceNormal, GraphData, GraphError, MeshGraph, VertexPosition};
+use crate::primitive::Trigon;
+
+// Binary headers must not begin with `solid`, as some readers use this to
+// detect ASCII encodings.
+const HEADER: &[u8] = b"Binary STL written by Plexus";
+
+/// A triangle with its normal followed by the positions of its vertices.
+type Facet = [[f32; 3]; 4];
+
+/// Errors concerning the [STL] encoding.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+#[derive(Debug, Error)]
+pub enum StlError {
+    /// An element could not be parsed, such as a truncated binary facet or a
+    /// malformed ASCII vertex.
+    #[error("malformed element")]
+    ElementMalformed,
+    /// A graph could not be constructed from the encoded triangles, such as
+    /// when welding produces non-manifold topology.
+    #[error("graph construction failed")]
+    Graph(GraphError),
+    /// An I/O operation (read or write via the `Read` and `Write` traits)
+    /// failed.
+    #[error("I/O operation failed")]
+    Io(io::Error),
+}
+
+impl From<GraphError> for StlError {
+    fn from(error: GraphError) -> Self {
+        StlError::Graph(error)
+    }
+}
+
+impl From<io::Error> for StlError {
+    fn from(error: io::Error) -> Self {
+        StlError::Io(error)
+    }
+}
+
+/// Reads a [`MeshGraph`] from an [STL] encoding.
+///
+/// Both binary and ASCII encodings are supported and are detected from the
+/// data. [STL] stores the positions of each triangle separately, so vertices
+/// with exactly equal positions are merged as triangles are read. Otherwise,
+/// the resulting graph would be a disjoint set of triangles. Triangles that
+/// refer to the same vertex more than once are degenerate and are skipped.
+///
+/// See [`from_stl_with_tolerance`] to also merge vertices that are near but
+/// not exactly equal.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, a binary encoding is truncated, an
+/// element is malformed, or the triangles do not form a consistent graph.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+///
+/// [`from_stl_with_tolerance`]: crate::encoding::stl::from_stl_with_tolerance
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn from_stl<G, R>(mut read: R) -> Result<MeshGraph<G>, StlError>
+where
+    G: GraphData,
+    G::Vertex: FromGeometry<(f64, f64, f64)>,
+    R: Read,
+{
+    let mut data = Vec::new();
+    read.read_to_end(&mut data)?;
+    let triangles = if is_binary(&data) {
+        read_binary(&data)?
+    }
+    else {
+        read_ascii(&data)?
+    };
+    let mut indices = HashMap::new();
+    let mut positions = Vec::new();
+    let mut faces = Vec::with_capacity(triangles.len());
+    for triangle in triangles {
+        let [a, b, c] = triangle.map(|position| {
+            // Normalize negative zero so that it is merged with zero.
+            let position = position.map(|x| x + 0.0);
+            *indices.entry(position.map(f32::to_bits)).or_insert_with(|| {
+                let [x, y, z] = position.map(f64::from);
+                positions.push((x, y, z));
+                positions.len() - 1
+            })
+        });
+        if a != b && b != c && c != a {
+            faces.push(Trigon::new(a, b, c));
+        }
+    }
+    Ok(MeshGraph::from_raw_buffers(faces, positions)?)
+}
+
+/// Reads a [`MeshGraph`] from an [STL] encoding and welds vertices within the
+/// given distance of each other.
+///
+/// This function behaves like [`from_stl`], but also welds vertices using
+/// [`MeshGraph::weld`]. This is useful when the positions of shared vertices
+/// differ slightly between triangles.
+///
+/// # Errors
+///
+/// Returns the same errors as [`from_stl`]. Groups of vertices that cannot be
+/// welded without producing non-manifold topology are not merged.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+///
+/// [`from_stl`]: crate::encoding::stl::from_stl
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::weld`]: crate::graph::MeshGraph::weld
+pub fn from_stl_with_tolerance<G, R>(read: R, tolerance: f64) -> Result<MeshGraph<G>, StlError>
+where
+    G: GraphData,
+    G::Vertex: AsPosition + FromGeometry<(f64, f64, f64)>,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    R: Read,
+{
+    let mut graph = from_stl(read)?;
+    graph.weld(tolerance)?;
+    Ok(graph)
+}
+
+/// Variant of the [STL] encoding.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum StlFormat {
+    /// Human readable text. See [`to_stl_ascii`].
+    ///
+    /// [`to_stl_ascii`]: crate::encoding::stl::to_stl_ascii
+    Ascii,
+    /// Compact little-endian binary. See [`to_stl_binary`].
+    ///
+    /// [`to_stl_binary`]: crate::encoding::stl::to_stl_binary
+    Binary,
+}
+
+/// Writes a [`MeshGraph`] to an [STL] encoding of the given format.
+///
+/// # Errors
+///
+/// Returns the same errors as [`to_stl_ascii`] or [`to_stl_binary`]
+/// depending on the format.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+///
+/// [`to_stl_ascii`]: crate::encoding::stl::to_stl_ascii
+/// [`to_stl_binary`]: crate::encoding::stl::to_stl_binary
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn to_stl<G, W>(graph: &MeshGraph<G>, writer: W, format: StlFormat) -> io::Result<()>
+where
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    match format {
+        StlFormat::Ascii => to_stl_ascii(graph, writer),
+        StlFormat::Binary => to_stl_binary(graph, writer),
+    }
+}
+
+/// Writes a [`MeshGraph`] to a binary [STL] encoding.
+///
+/// An 80-byte header and the number of triangles are written followed by the
+/// normal and vertex positions of each triangle. Values are written as
+/// little-endian 32-bit floating point numbers. Non-triangular faces are
+/// triangulated and each of their triangles is written with the normal of the
+/// face.
+///
+/// # Errors
+///
+/// Returns an error if writing fails or if there are more triangles than can
+/// be represented by the encoding.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn to_stl_binary<G, W>(graph: &MeshGraph<G>, mut writer: W) -> io::Result<()>
+where
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    let facets = facets(graph);
+    let count = u32::try_from(facets.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too many triangles"))?;
+    let mut header = [0u8; 80];
+    header[..HEADER.len()].copy_from_slice(HEADER);
+    writer.write_all(&header)?;
+    writer.write_all(&count.to_le_bytes())?;
+    for facet in facets {
+        for component in facet.iter().flatten() {
+            writer.write_all(&component.to_le_bytes())?;
+        }
+        // Attribute byte count.
+        writer.write_all(&0u16.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+/// Writes a [`MeshGraph`] to an ASCII [STL] encoding.
+///
+/// Non-triangular faces are triangulated and each of their triangles is
+/// written with the normal of the face.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+///
+/// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+pub fn to_stl_ascii<G, W>(graph: &MeshGraph<G>, mut writer: W) -> io::Result<()>
+where
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    W: Write,
+{
+    writeln!(writer, "solid plexus")?;
+    for [[i, j, k], a, b, c] in facets(graph) {
+        writeln!(writer, "  facet normal {:e} {:e} {:e}", i, j, k)?;
+        writeln!(writer, "    outer loop")?;
+        for [x, y, z] in [a, b, c] {
+            writeln!(writer, "      vertex {:e} {:e} {:e}", x, y, z)?;
+        }
+        writeln!(writer, "    endloop")?;
+        writeln!(writer, "  endfacet")?;
+    }
+    writeln!(writer, "endsolid plexus")?;
+    Ok(())
+}
+
+// Binary encodings may also begin with `solid`, so the size of the data is
+// compared with the number of triangles given by the binary header first.
+fn is_binary(data: &[u8]) -> bool {
+    match data.get(80..84) {
+        Some(count) => {
+            let count = u32::from_le_bytes(count.try_into().unwrap()) as usize;
+            count.checked_mul(50).and_then(|n| n.checked_add(84)) == Some(data.len())
+                || !data.starts_with(b"solid")
+        }
+        None => !data.starts_with(b"solid"),
+    }
+}
+
+fn read_binary(data: &[u8]) -> Result<Vec<[[f32; 3]; 3]>, StlError> {
+    let count = data
+        .get(80..84)
+        .map(|count| u32::from_le_bytes(count.try_into().unwrap()) as usize)
+        .ok_or(StlError::ElementMalformed)?;
+    let facets = data.get(84..).unwrap_or(&[]);
+    if facets.len() / 50 < count {
+        return Err(StlError::ElementMalformed);
+    }
+    Ok(facets
+        .chunks_exact(50)
+        .take(count)
+        .map(|facet| {
+            // Skip the normal and read the positions of the vertices.
+            let component = |i: usize| {
+                let offset = 12 + (4 * i);
+                f32::from_le_bytes(facet[offset..(offset + 4)].try_into().unwrap())
+            };
+            [0, 1, 2].map(|j| [0, 1, 2].map(|k| component((3 * j) + k)))
+        })
+        .collect())
+}
+
+fn read_ascii(data: &[u8]) -> Result<Vec<[[f32; 3]; 3]>, StlError> {
+    let data = str::from_utf8(data).map_err(|_| StlError::ElementMalformed)?;
+    let mut positions = Vec::new();
+    for line in data.lines() {
+        let mut tokens = line.split_whitespace();
+        if let Some("vertex") = tokens.next() {
+            let mut coordinate = || {
+                tokens
+                    .next()
+                    .and_then(|token| token.parse::<f32>().ok())
+                    .ok_or(StlError::ElementMalformed)
+            };
+            positions.push([coordinate()?, coordinate()?, coordinate()?]);
+        }
+    }
+    if positions.len() % 3 != 0 {
+        return Err(StlError::ElementMalformed);
+    }
+    Ok(positions
+        .chunks_exact(3)
+        .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+        .collect())
+}
+
+fn facets<G>(graph: &MeshGraph<G>) -> Vec<Facet>
+where
+    G: FaceNormal,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    let into_f32 = |position: VertexPosition<G>| {
+        let (x, y, z) = position.into_xyz();
+        [x, y, z].map(|x| x.to_f32().unwrap_or(0.0))
+    };
+    let mut facets = Vec::with_capacity(graph.face_count());
+    for face in graph.faces() {
+        // Degenerate faces have no normal. Readers typically compute the
+        // normal of a facet from its vertices when the normal is zero.
+        let normal = face
+            .normal()
+            .map(|normal| into_f32(VertexPosition::<G>::origin() + normal))
+            .unwrap_or([0.0; 3]);
+        let positions = face
+            .adjacent_vertices()
+            .map(|vertex| into_f32(*vertex.position()))
+            .collect::<SmallVec<[_; 8]>>();
+        facets.extend(fan(&positions, 0).map(|[a, b, c]| [normal, a, b, c]));
+    }
+    facets
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::convert::TryInto;
+
+    use crate::encoding::stl::{self, StlError, StlFormat};
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn encode_binary() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.triangulate();
+        let mut stl = Vec::new();
+        stl::to_stl_binary(&graph, &mut stl).unwrap();
+
+        let count = u32::from_le_bytes(stl[80..84].try_into().unwrap());
+        assert_eq!(graph.face_count(), count as usize);
+        assert_eq!(84 + (50 * graph.face_count()), stl.len());
+    }
+
+    #[test]
+    fn encode_ascii() {
+        // Faces of the cube are quadrilaterals and are triangulated when
+        // written.
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut stl = Vec::new();
+        stl::to_stl_ascii(&graph, &mut stl).unwrap();
+        let stl = String::from_utf8(stl).unwrap();
+
+        assert!(stl.starts_with("solid"));
+        assert_eq!(
+            12,
+            stl.lines()
+                .filter(|line| line.trim_start().starts_with("facet normal"))
+                .count()
+        );
+        assert_eq!(
+            36,
+            stl.lines()
+                .filter(|line| line.trim_start().starts_with("vertex"))
+                .count()
+        );
+    }
+
+    #[test]
+    fn encode_with_format() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        let (mut expected, mut stl) = (Vec::new(), Vec::new());
+        stl::to_stl_ascii(&graph, &mut expected).unwrap();
+        stl::to_stl(&graph, &mut stl, StlFormat::Ascii).unwrap();
+        assert_eq!(expected, stl);
+
+        let (mut expected, mut stl) = (Vec::new(), Vec::new());
+        stl::to_stl_binary(&graph, &mut expected).unwrap();
+        stl::to_stl(&graph, &mut stl, StlFormat::Binary).unwrap();
+        assert_eq!(expected, stl);
+
+        let mut stl = Vec::new();
+        graph.to_stl(&mut stl, StlFormat::Binary).unwrap();
+        assert_eq!(expected, stl);
+    }
+
+    #[test]
+    fn decode_binary() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut stl = Vec::new();
+        stl::to_stl_binary(&graph, &mut stl).unwrap();
+        let graph: MeshGraph<Point3<f64>> = stl::from_stl(stl.as_slice()).unwrap();
+
+        // Vertices are shared by the triangles of the cube.
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn decode_ascii() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut stl = Vec::new();
+        stl::to_stl_ascii(&graph, &mut stl).unwrap();
+        let graph: MeshGraph<Point3<f64>> = stl::from_stl(stl.as_slice()).unwrap();
+
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn decode_with_tolerance() {
+        // The shared vertex at $(1, 1, 0)$ differs slightly between triangles.
+        let stl: &[u8] = b"\
+solid test
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 0 0
+      vertex 1 1 0
+    endloop
+  endfacet
+  facet normal 0 0 1
+    outer loop
+      vertex 0 0 0
+      vertex 1 1.00001 0
+      vertex 0 1 0
+    endloop
+  endfacet
+endsolid test
+";
+        let graph: MeshGraph<Point3<f64>> = stl::from_stl(stl).unwrap();
+        assert_eq!(5, graph.vertex_count());
+
+        let graph: MeshGraph<Point3<f64>> = stl::from_stl_with_tolerance(stl, 1e-3).unwrap();
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+    }
+
+    #[test]
+    fn reject_truncated_binary() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let mut stl = Vec::new();
+        stl::to_stl_binary(&graph, &mut stl).unwrap();
+        stl.truncate(stl.len() - 10);
+
+        assert!(matches!(
+            stl::from_stl::<Point3<f64>, _>(stl.as_slice()),
+            Err(StlError::ElementMalformed)
+        ));
+    }
+}
@@ -85,6 +85,61 @@ where
     }
 }
 
+/// Transformation of positions.
+///
+/// Transforms map a position to another position in the same space, such as
+/// an affine transformation. `Transform` is implemented for functions of the
+/// form `Fn(S) -> S`. When geometry features are enabled, `Transform` is also
+/// implemented for transformation types of integrated crates, such as
+/// isometries and homogeneous matrices.
+///
+/// See [`MeshGraph::transform`].
+///
+/// # Examples
+///
+/// Implementing a transform that scales positions along the $x$-axis:
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::geometry::Transform;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+/// use plexus::primitive::cube::Cube;
+/// use plexus::primitive::generate::Position;
+///
+/// type E3 = Point3<R64>;
+///
+/// struct ScaleX(f64);
+///
+/// impl Transform<Point3<f64>> for ScaleX {
+///     fn transform(&self, position: Point3<f64>) -> Point3<f64> {
+///         Point3::new(position.x * self.0, position.y, position.z)
+///     }
+/// }
+///
+/// let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+/// graph.transform(&ScaleX(2.0));
+/// ```
+///
+/// [`MeshGraph::transform`]: crate::graph::MeshGraph::transform
+pub trait Transform<S> {
+    fn transform(&self, position: S) -> S;
+}
+
+impl<S, F> Transform<S> for F
+where
+    F: Fn(S) -> S,
+{
+    fn transform(&self, position: S) -> S {
+        self(position)
+    }
+}
+
 pub trait Metric: Eq + One + Ord + Zero {}
 
 impl<Q> Metric for Q where Q: Eq + One + Ord + Zero {}
@@ -1,3 +1,6 @@
+use theon::space::Vector;
+use theon::{AsPosition, Position};
+
 use crate::entity::borrow::Reborrow;
 
 pub type Data<M> = <M as Parametric>::Data;
@@ -68,6 +71,27 @@ pub trait GraphData: Sized {
     type Arc: Clone + Default;
     type Edge: Clone + Default;
     type Face: Clone + Default;
+
+    /// Gets the normal of vertex data, if any.
+    ///
+    /// If a normal is returned, then it is normalized and used by
+    /// [`VertexView::normal`] rather than computing a normal from the adjacent
+    /// faces of the vertex. By default, vertex data has no normal.
+    ///
+    /// Normals in vertex data are not maintained by the graph. Operations that
+    /// move vertices or change the faces adjacent to them, such as
+    /// [`MeshGraph::transform`] or subdivision, do not update these normals,
+    /// so they may no longer agree with the surface of the graph.
+    ///
+    /// [`MeshGraph::transform`]: crate::graph::MeshGraph::transform
+    /// [`VertexView::normal`]: crate::graph::VertexView::normal
+    fn vertex_normal(vertex: &Self::Vertex) -> Option<Vector<Position<Self::Vertex>>>
+    where
+        Self::Vertex: AsPosition,
+    {
+        let _ = vertex;
+        None
+    }
 }
 
 impl GraphData for () {
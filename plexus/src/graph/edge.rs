@@ -1,9 +1,13 @@
 use arrayvec::ArrayVec;
 use derivative::Derivative;
+use num::{NumCast, ToPrimitive, Zero};
 use std::borrow::Borrow;
+use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
+use std::mem;
 use std::ops::{Deref, DerefMut};
-use theon::space::{EuclideanSpace, Scalar, Vector};
+use theon::ops::Cross;
+use theon::space::{EuclideanSpace, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 
 use crate::entity::borrow::{Reborrow, ReborrowInto, ReborrowMut};
@@ -12,10 +16,12 @@ use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
 use crate::graph::data::{Data, GraphData, Parametric};
 use crate::graph::face::{Face, FaceKey, FaceOrphan, FaceView, Ring};
-use crate::graph::geometry::{ArcNormal, EdgeMidpoint, VertexPosition};
+use crate::graph::geometry::{ArcNormal, EdgeMidpoint, FaceNormal, VertexPosition};
 use crate::graph::mutation::edge::{
-    self, ArcBridgeCache, ArcExtrudeCache, EdgeRemoveCache, EdgeSplitCache,
+    self, ArcBridgeCache, ArcExtrudeCache, EdgeCollapseCache, EdgeFlipCache, EdgeRemoveCache,
+    EdgeSplitCache, EdgeSplitFacesCache,
 };
+use crate::graph::mutation::face::{self, FaceInsertCache};
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
@@ -24,6 +30,7 @@ use crate::graph::{
     ViewCirculator,
 };
 use crate::transact::{BypassOrCommit, Mutate};
+use crate::{DynamicArity, IteratorExt as _};
 
 type Mutation<M> = mutation::Mutation<Immediate<M>>;
 
@@ -291,6 +298,11 @@ where
     {
         self.inner.get_mut()
     }
+
+    /// Replaces the data of the arc and returns the previous data.
+    pub fn set_geometry(&mut self, data: G::Arc) -> G::Arc {
+        mem::replace(self.get_mut(), data)
+    }
 }
 
 /// Reachable API.
@@ -1345,6 +1357,11 @@ where
     {
         self.inner.get_mut()
     }
+
+    /// Replaces the data of the edge and returns the previous data.
+    pub fn set_geometry(&mut self, data: G::Edge) -> G::Edge {
+        mem::replace(self.get_mut(), data)
+    }
 }
 
 /// Reachable API.
@@ -1399,6 +1416,478 @@ where
     }
 }
 
+impl<B, M, G> EdgeView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Gets the dihedral angle of the edge in radians.
+    ///
+    /// The dihedral angle is the angle between the normals of the faces
+    /// incident to the edge. It is zero if the faces are coplanar and
+    /// approaches $\pi$ as the faces fold onto each other.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::TopologyNotFound`] if the edge is a boundary edge,
+    /// because the dihedral angle is undefined without two incident faces.
+    /// Returns an error if the normal of either face cannot be computed.
+    ///
+    /// [`GraphError::TopologyNotFound`]: crate::graph::GraphError::TopologyNotFound
+    pub fn dihedral_angle(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let arc = self.arc();
+        let (left, right) = match (arc.face(), arc.opposite_arc().face()) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Err(GraphError::TopologyNotFound),
+        };
+        let cosine = <G as FaceNormal>::normal(left)?
+            .dot(<G as FaceNormal>::normal(right)?)
+            .to_f64()
+            .ok_or(GraphError::Geometry)?;
+        <Scalar<VertexPosition<G>> as NumCast>::from(cosine.max(-1.0).min(1.0).acos())
+            .ok_or(GraphError::Geometry)
+    }
+
+    /// Gets the signed dihedral angle of the edge in radians.
+    ///
+    /// The magnitude of the angle is the same as [`dihedral_angle`]. The sign
+    /// is determined by the direction of the edge with respect to the normals
+    /// of its incident faces: the angle is positive if the edge is convex and
+    /// negative if the edge is concave. The sign does not depend on which arc
+    /// of the edge is used.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`dihedral_angle`].
+    ///
+    /// [`dihedral_angle`]: crate::graph::EdgeView::dihedral_angle
+    pub fn signed_dihedral_angle(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let angle = self.dihedral_angle()?;
+        let arc = self.arc();
+        let (left, right) = match (arc.face(), arc.opposite_arc().face()) {
+            (Some(left), Some(right)) => (left, right),
+            _ => return Err(GraphError::TopologyNotFound),
+        };
+        let direction = *arc.destination_vertex().position() - *arc.source_vertex().position();
+        let sign = <G as FaceNormal>::normal(left)?
+            .cross(<G as FaceNormal>::normal(right)?)
+            .dot(direction);
+        Ok(if sign < Zero::zero() { -angle } else { angle })
+    }
+}
+
+impl<'a, M, G> EdgeView<&'a mut M>
+where
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Default
+        + Mutable<Data = G>,
+    G: GraphData,
+{
+    /// Splits the edge at its midpoint and splits its incident faces.
+    ///
+    /// Splitting inserts a new vertex with the data of one of the edge's
+    /// vertices but modified such that the position of the vertex is the
+    /// computed midpoint of the edge. Each triangular face incident to the edge
+    /// is then split by connecting the inserted vertex to the opposite vertex
+    /// of that face. If the edge is a boundary edge, then only its single
+    /// incident face is split.
+    ///
+    /// Unlike [`ArcView::split_at_midpoint`], this refines the edge without
+    /// increasing the arity of its incident faces.
+    ///
+    /// Returns the inserted vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face incident to the edge is not triangular.
+    ///
+    /// # Examples
+    ///
+    /// Splitting the interior edge of two triangles:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .unwrap()
+    ///     .key();
+    /// let vertex = graph.edge_mut(key).unwrap().split().unwrap();
+    ///
+    /// assert_eq!(4, vertex.valence());
+    /// ```
+    ///
+    /// [`ArcView::split_at_midpoint`]: crate::graph::ArcView::split_at_midpoint
+    pub fn split(self) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        G: EdgeMidpoint,
+        G::Vertex: AsPositionMut,
+    {
+        let mut data = self.arc().source_vertex().get().clone();
+        *data.as_position_mut() = self.midpoint();
+        let cache = EdgeSplitFacesCache::from_arc(self.arc())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| edge::split_faces_with(mutation, cache, || data))
+            .map(|(storage, m)| Bind::bind(storage, m).expect_consistent())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
+    /// Flips the edge between its two incident triangles.
+    ///
+    /// Flipping replaces the edge with an edge between the opposite vertices
+    /// of its incident triangles. The triangles are replaced such that they
+    /// share the flipped edge.
+    ///
+    /// Returns the flipped edge.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the edge is a boundary edge, if either of its
+    /// incident faces is not a triangle, or if the flipped edge already
+    /// exists.
+    ///
+    /// # Examples
+    ///
+    /// Flipping the diagonal of a quadrilateral:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+    ///     vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .unwrap()
+    ///     .key();
+    /// let edge = graph.edge_mut(key).unwrap().flip().unwrap();
+    ///
+    /// assert_eq!(3, edge.arc().source_vertex().valence());
+    /// ```
+    pub fn flip(self) -> Result<Self, GraphError> {
+        let cache = EdgeFlipCache::from_arc(self.arc())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| edge::flip(mutation, cache))
+            .map(|(storage, ab)| ArcView::bind(storage, ab).expect_consistent().into_edge())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
+    /// Rotates the edge to join the vertices opposite it if doing so improves
+    /// the valence distribution of its neighborhood.
+    ///
+    /// Rotation reconnects the edge like [`EdgeView::flip`], but only when the
+    /// reconnection moves the valences of the four vertices of the edge's
+    /// triangles closer to their ideal valences. The ideal valence of an
+    /// interior vertex is six and the ideal valence of a boundary vertex is
+    /// four. Improvement is measured by the sum of squared deviations from the
+    /// ideal valences. Rotating edges is useful for regularizing meshes after
+    /// other remeshing operations, such as collapses and splits.
+    ///
+    /// Returns the rotated edge.
+    ///
+    /// # Errors
+    ///
+    /// The edge must be an interior edge (it must have two incident faces)
+    /// and both incident faces must be triangles. Returns an error if either
+    /// of these preconditions is not met, if the rotated edge would duplicate
+    /// an existing edge, or if rotation does not improve the valence
+    /// distribution.
+    ///
+    /// [`EdgeView::flip`]: crate::graph::EdgeView::flip
+    pub fn rotate(self) -> Result<Self, GraphError> {
+        let cache = EdgeFlipCache::from_arc(self.arc())?;
+        {
+            let deviation = |vertex: VertexView<&M>, offset: isize| {
+                let ideal = if vertex
+                    .outgoing_arcs()
+                    .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+                {
+                    4
+                }
+                else {
+                    6
+                };
+                let deviation = vertex.valence() as isize + offset - ideal;
+                deviation * deviation
+            };
+            let arc = self.arc();
+            let (a, b) = (arc.source_vertex(), arc.destination_vertex());
+            let c = arc.next_arc().destination_vertex();
+            let d = arc.opposite_arc().next_arc().destination_vertex();
+            let before = deviation(a, 0) + deviation(b, 0) + deviation(c, 0) + deviation(d, 0);
+            let after = deviation(a, -1) + deviation(b, -1) + deviation(c, 1) + deviation(d, 1);
+            if after >= before {
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| edge::flip(mutation, cache))
+            .map(|(storage, ab)| ArcView::bind(storage, ab).expect_consistent().into_edge())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
+    /// Inserts a face that closes the boundary of the edge.
+    ///
+    /// The boundary loop is traversed from the boundary arc of the edge and a
+    /// single face is inserted into the ring formed by its vertices. This is
+    /// similar to [`Ring::get_or_insert_face`], but fails rather than
+    /// returning an existing face.
+    ///
+    /// Returns the inserted face.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::TopologyMalformed`] if the edge is not a boundary
+    /// edge or if its boundary loop is not simple (i.e., visits a vertex more
+    /// than once).
+    ///
+    /// # Examples
+    ///
+    /// Closing the hole left by removing a face from a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph.face_mut(key).unwrap().remove();
+    ///
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| edge.is_boundary_edge())
+    ///     .unwrap()
+    ///     .key();
+    /// let face = graph.edge_mut(key).unwrap().fill_boundary().unwrap();
+    ///
+    /// assert_eq!(4, face.arity());
+    /// ```
+    ///
+    /// [`GraphError::TopologyMalformed`]: crate::graph::GraphError::TopologyMalformed
+    /// [`Ring::get_or_insert_face`]: crate::graph::Ring::get_or_insert_face
+    pub fn fill_boundary(self) -> Result<FaceView<&'a mut M>, GraphError> {
+        let ring = self
+            .into_arc()
+            .into_boundary_arc()
+            .ok_or(GraphError::TopologyMalformed)?
+            .into_ring();
+        if ring.vertices().keys().collect::<HashSet<_>>().len() != ring.arity() {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let cache = FaceInsertCache::from_ring(ring.to_ref())?;
+        let (storage, _) = ring.into_arc().unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| {
+                face::insert_with(mutation, cache, || (Default::default(), Default::default()))
+            })
+            .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
+    /// Collapses the edge into one of its vertices.
+    ///
+    /// Collapsing removes one of the edge's vertices and reconnects its faces
+    /// to the remaining vertex, which is moved to the computed midpoint of the
+    /// edge. The triangular faces incident to the edge degenerate and are
+    /// removed.
+    ///
+    /// Returns the remaining vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a face incident to the edge is not triangular, if
+    /// both vertices of the edge are boundary vertices, if the collapse would
+    /// produce a non-manifold configuration, or if the collapse would fold
+    /// (invert) any of the remaining faces.
+    ///
+    /// # Examples
+    ///
+    /// Collapsing an edge between an interior vertex and a boundary vertex:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![
+    ///         Trigon::new(0usize, 1, 4),
+    ///         Trigon::new(1, 2, 4),
+    ///         Trigon::new(2, 3, 4),
+    ///         Trigon::new(3, 0, 4),
+    ///     ],
+    ///     vec![
+    ///         (0.0, 0.0, 0.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (1.0, 1.0, 0.0),
+    ///         (0.0, 1.0, 0.0),
+    ///         (0.5, 0.5, 0.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .unwrap()
+    ///     .key();
+    /// let vertex = graph.edge_mut(key).unwrap().collapse().unwrap();
+    ///
+    /// assert_eq!(3, vertex.valence());
+    /// assert_eq!(2, graph.face_count());
+    /// ```
+    pub fn collapse(self) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        G: EdgeMidpoint,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        self.collapse_with_policy(None)
+    }
+
+    /// Collapses the edge into one of its vertices if the valence of the
+    /// remaining vertex does not exceed the given maximum.
+    ///
+    /// Collapsing can produce vertices with arbitrarily high valence, which
+    /// degrades the quality of a mesh. This function behaves like
+    /// [`EdgeView::collapse`], but refuses collapses that would produce a
+    /// vertex with more than `max_valence` adjacent vertices.
+    ///
+    /// Returns the remaining vertex.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the valence of the remaining vertex would exceed
+    /// `max_valence` or under the conditions described by
+    /// [`EdgeView::collapse`].
+    ///
+    /// [`EdgeView::collapse`]: crate::graph::EdgeView::collapse
+    pub fn collapse_with_max_valence(
+        self,
+        max_valence: usize,
+    ) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        G: EdgeMidpoint,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        self.collapse_with_policy(Some(max_valence))
+    }
+
+    fn collapse_with_policy(
+        self,
+        max_valence: Option<usize>,
+    ) -> Result<VertexView<&'a mut M>, GraphError>
+    where
+        G: EdgeMidpoint,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let cache = EdgeCollapseCache::from_arc(self.arc(), max_valence)?;
+        let midpoint = self.midpoint();
+        // Reject the collapse if moving either vertex to the midpoint inverts
+        // any of the faces that remain after the collapse.
+        {
+            let arc = self.arc();
+            let excluded = [arc.face(), arc.opposite_arc().face()]
+                .into_iter()
+                .flatten()
+                .map(|face| face.key())
+                .collect::<ArrayVec<_, 2>>();
+            for vertex in [arc.source_vertex(), arc.destination_vertex()] {
+                let position = *vertex.position();
+                for arc in vertex.incoming_arcs().filter(|arc| {
+                    arc.face()
+                        .map_or(false, |face| !excluded.contains(&face.key()))
+                }) {
+                    let previous = *arc.source_vertex().position();
+                    let next = *arc.next_arc().destination_vertex().position();
+                    let before = (next - position).cross(previous - position);
+                    let after = (next - midpoint).cross(previous - midpoint);
+                    if before.dot(after) <= Zero::zero() {
+                        return Err(GraphError::Geometry);
+                    }
+                }
+            }
+        }
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| {
+                edge::collapse_with(mutation, cache, move |mut data| {
+                    *data.as_position_mut() = midpoint;
+                    data
+                })
+            })
+            .map(|(storage, a)| Bind::bind(storage, a).expect_consistent())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+}
+
 impl<B> Borrow<EdgeKey> for EdgeView<B>
 where
     B: Reborrow,
@@ -1866,12 +2355,13 @@ mod tests {
     use nalgebra::{Point2, Point3};
 
     use crate::geometry::FromGeometry;
-    use crate::graph::{ArcKey, GraphData, MeshGraph};
+    use crate::graph::{ArcKey, GraphData, GraphError, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
-    use crate::primitive::Tetragon;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::{Tetragon, Trigon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
@@ -1963,6 +2453,372 @@ mod tests {
         );
     }
 
+    #[test]
+    fn split_edge_faces() {
+        // Construct a graph with two triangles that share an edge.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let key = graph
+            .edges()
+            .find(|edge| !edge.is_boundary_edge())
+            .unwrap()
+            .key();
+        let vertex = graph.edge_mut(key).unwrap().split().unwrap().into_ref();
+
+        let midpoint: E2 = (0.5, 0.5).into_geometry();
+        assert_eq!(midpoint, *vertex.position());
+        assert_eq!(4, vertex.valence());
+        assert_eq!(4, vertex.adjacent_faces().count());
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn split_boundary_edge_faces() {
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0), (1.0, 0.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let key = graph.edges().nth(0).unwrap().key();
+        let vertex = graph.edge_mut(key).unwrap().split().unwrap().into_ref();
+
+        assert_eq!(3, vertex.valence());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn reject_inconsistently_oriented_faces() {
+        // A flipped face would occupy the arc of its neighbor and is rejected,
+        // so the faces of an edge are always consistently oriented.
+        assert!(MeshGraph::<E2>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 3, 2)],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn dihedral_angle() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        for edge in graph.edges() {
+            assert!((std::f64::consts::FRAC_PI_2 - edge.dihedral_angle().unwrap()).abs() < 1e-9);
+        }
+
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        for edge in graph.edges() {
+            if edge.is_boundary_edge() {
+                assert_eq!(
+                    GraphError::TopologyNotFound,
+                    edge.dihedral_angle().err().unwrap()
+                );
+            }
+            else {
+                assert!(edge.dihedral_angle().unwrap().abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn signed_dihedral_angle() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        for edge in graph.edges() {
+            let angle = edge.signed_dihedral_angle().unwrap();
+            assert!((std::f64::consts::FRAC_PI_2 - angle).abs() < 1e-9);
+        }
+
+        // Fold two triangles along their shared edge such that the edge is
+        // concave when viewed from above.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 1.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let edge = graph.edges().find(|edge| !edge.is_boundary_edge()).unwrap();
+        assert!(edge.signed_dihedral_angle().unwrap() < 0.0);
+        assert!(
+            (edge.dihedral_angle().unwrap() + edge.signed_dihedral_angle().unwrap()).abs() < 1e-9
+        );
+    }
+
+    #[test]
+    fn flip_edge() {
+        let mut graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        let ab = find_arc(&graph, ((0.0, 0.0), (1.0, 1.0))).unwrap();
+        let key = graph.arc_mut(ab).unwrap().into_edge().flip().unwrap().key();
+
+        // The flipped edge joins the vertices opposite the original edge.
+        let cd = find_arc(&graph, ((1.0, 0.0), (0.0, 1.0))).unwrap();
+        assert_eq!(key, graph.arc(cd).unwrap().edge().key());
+        assert!(find_arc(&graph, ((0.0, 0.0), (1.0, 1.0))).is_none());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn reject_flip_edge() {
+        // Flipping an interior edge of a tetrahedron would duplicate an edge.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                Trigon::new(0usize, 1, 2),
+                Trigon::new(0, 3, 1),
+                Trigon::new(1, 3, 2),
+                Trigon::new(2, 3, 0),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.0, 0.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let key = graph.edges().nth(0).unwrap().key();
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.edge_mut(key).unwrap().flip().err().unwrap()
+        );
+
+        // Quadrilaterals cannot be flipped.
+        let mut graph = MeshGraph::<E2>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 2, 3, 0, 3, 4, 5],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (0.0, 1.0),
+                (-1.0, 1.0),
+                (-1.0, 0.0),
+            ],
+            4,
+        )
+        .unwrap();
+        let ab = find_arc(&graph, ((0.0, 0.0), (0.0, 1.0))).unwrap();
+        assert_eq!(
+            GraphError::TopologyNotTriangular,
+            graph.arc_mut(ab).unwrap().into_edge().flip().err().unwrap()
+        );
+    }
+
+    #[test]
+    fn rotate_edge() {
+        // Construct a hexagonal patch about a center vertex with valence five.
+        // The edge between the vertices at indices 1 and 5 is irregular.
+        let graph = || {
+            MeshGraph::<Point2<f64>>::from_raw_buffers(
+                vec![
+                    Trigon::new(6usize, 1, 2),
+                    Trigon::new(6, 2, 3),
+                    Trigon::new(6, 3, 4),
+                    Trigon::new(6, 4, 5),
+                    Trigon::new(6, 5, 1),
+                    Trigon::new(5, 0, 1),
+                ],
+                vec![
+                    (2.0, 0.0),   // 0
+                    (1.0, 2.0),   // 1
+                    (-1.0, 2.0),  // 2
+                    (-2.0, 0.0),  // 3
+                    (-1.0, -2.0), // 4
+                    (1.0, -2.0),  // 5
+                    (0.0, 0.0),   // 6
+                ],
+            )
+            .unwrap()
+        };
+
+        // Rotating the irregular edge joins the center to the remaining
+        // boundary vertex and balances the valences of the patch.
+        let mut graph = graph();
+        let ab = find_arc(&graph, ((1.0, -2.0), (1.0, 2.0))).unwrap();
+        graph.arc_mut(ab).unwrap().into_edge().rotate().unwrap();
+        let center = graph
+            .vertices()
+            .find(|vertex| *vertex.position() == Point2::origin())
+            .unwrap();
+        assert_eq!(6, center.valence());
+        assert!(graph
+            .vertices()
+            .filter(|vertex| vertex.key() != center.key())
+            .all(|vertex| vertex.valence() == 3));
+
+        // Rotating a spoke of the center would unbalance the valences.
+        let mut graph = graph();
+        let ab = find_arc(&graph, ((0.0, 0.0), (-2.0, 0.0))).unwrap();
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.arc_mut(ab).unwrap().into_edge().rotate().err().unwrap()
+        );
+        assert_eq!(12, graph.edge_count());
+    }
+
+    #[test]
+    fn fill_boundary() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove().unwrap();
+
+        // Interior edges cannot be filled.
+        let key = graph
+            .edges()
+            .find(|edge| !edge.is_boundary_edge())
+            .unwrap()
+            .key();
+        assert_eq!(
+            Some(GraphError::TopologyMalformed),
+            graph.edge_mut(key).unwrap().fill_boundary().err()
+        );
+
+        let key = graph
+            .edges()
+            .find(|edge| edge.is_boundary_edge())
+            .unwrap()
+            .key();
+        let face = graph.edge_mut(key).unwrap().fill_boundary().unwrap();
+
+        assert_eq!(4, face.arity());
+        assert_eq!(6, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+        assert!(graph.validate().is_ok());
+    }
+
+    #[test]
+    fn collapse_edge() {
+        // Construct a graph with a single interior vertex. Note that all faces
+        // are triangles.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                Trigon::new(0usize, 3, 4),
+                Trigon::new(4, 1, 0),
+                Trigon::new(1, 4, 2),
+                Trigon::new(2, 4, 5),
+                Trigon::new(4, 3, 6),
+                Trigon::new(6, 7, 4),
+                Trigon::new(4, 7, 8),
+                Trigon::new(8, 5, 4),
+            ],
+            vec![
+                (0.0, 0.0, 0.0), // 0
+                (1.0, 0.0, 0.0), // 1
+                (2.0, 0.0, 0.0), // 2
+                (0.0, 1.0, 0.0), // 3
+                (1.0, 1.0, 0.0), // 4
+                (2.0, 1.0, 0.0), // 5
+                (0.0, 2.0, 0.0), // 6
+                (1.0, 2.0, 0.0), // 7
+                (2.0, 2.0, 0.0), // 8
+            ],
+        )
+        .unwrap();
+
+        // Collapse the edge between the interior vertex and a boundary vertex.
+        // The interior vertex is removed.
+        let ab = find_arc(&graph, ((1.0, 0.0, 0.0), (1.0, 1.0, 0.0))).unwrap();
+        let vertex = graph
+            .arc_mut(ab)
+            .unwrap()
+            .into_edge()
+            .collapse()
+            .unwrap()
+            .into_ref();
+
+        let expected: E3 = (1.0, 0.5, 0.0).into_geometry();
+        assert_eq!(expected, vertex.data);
+        assert_eq!(7, vertex.valence());
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+        assert_eq!(13, graph.edge_count());
+    }
+
+    #[test]
+    fn reject_collapse_edge_non_manifold() {
+        // Collapsing any edge of a tetrahedron would leave two coincident
+        // triangles.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                Trigon::new(0usize, 1, 2),
+                Trigon::new(0, 3, 1),
+                Trigon::new(1, 3, 2),
+                Trigon::new(2, 3, 0),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.0, 0.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let key = graph.edges().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.edge_mut(key).unwrap().collapse().err().unwrap()
+        );
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+    }
+
+    #[test]
+    fn reject_collapse_edge_max_valence() {
+        // Collapsing an edge between a pole (with valence 12) and the equator
+        // (with valence 4) produces a vertex with valence 12.
+        let mut graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(12, 2).polygons::<Position<E3>>().collect();
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.valence() == 12)
+            .unwrap()
+            .outgoing_arc()
+            .edge()
+            .key();
+
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph
+                .edge_mut(key)
+                .unwrap()
+                .collapse_with_max_valence(10)
+                .err()
+                .unwrap()
+        );
+        assert_eq!(14, graph.vertex_count());
+        assert_eq!(24, graph.face_count());
+
+        let vertex = graph
+            .edge_mut(key)
+            .unwrap()
+            .collapse_with_max_valence(12)
+            .unwrap();
+        assert_eq!(12, vertex.valence());
+    }
+
     #[test]
     fn remove_edge() {
         // Construct a graph with two connected quadrilaterals.
@@ -1995,4 +2851,35 @@ mod tests {
         // After the removal, the graph should have no faces.
         assert_eq!(0, graph.face_count());
     }
+
+    #[test]
+    fn set_arc_and_edge_data() {
+        enum Weight {}
+
+        impl GraphData for Weight {
+            type Vertex = [i32; 2];
+            type Arc = u32;
+            type Edge = u64;
+            type Face = ();
+        }
+
+        let mut graph = MeshGraph::<Weight>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![[0i32, 0], [1, 0], [0, 1]],
+        )
+        .unwrap();
+        let key = graph.arcs().next().unwrap().key();
+        let mut arc = graph.arc_mut(key).unwrap();
+
+        assert_eq!(0, arc.set_geometry(3));
+        assert_eq!(3, *arc.get());
+        let mut edge = arc.into_edge();
+        let edge_key = edge.key();
+
+        assert_eq!(0, edge.set_geometry(7));
+        assert_eq!(7, *edge.get());
+        assert_eq!(3, graph.arc(key).unwrap().data);
+        assert_eq!(7, graph.edge(edge_key).unwrap().data);
+        assert_eq!(0, graph.arc(key.into_opposite()).unwrap().data);
+    }
 }
@@ -1,12 +1,14 @@
 use derivative::Derivative;
+use num::ToPrimitive;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::cmp;
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
+use std::mem;
 use std::ops::{Deref, DerefMut};
 use theon::query::{Intersection, Line, Plane};
-use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use typenum::U3;
 
@@ -19,18 +21,18 @@ use crate::entity::traverse::{Adjacency, Breadth, Depth, Trace, TraceFirst, Trav
 use crate::entity::view::{Bind, ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
-use crate::graph::geometry::{FaceCentroid, FaceNormal, FacePlane, VertexPosition};
+use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge, EdgeView};
+use crate::graph::geometry::{FaceArea, FaceCentroid, FaceNormal, FacePlane, VertexPosition};
 use crate::graph::mutation::face::{
-    self, FaceBridgeCache, FaceExtrudeCache, FaceInsertCache, FacePokeCache, FaceRemoveCache,
-    FaceSplitCache,
+    self, FaceBridgeCache, FaceExtrudeCache, FaceFlipCache, FaceInsertCache, FacePokeCache,
+    FaceRemoveCache, FaceSplitCache,
 };
 use crate::graph::mutation::{self, Consistent, Immediate, Mutable};
 use crate::graph::path::Path;
 use crate::graph::vertex::{Vertex, VertexKey, VertexOrphan, VertexView};
 use crate::graph::{
-    Circulator, GraphError, MeshGraph, OptionExt as _, OrphanCirculator, ResultExt as _, Selector,
-    ViewCirculator,
+    CentroidWeighting, Circulator, GraphError, MeshGraph, OptionExt as _, OrphanCirculator,
+    ResultExt as _, Selector, ViewCirculator,
 };
 use crate::transact::{BypassOrCommit, Mutate};
 use crate::{DynamicArity, IteratorExt as _, StaticArity};
@@ -217,6 +219,11 @@ where
     {
         self.inner.get_mut()
     }
+
+    /// Replaces the data of the face and returns the previous data.
+    pub fn set_geometry(&mut self, data: G::Face) -> G::Face {
+        mem::replace(self.get_mut(), data)
+    }
 }
 
 /// Reachable API.
@@ -259,6 +266,38 @@ where
     }
 }
 
+impl<B, M, G> FaceView<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Converts the face into its first edge.
+    ///
+    /// See [`FaceView::first_edge`].
+    ///
+    /// [`FaceView::first_edge`]: crate::graph::FaceView::first_edge
+    pub fn into_first_edge(self) -> EdgeView<B> {
+        self.into_arc().into_edge()
+    }
+
+    /// Gets the first edge of the face.
+    ///
+    /// The first edge is the edge of the leading arc of the face. When a face
+    /// is inserted from a perimeter of vertices, its leading arc is the arc
+    /// from the first vertex to the second vertex of that perimeter. Iteration
+    /// over the arcs and vertices of a face begins with this arc, so the first
+    /// edge is stable for graphs constructed in the same way and determines
+    /// the ordering of operations like fan triangulation and exports.
+    pub fn first_edge(&self) -> EdgeView<&M> {
+        self.to_ref().into_first_edge()
+    }
+}
+
 impl<B, M, G> FaceView<B>
 where
     B: Reborrow<Target = M>,
@@ -277,6 +316,27 @@ where
         G::centroid(self.to_ref()).expect_consistent()
     }
 
+    /// Gets the centroid of the face with the given weighting of its vertex
+    /// positions.
+    ///
+    /// The [`Uniform`] weighting is the same as [`centroid`].
+    ///
+    /// Returns an error if the weights of the face sum to zero, such as the
+    /// area of a degenerate face.
+    ///
+    /// [`centroid`]: crate::graph::FaceView::centroid
+    /// [`Uniform`]: crate::graph::CentroidWeighting::Uniform
+    pub fn centroid_weighted(
+        &self,
+        weighting: CentroidWeighting,
+    ) -> Result<VertexPosition<G>, GraphError>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPosition,
+    {
+        G::centroid_weighted(self.to_ref(), weighting)
+    }
+
     pub fn normal(&self) -> Result<Vector<VertexPosition<G>>, GraphError>
     where
         G: FaceNormal,
@@ -285,6 +345,18 @@ where
         G::normal(self.to_ref())
     }
 
+    /// Gets the area of the face.
+    ///
+    /// The area of a face with any arity is computed from a triangle fan about
+    /// its centroid. Degenerate faces have zero area.
+    pub fn area(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: FaceArea,
+        G::Vertex: AsPosition,
+    {
+        G::area(self.to_ref())
+    }
+
     pub fn plane(&self) -> Result<Plane<VertexPosition<G>>, GraphError>
     where
         G: FacePlane,
@@ -293,6 +365,98 @@ where
     {
         G::plane(self.to_ref())
     }
+
+    /// Gets the barycentric coordinates of a point with respect to the
+    /// vertices of the face.
+    ///
+    /// The coordinates are ordered by the vertices of the face. For triangles,
+    /// these are conventional barycentric coordinates. For other polygons,
+    /// these are generalized (mean value) coordinates, which assume that the
+    /// face is convex. The point is projected into the face, so it should lie
+    /// in (or very near) the plane of the face.
+    ///
+    /// Returns `None` if the point lies outside of the face or the face is
+    /// degenerate.
+    pub fn barycentric(&self, point: VertexPosition<G>) -> Option<Vec<f64>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        const EPSILON: f64 = 1e-9;
+
+        // Only inner products are needed, so coordinates are computed from
+        // inner products converted into `f64`.
+        let dot = |u: Vector<VertexPosition<G>>, v: Vector<VertexPosition<G>>| u.dot(v).to_f64();
+        let triangle = |a: VertexPosition<G>, b: VertexPosition<G>, c: VertexPosition<G>| {
+            let (ab, ac, ap) = (b - a, c - a, point - a);
+            let (d00, d01, d11) = (dot(ab, ab)?, dot(ab, ac)?, dot(ac, ac)?);
+            let (d20, d21) = (dot(ap, ab)?, dot(ap, ac)?);
+            let denominator = (d00 * d11) - (d01 * d01);
+            if denominator.abs() <= EPSILON {
+                return None;
+            }
+            let v = ((d11 * d20) - (d01 * d21)) / denominator;
+            let w = ((d00 * d21) - (d01 * d20)) / denominator;
+            let u = 1.0 - v - w;
+            if u >= -EPSILON && v >= -EPSILON && w >= -EPSILON {
+                Some(vec![u, v, w])
+            }
+            else {
+                None
+            }
+        };
+
+        let positions = self
+            .adjacent_vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 4]>>();
+        let n = positions.len();
+        if n == 3 {
+            return triangle(positions[0], positions[1], positions[2]);
+        }
+        // The point is within the face if it is within any triangle of a fan
+        // about the leading vertex.
+        if !(1..(n - 1)).any(|i| triangle(positions[0], positions[i], positions[i + 1]).is_some()) {
+            return None;
+        }
+        let offsets = positions
+            .iter()
+            .map(|position| *position - point)
+            .collect::<SmallVec<[_; 4]>>();
+        let distances = offsets
+            .iter()
+            .map(|offset| dot(*offset, *offset).map(f64::sqrt))
+            .collect::<Option<SmallVec<[_; 4]>>>()?;
+        let unit = |i: usize| {
+            let mut weights = vec![0.0; n];
+            weights[i] = 1.0;
+            weights
+        };
+        if let Some(i) = distances.iter().position(|distance| *distance <= EPSILON) {
+            return Some(unit(i));
+        }
+        // Compute the tangent of half of the angle subtended by each arc. If
+        // the point lies on an arc, then interpolate its vertices linearly.
+        let mut tangents = SmallVec::<[_; 4]>::with_capacity(n);
+        for i in 0..n {
+            let j = (i + 1) % n;
+            let product = distances[i] * distances[j];
+            let cosine = dot(offsets[i], offsets[j])?;
+            if product + cosine <= EPSILON * product {
+                let mut weights = vec![0.0; n];
+                weights[i] = distances[j] / (distances[i] + distances[j]);
+                weights[j] = distances[i] / (distances[i] + distances[j]);
+                return Some(weights);
+            }
+            let sine = ((product * product) - (cosine * cosine)).max(0.0).sqrt();
+            tangents.push(sine / (product + cosine));
+        }
+        let weights = (0..n)
+            .map(|i| (tangents[(i + n - 1) % n] + tangents[i]) / distances[i])
+            .collect::<Vec<_>>();
+        let sum = weights.iter().sum::<f64>();
+        Some(weights.into_iter().map(|weight| weight / sum).collect())
+    }
 }
 
 impl<B, M, G> FaceView<B>
@@ -659,7 +823,7 @@ where
     /// Returns an error if the destination face cannot be found or the arity of
     /// the face and its destination are not the same.
     pub fn bridge(self, destination: FaceKey) -> Result<(), GraphError> {
-        let cache = FaceBridgeCache::from_face(self.to_ref(), destination)?;
+        let cache = FaceBridgeCache::from_face(self.to_ref(), destination, 0)?;
         let (storage, _) = self.unbind();
         Mutation::take(storage)
             .bypass_or_commit_with(|mutation| face::bridge(mutation, cache))
@@ -857,6 +1021,32 @@ where
             .expect_consistent()
     }
 
+    /// Reverses the winding of the face.
+    ///
+    /// The face is reinserted into the opposite arcs of its perimeter, so its
+    /// vertices are visited in reverse order and its normal is negated. The
+    /// face retains its data, but not necessarily its key.
+    ///
+    /// Returns the flipped face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the opposite arcs of the face are already
+    /// occupied by another face. Flipping such a face would produce
+    /// inconsistently oriented neighbors; use [`MeshGraph::flip_orientation`]
+    /// to reverse all faces at once.
+    ///
+    /// [`MeshGraph::flip_orientation`]: crate::graph::MeshGraph::flip_orientation
+    pub fn flip(self) -> Result<Self, GraphError> {
+        let cache = FaceFlipCache::from_face(self.to_ref())?;
+        let (storage, _) = self.unbind();
+        Ok(Mutation::take(storage)
+            .bypass_or_commit_with(|mutation| face::flip(mutation, cache))
+            .map(|(storage, face)| Bind::bind(storage, face).expect_consistent())
+            .map_err(|(_, error)| error)
+            .expect_consistent())
+    }
+
     /// Removes the face.
     ///
     /// Returns the remaining ring of the face if it is not entirely disjoint, otherwise `None`.
@@ -1523,9 +1713,7 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        // This requires consistency, because an inconsistent graph may not
-        // produce the expected minimum of three vertices.
-        (3, None)
+        (self.inner.lower_bound(), None)
     }
 }
 
@@ -1540,9 +1728,7 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        // This requires consistency, because an inconsistent graph may not
-        // produce the expected minimum of three vertices.
-        (3, None)
+        (self.inner.lower_bound(), None)
     }
 }
 
@@ -1572,6 +1758,21 @@ where
     storage: B,
     arc: Option<ArcKey>,
     trace: TraceFirst<ArcKey>,
+    visited: usize,
+}
+
+impl<B, M, G> ArcCirculator<B>
+where
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>> + Consistent + Parametric<Data = G>,
+    G: GraphData,
+{
+    // Rings in consistent storage have at least three arcs, so the lower bound
+    // is three less any arcs that have already been visited. The arity of the
+    // ring is not known without walking it, so no upper bound is given.
+    fn lower_bound(&self) -> usize {
+        3usize.saturating_sub(self.visited)
+    }
 }
 
 impl<B, M, G> Circulator<B> for ArcCirculator<B>
@@ -1586,6 +1787,7 @@ where
         self.arc
             .and_then(|arc| self.trace.insert(arc).then_some(arc))
             .map(|arc| {
+                self.visited += 1;
                 self.arc = self
                     .storage
                     .reborrow()
@@ -1608,6 +1810,7 @@ where
             storage: self.storage.clone(),
             arc: self.arc,
             trace: self.trace,
+            visited: self.visited,
         }
     }
 }
@@ -1624,6 +1827,7 @@ where
             storage,
             arc: Some(key),
             trace: Default::default(),
+            visited: 0,
         }
     }
 }
@@ -1640,9 +1844,7 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        // This requires consistency, because an inconsistent graph may not
-        // produce the expected minimum of three arcs.
-        (3, None)
+        (self.lower_bound(), None)
     }
 }
 
@@ -1657,9 +1859,7 @@ where
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        // This requires consistency, because an inconsistent graph may not
-        // produce the expected minimum of three arcs.
-        (3, None)
+        (self.lower_bound(), None)
     }
 }
 
@@ -1788,20 +1988,284 @@ where
 
 #[cfg(test)]
 mod tests {
+    use approx::abs_diff_eq;
     use decorum::R64;
     use nalgebra::{Point2, Point3};
 
-    use crate::graph::MeshGraph;
+    use crate::graph::{CentroidWeighting, GraphData, GraphError, MeshGraph};
     use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::cube::Cube;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
-    use crate::primitive::Tetragon;
+    use crate::primitive::{NGon, Tetragon, Trigon, UnboundedPolygon};
 
     type E2 = Point2<R64>;
     type E3 = Point3<R64>;
 
+    #[test]
+    fn first_edge() {
+        let positions = vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)];
+        let endpoints = |graph: &MeshGraph<Point2<f64>>| {
+            let arc = graph.faces().nth(0).unwrap().first_edge().into_arc();
+            let arc = if arc.is_boundary_arc() {
+                arc.into_opposite_arc()
+            }
+            else {
+                arc
+            };
+            (*arc.source_vertex().position(), *arc.destination_vertex().position())
+        };
+
+        // The first edge is formed from the first and second vertices of the
+        // perimeter of the face and is the same for equivalent graphs.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            positions.clone(),
+        )
+        .unwrap();
+        let face = graph.faces().nth(0).unwrap();
+        assert_eq!(face.arc().edge().key(), face.first_edge().key());
+        assert_eq!(
+            face.adjacent_arcs().nth(0).unwrap().edge().key(),
+            face.first_edge().key()
+        );
+        assert_eq!((Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)), endpoints(&graph));
+        let other = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            positions.clone(),
+        )
+        .unwrap();
+        assert_eq!(endpoints(&graph), endpoints(&other));
+
+        // Rotating the perimeter of the face rotates its first edge.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(2usize, 3, 0, 1)],
+            positions,
+        )
+        .unwrap();
+        assert_eq!((Point2::new(1.0, 1.0), Point2::new(0.0, 1.0)), endpoints(&graph));
+    }
+
+    #[test]
+    fn first_edge_stable() {
+        // Gets the endpoints of the arc of the first edge in each face, ordered
+        // by the centroids of the faces.
+        fn endpoints(graph: &MeshGraph<Point2<f64>>) -> Vec<(Point2<f64>, Point2<f64>)> {
+            let mut faces = graph.faces().collect::<Vec<_>>();
+            faces.sort_by(|a, b| a.centroid().x.partial_cmp(&b.centroid().x).unwrap());
+            faces
+                .into_iter()
+                .map(|face| {
+                    let arc = face.first_edge().into_arc();
+                    let arc = if arc.face().map(|face| face.key()) == Some(face.key()) {
+                        arc
+                    }
+                    else {
+                        arc.into_opposite_arc()
+                    };
+                    (*arc.source_vertex().position(), *arc.destination_vertex().position())
+                })
+                .collect()
+        }
+
+        // Two quadrilaterals that share an edge.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 4, 3), Tetragon::new(1, 2, 5, 4)],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let expected = vec![
+            (Point2::new(0.0, 0.0), Point2::new(1.0, 0.0)),
+            (Point2::new(1.0, 0.0), Point2::new(2.0, 0.0)),
+        ];
+        assert_eq!(expected, endpoints(&graph));
+
+        // Clones have the same first edges.
+        assert_eq!(expected, endpoints(&graph.clone()));
+
+        // Shuffling the vertex buffer and the order of faces does not change
+        // the first edges so long as each perimeter begins with the same
+        // vertex.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Tetragon::new(3usize, 1, 0, 5), Tetragon::new(4, 3, 5, 2)],
+            vec![
+                (2.0, 1.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 0.0),
+                (0.0, 0.0),
+                (1.0, 1.0),
+            ],
+        )
+        .unwrap();
+        assert_eq!(expected, endpoints(&graph));
+    }
+
+    #[test]
+    fn area() {
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::tetragon(0usize, 1, 2, 3),
+                UnboundedPolygon::trigon(1, 4, 2),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (2.0, 2.0, 0.0),
+                (0.0, 2.0, 0.0),
+                (3.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let mut areas = graph
+            .faces()
+            .map(|face| face.area().unwrap())
+            .collect::<Vec<_>>();
+        areas.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert!(abs_diff_eq!(1.0, areas[0], epsilon = 1e-9));
+        assert!(abs_diff_eq!(4.0, areas[1], epsilon = 1e-9));
+
+        // Degenerate faces have no area.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![UnboundedPolygon::trigon(0usize, 1, 2)],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (2.0, 0.0, 0.0)],
+        )
+        .unwrap();
+        assert_eq!(0.0, graph.faces().nth(0).unwrap().area().unwrap());
+    }
+
+    #[test]
+    fn centroid_weighted() {
+        // A square with an additional vertex along its lower edge.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3, 4])],
+            vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)],
+        )
+        .unwrap();
+        let face = graph.faces().nth(0).unwrap();
+
+        assert_eq!(
+            face.centroid(),
+            face.centroid_weighted(CentroidWeighting::Uniform).unwrap()
+        );
+        let centroid = face.centroid();
+        assert!(abs_diff_eq!(1.0, centroid.x, epsilon = 1e-9));
+        assert!(abs_diff_eq!(0.8, centroid.y, epsilon = 1e-9));
+        // The area and perimeter are not affected by the additional vertex.
+        for weighting in [CentroidWeighting::EdgeLength, CentroidWeighting::TriangleArea] {
+            let centroid = face.centroid_weighted(weighting).unwrap();
+            assert!(abs_diff_eq!(1.0, centroid.x, epsilon = 1e-9));
+            assert!(abs_diff_eq!(1.0, centroid.y, epsilon = 1e-9));
+        }
+
+        // The centroid of the perimeter of a triangle differs from the
+        // centroid of its area.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0), (4.0, 0.0), (0.0, 3.0)],
+        )
+        .unwrap();
+        let face = graph.faces().nth(0).unwrap();
+        let centroid = face
+            .centroid_weighted(CentroidWeighting::EdgeLength)
+            .unwrap();
+        assert!(abs_diff_eq!(1.5, centroid.x, epsilon = 1e-9));
+        assert!(abs_diff_eq!(1.0, centroid.y, epsilon = 1e-9));
+        let centroid = face
+            .centroid_weighted(CentroidWeighting::TriangleArea)
+            .unwrap();
+        assert!(abs_diff_eq!(4.0 / 3.0, centroid.x, epsilon = 1e-9));
+        assert!(abs_diff_eq!(1.0, centroid.y, epsilon = 1e-9));
+
+        // The fan of a non-convex face contains inverted triangles, which are
+        // subtracted from the area.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3, 4])],
+            vec![(0.0, 0.0), (4.0, 0.0), (4.0, 4.0), (2.0, 1.0), (0.0, 4.0)],
+        )
+        .unwrap();
+        let face = graph.faces().nth(0).unwrap();
+        let centroid = face.centroid();
+        assert!(abs_diff_eq!(2.0, centroid.x, epsilon = 1e-9));
+        assert!(abs_diff_eq!(1.8, centroid.y, epsilon = 1e-9));
+        let centroid = face
+            .centroid_weighted(CentroidWeighting::TriangleArea)
+            .unwrap();
+        assert!(abs_diff_eq!(2.0, centroid.x, epsilon = 1e-9));
+        assert!(abs_diff_eq!(1.4, centroid.y, epsilon = 1e-9));
+
+        // Degenerate faces have no area.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0), (1.0, 0.0), (2.0, 0.0)],
+        )
+        .unwrap();
+        assert_eq!(
+            Err(GraphError::Geometry),
+            graph
+                .faces()
+                .nth(0)
+                .unwrap()
+                .centroid_weighted(CentroidWeighting::TriangleArea)
+        );
+    }
+
+    #[test]
+    fn barycentric() {
+        // Construct a graph with a square and a triangle.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::tetragon(0usize, 1, 2, 3),
+                UnboundedPolygon::trigon(1, 4, 2),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (2.0, 0.5, 0.0),
+            ],
+        )
+        .unwrap();
+        let (triangle, square) = {
+            let mut faces = graph.faces().collect::<Vec<_>>();
+            faces.sort_by_key(|face| face.arity());
+            (faces[0], faces[1])
+        };
+
+        // The centroid of a triangle has equal weights.
+        let weights = triangle.barycentric(triangle.centroid()).unwrap();
+        assert_eq!(3, weights.len());
+        for weight in weights {
+            assert!(abs_diff_eq!(1.0 / 3.0, weight, epsilon = 1e-9));
+        }
+        // The centroid of a square has equal weights.
+        let weights = square.barycentric(square.centroid()).unwrap();
+        assert_eq!(4, weights.len());
+        for weight in weights {
+            assert!(abs_diff_eq!(0.25, weight, epsilon = 1e-9));
+        }
+        // A vertex has a weight of one.
+        let weights = triangle.barycentric(Point3::new(2.0, 0.5, 0.0)).unwrap();
+        assert!(abs_diff_eq!(
+            1.0,
+            weights.iter().cloned().fold(0.0, f64::max),
+            epsilon = 1e-9
+        ));
+        // Points outside of a face have no weights.
+        assert!(triangle.barycentric(Point3::new(0.0, 0.0, 0.0)).is_none());
+        assert!(square.barycentric(Point3::new(-0.5, 0.5, 0.0)).is_none());
+    }
+
     #[test]
     fn circulate_over_arcs() {
         let graph: MeshGraph<E3> = UvSphere::new(3, 2)
@@ -1811,6 +2275,12 @@ mod tests {
 
         // All faces should be triangles and should have three edges.
         assert_eq!(3, face.adjacent_arcs().count());
+        // Circulators over rings report a lower bound of three.
+        let mut arcs = face.adjacent_arcs();
+        assert_eq!((3, None), arcs.size_hint());
+        arcs.next();
+        assert_eq!((2, None), arcs.size_hint());
+        assert_eq!((3, None), face.adjacent_vertices().size_hint());
     }
 
     #[test]
@@ -1952,6 +2422,29 @@ mod tests {
         assert_eq!(3, vertex.adjacent_faces().count());
     }
 
+    #[test]
+    fn flip_face() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        let key = graph.faces().nth(0).unwrap().key();
+        let face = graph.face_mut(key).unwrap().flip().unwrap();
+
+        assert!(abs_diff_eq!(-1.0, face.normal().unwrap().z, epsilon = 1e-9));
+        assert_eq!(Ok(()), graph.validate());
+
+        // Faces with neighbors cannot be flipped independently.
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        assert_eq!(
+            GraphError::TopologyConflict,
+            graph.face_mut(key).unwrap().flip().err().unwrap()
+        );
+    }
+
     #[test]
     fn triangulate_mesh() {
         let (indices, vertices) = Cube::new()
@@ -1986,4 +2479,28 @@ mod tests {
         assert_eq!(1, ring.shortest_logical_metric(keys[0], keys[3]).unwrap());
         assert_eq!(0, ring.shortest_logical_metric(keys[0], keys[0]).unwrap());
     }
+
+    #[test]
+    fn set_face_data() {
+        enum MaterialId {}
+
+        impl GraphData for MaterialId {
+            type Vertex = [i32; 2];
+            type Arc = ();
+            type Edge = ();
+            type Face = u64;
+        }
+
+        let mut graph = MeshGraph::<MaterialId>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![[0i32, 0], [1, 0], [1, 1], [0, 1]],
+        )
+        .unwrap();
+        let key = graph.faces().next().unwrap().key();
+        let mut face = graph.face_mut(key).unwrap();
+
+        assert_eq!(0, face.set_geometry(7));
+        assert_eq!(7, *face.get());
+        assert_eq!(7, graph.face(key).unwrap().data);
+    }
 }
@@ -7,9 +7,11 @@
 // necessary, constraints are specified there so that they do not pollute user
 // code.
 
+use num::{NumCast, One, Zero};
+use smallvec::SmallVec;
 use theon::ops::{Cross, Interpolate, Project};
 use theon::query::Plane;
-use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Vector, VectorSpace};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector, VectorSpace};
 use theon::{AsPosition, Position};
 use typenum::U3;
 
@@ -20,7 +22,7 @@ use crate::graph::edge::{Arc, ArcView, Edge, ToArc};
 use crate::graph::face::{Face, ToRing};
 use crate::graph::mutation::Consistent;
 use crate::graph::vertex::{Vertex, VertexView};
-use crate::graph::{GraphError, OptionExt as _, ResultExt as _};
+use crate::graph::{CentroidWeighting, GraphError, OptionExt as _, ResultExt as _, VertexAttribute};
 use crate::IteratorExt as _;
 
 pub type VertexPosition<G> = Position<<G as GraphData>::Vertex>;
@@ -56,7 +58,7 @@ where
     }
 }
 
-pub trait VertexNormal: FaceNormal
+pub trait VertexNormal: FaceArea + FaceNormal
 where
     Self::Vertex: AsPosition,
 {
@@ -72,9 +74,14 @@ where
 
 impl<G> VertexNormal for G
 where
-    G: FaceNormal,
+    G: FaceArea + FaceNormal,
     G::Vertex: AsPosition,
 {
+    // Normals in vertex data are normalized and otherwise used as is. If there
+    // is no such normal, then the normals of adjacent faces are weighted by
+    // the area of those faces, so that small faces (often slivers) do not skew
+    // the normal. Arcs of a boundary vertex that have no face do not
+    // contribute. Degenerate faces have no normal and are skipped.
     fn normal<B>(vertex: VertexView<B>) -> Result<Vector<VertexPosition<Self>>, GraphError>
     where
         B: Reborrow,
@@ -84,15 +91,21 @@ where
             + Consistent
             + Parametric<Data = Self>,
     {
-        Vector::<VertexPosition<Self>>::mean(
-            vertex
-                .adjacent_faces()
-                .map(<Self as FaceNormal>::normal)
-                .collect::<Result<Vec<_>, _>>()?,
-        )
-        .expect_consistent()
-        .normalize()
-        .ok_or(GraphError::Geometry)
+        if let Some(normal) = Self::vertex_normal(vertex.get()) {
+            return normal.normalize().ok_or(GraphError::Geometry);
+        }
+        vertex
+            .adjacent_faces()
+            .filter_map(|face| match <Self as FaceNormal>::normal(face) {
+                Ok(normal) => Some(<Self as FaceArea>::area(face).map(|area| normal * area)),
+                Err(GraphError::Geometry) => None,
+                Err(error) => Some(Err(error)),
+            })
+            .collect::<Result<SmallVec<[_; 8]>, GraphError>>()?
+            .into_iter()
+            .reduce(|sum, normal| sum + normal)
+            .and_then(|normal| normal.normalize())
+            .ok_or(GraphError::Geometry)
     }
 }
 
@@ -184,6 +197,16 @@ where
         B::Target:
             AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
         T: ToRing<B>;
+
+    fn centroid_weighted<B, T>(
+        ring: T,
+        weighting: CentroidWeighting,
+    ) -> Result<VertexPosition<Self>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>;
 }
 
 impl<G> FaceCentroid for G
@@ -204,6 +227,95 @@ where
                 .expect_consistent(),
         )
     }
+
+    // Weighted centroids are computed as a weighted mean of translations from
+    // the uniform centroid. If the weights sum to zero, then the face is
+    // degenerate and there is no weighted centroid.
+    fn centroid_weighted<B, T>(
+        ring: T,
+        weighting: CentroidWeighting,
+    ) -> Result<VertexPosition<Self>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>,
+    {
+        let ring = ring.into_ring();
+        let positions = ring
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 4]>>();
+        let c = Self::centroid(ring)?;
+        let zero = Scalar::<VertexPosition<Self>>::zero();
+        let one = Scalar::<VertexPosition<Self>>::one();
+        let segments = positions
+            .iter()
+            .zip(positions.iter().cycle().skip(1))
+            .map(|(a, b)| (*a - c, *b - c));
+        let translations = match weighting {
+            CentroidWeighting::Uniform => {
+                return Ok(c);
+            }
+            CentroidWeighting::EdgeLength => segments
+                .map(|(u, w)| {
+                    // The centroid of an edge is its midpoint.
+                    let length = (w - u).magnitude();
+                    ((u + w) * (length / (one + one)), length)
+                })
+                .collect::<SmallVec<[_; 4]>>(),
+            CentroidWeighting::TriangleArea => {
+                // Triangles are projected into a plane spanned by the
+                // translation furthest from the centroid and the furthest
+                // component of the translations orthogonal to it. Areas are
+                // signed, so that triangles that are inverted in the fan of a
+                // non-convex face are subtracted. The basis is not normalized,
+                // which scales the area of all triangles equally and so does
+                // not affect the mean.
+                let furthest = |u: Vector<VertexPosition<Self>>, w: Vector<VertexPosition<Self>>| {
+                    if w.dot(w) > u.dot(u) {
+                        w
+                    }
+                    else {
+                        u
+                    }
+                };
+                let x = positions
+                    .iter()
+                    .map(|position| *position - c)
+                    .reduce(furthest)
+                    .expect_consistent();
+                let base = x.dot(x);
+                if base == zero {
+                    return Err(GraphError::Geometry);
+                }
+                let y = positions
+                    .iter()
+                    .map(|position| {
+                        let u = *position - c;
+                        u - (x * (x.dot(u) / base))
+                    })
+                    .reduce(furthest)
+                    .expect_consistent();
+                segments
+                    .map(|(u, w)| {
+                        let area = (u.dot(x) * w.dot(y)) - (u.dot(y) * w.dot(x));
+                        ((u + w) * (area / (one + one + one)), area)
+                    })
+                    .collect::<SmallVec<[_; 4]>>()
+            }
+        };
+        let (translation, weight) = translations
+            .into_iter()
+            .reduce(|(sum, total), (translation, weight)| (sum + translation, total + weight))
+            .expect_consistent();
+        if weight == zero {
+            Err(GraphError::Geometry)
+        }
+        else {
+            Ok(c + (translation * (one / weight)))
+        }
+    }
 }
 
 pub trait FaceNormal: GraphData
@@ -246,6 +358,122 @@ where
     }
 }
 
+pub trait FaceArea: GraphData
+where
+    Self::Vertex: AsPosition,
+{
+    fn area<B, T>(ring: T) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>;
+}
+
+impl<G> FaceArea for G
+where
+    G: FaceCentroid + GraphData,
+    G::Vertex: AsPosition,
+    Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    // The area is computed by triangulating the ring about its centroid, so
+    // non-planar and non-convex rings are approximated.
+    fn area<B, T>(ring: T) -> Result<Scalar<VertexPosition<Self>>, GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>,
+    {
+        let ring = ring.into_ring();
+        let positions = ring
+            .vertices()
+            .map(|vertex| *vertex.position())
+            .collect::<SmallVec<[_; 4]>>();
+        let c = G::centroid(ring)?;
+        let area = positions
+            .iter()
+            .zip(positions.iter().cycle().skip(1))
+            .map(|(a, b)| (*a - c).cross(*b - c).magnitude())
+            .fold(Scalar::<VertexPosition<Self>>::zero(), |sum, area| sum + area);
+        let two = Scalar::<VertexPosition<Self>>::one() + One::one();
+        Ok(area / two)
+    }
+}
+
+pub trait FaceTangent: GraphData
+where
+    Self::Vertex: AsPosition,
+{
+    /// Gets the tangent and bitangent of a face given texture coordinates for
+    /// its vertices.
+    ///
+    /// The tangent and bitangent are aligned with the $u$ and $v$ directions
+    /// of the texture coordinates, respectively, and are not normalized.
+    fn tangent<B, T>(
+        ring: T,
+        uvs: &VertexAttribute<[f32; 2]>,
+    ) -> Result<(Vector<VertexPosition<Self>>, Vector<VertexPosition<Self>>), GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>;
+}
+
+impl<G> FaceTangent for G
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    // This follows Lengyel's method and only considers the first three
+    // vertices of the ring. The texture coordinates of faces with a greater
+    // arity are assumed to be an affine mapping of the plane of the face.
+    fn tangent<B, T>(
+        ring: T,
+        uvs: &VertexAttribute<[f32; 2]>,
+    ) -> Result<(Vector<VertexPosition<Self>>, Vector<VertexPosition<Self>>), GraphError>
+    where
+        B: Reborrow,
+        B::Target:
+            AsStorage<Arc<Self>> + AsStorage<Vertex<Self>> + Consistent + Parametric<Data = Self>,
+        T: ToRing<B>,
+    {
+        let into_scalar = |x: f32| -> Result<Scalar<VertexPosition<Self>>, GraphError> {
+            <Scalar<VertexPosition<Self>> as NumCast>::from(x).ok_or(GraphError::Geometry)
+        };
+        let ring = ring.into_ring();
+        let ((a, ua, va), (b, ub, vb), (c, uc, vc)) = ring
+            .vertices()
+            .take(3)
+            .map(|vertex| -> Result<_, GraphError> {
+                let [u, v] = *uvs
+                    .get(&vertex.key())
+                    .ok_or(GraphError::TopologyNotFound)?;
+                Ok((*vertex.position(), into_scalar(u)?, into_scalar(v)?))
+            })
+            .collect::<Result<SmallVec<[_; 3]>, GraphError>>()?
+            .into_iter()
+            .try_collect()
+            .expect_consistent();
+        let ab = b - a;
+        let ac = c - a;
+        let (ub, vb) = (ub - ua, vb - va);
+        let (uc, vc) = (uc - ua, vc - va);
+        let determinant = (ub * vc) - (uc * vb);
+        if determinant.is_zero() {
+            // The texture coordinates are degenerate.
+            return Err(GraphError::Geometry);
+        }
+        let r = Scalar::<VertexPosition<Self>>::one() / determinant;
+        let tangent = ((ab * vc) - (ac * vb)) * r;
+        let bitangent = ((ac * ub) - (ab * uc)) * r;
+        Ok((tangent, bitangent))
+    }
+}
+
 pub trait FacePlane: GraphData
 where
     Self::Vertex: AsPosition,
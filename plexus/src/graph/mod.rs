@@ -253,60 +253,76 @@ mod face;
 mod geometry;
 mod mutation;
 mod path;
+mod subdivision;
 mod vertex;
 
 use decorum::cmp::IntrinsicOrd;
 use decorum::R64;
-use num::{Integer, NumCast, ToPrimitive, Unsigned};
-use smallvec::SmallVec;
+use num::{Integer, NumCast, ToPrimitive, Unsigned, Zero};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use smallvec::{smallvec, SmallVec};
 use std::borrow::Borrow;
-use std::collections::{HashMap, HashSet};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet};
 use std::convert::TryFrom;
 use std::fmt::Debug;
 use std::hash::Hash;
-use std::iter::FromIterator;
+#[cfg(any(feature = "encoding-obj", feature = "encoding-ply", feature = "encoding-stl"))]
+use std::io::{self, Write};
+use std::iter::{self, FromIterator};
 use std::mem;
 use std::vec;
 use theon::adjunct::Map;
-use theon::query::Aabb;
-use theon::space::{EuclideanSpace, Scalar};
+use theon::ops::{Cross, Interpolate};
+use theon::query::{Aabb, Plane};
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
 use theon::{AsPosition, AsPositionMut};
 use thiserror::Error;
-use typenum::NonZero;
+use typenum::{NonZero, U3};
 
 use crate::buffer::{BufferError, FromRawBuffers, FromRawBuffersWithArity, MeshBuffer};
 use crate::builder::{Buildable, FacetBuilder, MeshBuilder, SurfaceBuilder};
 use crate::constant::{Constant, ToType, TypeOf};
+#[cfg(feature = "encoding-obj")]
+use crate::encoding::obj;
+#[cfg(feature = "encoding-ply")]
+use crate::encoding::ply::{self, PlyFormat};
+#[cfg(feature = "encoding-stl")]
+use crate::encoding::stl::{self, StlFormat};
 use crate::encoding::{FaceDecoder, FromEncoding, VertexDecoder};
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{AsStorage, AsStorageMut, AsStorageOf, Key, StorageTarget};
 use crate::entity::view::{Bind, Orphan, View};
 use crate::entity::{Entity, EntityError, Payload};
-use crate::geometry::{FromGeometry, IntoGeometry};
+use crate::geometry::{FromGeometry, IntoGeometry, Transform};
 use crate::graph::builder::GraphBuilder;
 use crate::graph::core::{Core, OwnedCore};
 use crate::graph::data::Parametric;
 use crate::graph::edge::{Arc, Edge};
 use crate::graph::face::Face;
-use crate::graph::mutation::face::FaceInsertCache;
+use crate::graph::mutation::face::{FaceBridgeCache, FaceInsertCache};
 use crate::graph::mutation::{Consistent, Immediate};
 use crate::graph::vertex::Vertex;
-use crate::index::{Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer};
+use crate::index::{
+    Flat, FromIndexer, Grouping, HashIndexer, IndexBuffer, IndexVertices, Indexer, SpatialIndexer,
+};
 use crate::primitive::decompose::IntoVertices;
-use crate::primitive::{IntoPolygons, Polygonal, UnboundedPolygon};
-use crate::transact::Transact;
-use crate::{DynamicArity, MeshArity, StaticArity};
+use crate::primitive::{IntoPolygons, Polygonal, Trigon, UnboundedPolygon};
+use crate::transact::{BypassOrCommit, Mutate, Transact};
+use crate::{DynamicArity, IteratorExt as _, MeshArity, StaticArity};
 
 pub use crate::entity::view::{ClosedView, Rebind};
 pub use crate::graph::data::GraphData;
 pub use crate::graph::edge::{ArcKey, ArcOrphan, ArcView, EdgeKey, EdgeOrphan, EdgeView, ToArc};
 pub use crate::graph::face::{FaceKey, FaceOrphan, FaceView, Ring, ToRing};
 pub use crate::graph::geometry::{
-    ArcNormal, EdgeMidpoint, FaceCentroid, FaceNormal, FacePlane, VertexCentroid, VertexNormal,
-    VertexPosition,
+    ArcNormal, EdgeMidpoint, FaceArea, FaceCentroid, FaceNormal, FacePlane, FaceTangent,
+    VertexCentroid, VertexNormal, VertexPosition,
 };
 pub use crate::graph::path::Path;
+pub use crate::graph::subdivision::{CatmullClark, FacePoints, Loop, SubdivisionScheme};
 pub use crate::graph::vertex::{VertexKey, VertexOrphan, VertexView};
 
 pub use Selector::ByIndex;
@@ -314,6 +330,18 @@ pub use Selector::ByKey;
 
 type Mutation<M> = mutation::Mutation<Immediate<M>>;
 
+/// Attribute data associated with vertices in a [`MeshGraph`].
+///
+/// Vertex attributes are keyed by [`VertexKey`] and are stored separately from
+/// the graph's vertex data. They are useful for data that is computed from
+/// or required by a graph but is not part of its [`GraphData`], such as
+/// texture coordinates or tangents.
+///
+/// [`GraphData`]: crate::graph::GraphData
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`VertexKey`]: crate::graph::VertexKey
+pub type VertexAttribute<T> = HashMap<VertexKey, T>;
+
 /// Errors concerning [`MeshGraph`]s.
 ///
 /// [`MeshGraph`]: crate::graph::MeshGraph
@@ -327,6 +355,9 @@ pub enum GraphError {
     TopologyMalformed,
     #[error("topology unreachable")]
     TopologyUnreachable,
+    /// Topology is not composed of triangles as required by an operation.
+    #[error("topology is not triangular")]
+    TopologyNotTriangular,
     #[error("arity is non-polygonal")]
     ArityNonPolygonal,
     /// The arity of a [`MeshGraph`] or other data structure is not compatible
@@ -350,6 +381,14 @@ pub enum GraphError {
     /// Geometry is incompatible or cannot be computed.
     #[error("geometric operation failed")]
     Geometry,
+    /// The lengths of buffers that must be parallel differ.
+    #[error("conflicting buffer length; expected {expected}, but got {actual}")]
+    BufferLengthConflict {
+        /// The expected length.
+        expected: usize,
+        /// The incompatible length that was encountered.
+        actual: usize,
+    },
     /// A graph or other data structure is not compatible with an encoding.
     #[error("encoding operation failed")]
     EncodingIncompatible,
@@ -450,6 +489,11 @@ where
     /// implementation uses [`TraceAny`] to detect and reject keys previously
     /// seen over the lifetime of the circulator.
     ///
+    /// The transmutation here only extends the lifetime of a mutable
+    /// reference to that of the exclusive borrow of the storage held by the
+    /// circulator. It cannot be used to share data between threads and does
+    /// not affect whether or not a graph is `Send` or `Sync`.
+    ///
     /// [`Circulator::next`]: crate::graph::Circulator::next
     /// [`TraceAny`]: crate::entity::traverse::TraceAny
     unsafe fn bind_next_orphan<T>(&mut self) -> Option<T>
@@ -559,6 +603,103 @@ impl<K> From<usize> for Selector<K> {
     }
 }
 
+/// Treatment of boundary vertices when smoothing a [`MeshGraph`].
+///
+/// See [`MeshGraph::smooth_laplacian`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::smooth_laplacian`]: crate::graph::MeshGraph::smooth_laplacian
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum SmoothBoundary {
+    /// Boundary vertices are not moved.
+    Fixed,
+    /// Boundary vertices are moved toward their adjacent boundary vertices
+    /// only, such that they are smoothed along the boundary.
+    Along,
+}
+
+/// Triangulation of faces when exporting a [`MeshGraph`] to flat buffers.
+///
+/// See [`MeshGraph::to_flat_buffers`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::to_flat_buffers`]: crate::graph::MeshGraph::to_flat_buffers
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Triangulation {
+    /// Faces are fanned from their leading vertex. This ignores geometry.
+    Fan,
+    /// Quadrilaterals are split along their shortest diagonal, which
+    /// typically produces better shaped triangles. Other faces are fanned.
+    ShortestDiagonal,
+}
+
+/// Weighting of vertex positions when computing the centroid of a face.
+///
+/// See [`FaceView::centroid_weighted`].
+///
+/// [`FaceView::centroid_weighted`]: crate::graph::FaceView::centroid_weighted
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum CentroidWeighting {
+    /// Vertex positions are weighted equally. This is the same as
+    /// [`FaceView::centroid`].
+    ///
+    /// [`FaceView::centroid`]: crate::graph::FaceView::centroid
+    Uniform,
+    /// Edge midpoints are weighted by the length of their edges. This is the
+    /// centroid of the perimeter of the face.
+    EdgeLength,
+    /// Triangles fanned about the uniform centroid are weighted by their
+    /// signed area. This is the centroid of the area of the face, including
+    /// non-convex faces.
+    TriangleArea,
+}
+
+/// Vertex attribute in an interleaved vertex buffer.
+///
+/// See [`MeshGraph::to_interleaved_f32`].
+///
+/// [`MeshGraph::to_interleaved_f32`]: crate::graph::MeshGraph::to_interleaved_f32
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum VertexBufferAttribute {
+    /// The three components of the position of a vertex.
+    Position,
+    /// The three components of the normal of a vertex. See
+    /// [`VertexView::normal`].
+    ///
+    /// [`VertexView::normal`]: crate::graph::VertexView::normal
+    Normal,
+}
+
+/// Report of the vertices merged by welding a graph.
+///
+/// See [`MeshGraph::weld_with_report`].
+///
+/// [`MeshGraph::weld_with_report`]: crate::graph::MeshGraph::weld_with_report
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct WeldReport {
+    merged: usize,
+    rejected: Vec<Vec<VertexKey>>,
+}
+
+impl WeldReport {
+    /// Gets the number of vertices removed from the graph.
+    ///
+    /// This includes both merged vertices and vertices removed along with
+    /// degenerate faces.
+    pub fn merged(&self) -> usize {
+        self.merged
+    }
+
+    /// Gets the groups of vertices that were not merged, because merging them
+    /// would produce non-manifold topology.
+    ///
+    /// Each group contains the keys of the unmerged vertices in the welded
+    /// graph. Vertices that were removed from the graph are not included.
+    pub fn rejected(&self) -> &[Vec<VertexKey>] {
+        &self.rejected
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum GraphKey {
     Vertex(VertexKey),
@@ -605,11 +746,29 @@ impl From<FaceKey> for GraphKey {
 /// See the [`graph`] module documentation and [user guide][guide-graphs] for
 /// more details.
 ///
+/// # Thread Safety
+///
+/// `MeshGraph` owns its storage and contains no shared or interior mutable
+/// state, so a graph is [`Send`] and [`Sync`] if the data of its entities
+/// (the associated types of its [`GraphData`]) is [`Send`] and [`Sync`].
+/// Graphs can be moved into other threads or shared immutably between them.
+///
+/// # Serialization
+///
+/// When the `serde` feature is enabled, `MeshGraph` implements `Serialize`
+/// and `Deserialize` if its vertex and face data do. Graphs are serialized as
+/// a buffer of vertex data and a buffer of faces (vertex indices and face
+/// data); keys, arcs, and edges are not serialized. Connectivity is rebuilt
+/// and validated when a graph is deserialized, so inconsistent data is
+/// rejected with an error rather than trusted.
+///
 /// [dcel]: https://en.wikipedia.org/wiki/doubly_connected_edge_list
 /// [guide-graphs]: https://plexus.rs/user-guide/graphs
 ///
 /// [`GraphData`]: crate::graph::GraphData
 /// [`graph`]: crate::graph
+/// [`Send`]: std::marker::Send
+/// [`Sync`]: std::marker::Sync
 pub struct MeshGraph<G = (R64, R64, R64)>
 where
     G: GraphData,
@@ -634,6 +793,52 @@ where
         MeshGraph::from(Core::default())
     }
 
+    /// Creates a [`MeshGraph`] from separate position, normal, and index
+    /// buffers.
+    ///
+    /// This is the common shape of data produced by loaders for formats like
+    /// glTF. Each vertex is constructed from its position and normal via
+    /// [`FromGeometry`], so normals become vertex data alongside positions.
+    /// To expose these normals via [`VertexView::normal`], implement
+    /// [`GraphData::vertex_normal`]. The arity of the polygons in the index
+    /// buffer must be given and constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::BufferLengthConflict`] if the position and normal
+    /// buffers differ in length. Otherwise, returns an error under the same
+    /// conditions as [`FromRawBuffersWithArity`].
+    ///
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    /// [`FromRawBuffersWithArity`]: crate::buffer::FromRawBuffersWithArity
+    /// [`GraphData::vertex_normal`]: crate::graph::GraphData::vertex_normal
+    /// [`GraphError::BufferLengthConflict`]: crate::graph::GraphError::BufferLengthConflict
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    /// [`VertexView::normal`]: crate::graph::VertexView::normal
+    pub fn from_indexed_buffers<N, P, Q, I, J, K>(
+        positions: I,
+        normals: J,
+        indices: K,
+        arity: usize,
+    ) -> Result<Self, GraphError>
+    where
+        N: Integer + ToPrimitive + Unsigned,
+        G::Vertex: FromGeometry<(P, Q)>,
+        I: IntoIterator<Item = P>,
+        J: IntoIterator<Item = Q>,
+        K: IntoIterator<Item = N>,
+    {
+        let positions = positions.into_iter().collect::<Vec<_>>();
+        let normals = normals.into_iter().collect::<Vec<_>>();
+        if positions.len() != normals.len() {
+            return Err(GraphError::BufferLengthConflict {
+                expected: positions.len(),
+                actual: normals.len(),
+            });
+        }
+        Self::from_raw_buffers_with_arity(indices, positions.into_iter().zip(normals), arity)
+    }
+
     /// Gets the number of vertices in the graph.
     pub fn vertex_count(&self) -> usize {
         self.core.vertices.len()
@@ -651,7 +856,7 @@ where
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the vertices in the graph.
-    pub fn vertices(&self) -> impl Iterator<Item = VertexView<&Self>> {
+    pub fn vertices(&self) -> impl ExactSizeIterator<Item = VertexView<&Self>> {
         self.core
             .vertices
             .iter()
@@ -661,7 +866,7 @@ where
     }
 
     /// Gets an iterator of orphan views over the vertices in the graph.
-    pub fn vertex_orphans(&mut self) -> impl Iterator<Item = VertexOrphan<G>> {
+    pub fn vertex_orphans(&mut self) -> impl ExactSizeIterator<Item = VertexOrphan<G>> {
         self.core
             .vertices
             .iter_mut()
@@ -686,7 +891,7 @@ where
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the arcs in the graph.
-    pub fn arcs(&self) -> impl Iterator<Item = ArcView<&Self>> {
+    pub fn arcs(&self) -> impl ExactSizeIterator<Item = ArcView<&Self>> {
         self.core
             .arcs
             .iter()
@@ -696,7 +901,7 @@ where
     }
 
     /// Gets an iterator of orphan views over the arcs in the graph.
-    pub fn arc_orphans(&mut self) -> impl Iterator<Item = ArcOrphan<G>> {
+    pub fn arc_orphans(&mut self) -> impl ExactSizeIterator<Item = ArcOrphan<G>> {
         self.core
             .arcs
             .iter_mut()
@@ -721,7 +926,7 @@ where
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the edges in the graph.
-    pub fn edges(&self) -> impl Iterator<Item = EdgeView<&Self>> {
+    pub fn edges(&self) -> impl ExactSizeIterator<Item = EdgeView<&Self>> {
         self.core
             .edges
             .iter()
@@ -731,7 +936,7 @@ where
     }
 
     /// Gets an iterator of orphan views over the edges in the graph.
-    pub fn edge_orphans(&mut self) -> impl Iterator<Item = EdgeOrphan<G>> {
+    pub fn edge_orphans(&mut self) -> impl ExactSizeIterator<Item = EdgeOrphan<G>> {
         self.core
             .edges
             .iter_mut()
@@ -756,7 +961,7 @@ where
 
     // TODO: Return `Clone + Iterator`.
     /// Gets an iterator of immutable views over the faces in the graph.
-    pub fn faces(&self) -> impl Iterator<Item = FaceView<&Self>> {
+    pub fn faces(&self) -> impl ExactSizeIterator<Item = FaceView<&Self>> {
         self.core
             .faces
             .iter()
@@ -766,7 +971,7 @@ where
     }
 
     /// Gets an iterator of orphan views over the faces in the graph.
-    pub fn face_orphans(&mut self) -> impl Iterator<Item = FaceOrphan<G>> {
+    pub fn face_orphans(&mut self) -> impl ExactSizeIterator<Item = FaceOrphan<G>> {
         self.core
             .faces
             .iter_mut()
@@ -801,13 +1006,252 @@ where
     }
 
     /// Gets an axis-aligned bounding box that encloses the graph.
-    pub fn aabb(&self) -> Aabb<VertexPosition<G>>
+    ///
+    /// Returns `None` if the graph has no vertices.
+    pub fn aabb(&self) -> Option<Aabb<VertexPosition<G>>>
     where
         G::Vertex: AsPosition,
         VertexPosition<G>: EuclideanSpace,
         Scalar<VertexPosition<G>>: IntrinsicOrd,
     {
-        Aabb::from_points(self.vertices().map(|vertex| *vertex.position()))
+        if self.vertex_count() == 0 {
+            None
+        }
+        else {
+            Some(Aabb::from_points(self.vertices().map(|vertex| *vertex.position())))
+        }
+    }
+
+    /// Gets the minimum and maximum corners of the axis-aligned bounding box
+    /// of the graph.
+    ///
+    /// This is equivalent to the lower and upper bounds of
+    /// [`MeshGraph::aabb`].
+    ///
+    /// Returns `None` if the graph has no vertices.
+    ///
+    /// # Examples
+    ///
+    /// Computing the center and extent of a graph, such as for framing it
+    /// with a camera:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{center, Point3};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+    ///     .polygons::<Position<E3>>()
+    ///     .collect();
+    /// let (min, max) = graph.bounds().unwrap();
+    /// let target = center(&min, &max);
+    /// let extent = (max - min).norm();
+    /// ```
+    ///
+    /// [`MeshGraph::aabb`]: crate::graph::MeshGraph::aabb
+    pub fn bounds(&self) -> Option<(VertexPosition<G>, VertexPosition<G>)>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+        Scalar<VertexPosition<G>>: IntrinsicOrd,
+    {
+        self.aabb().map(|aabb| (aabb.origin, aabb.endpoint()))
+    }
+
+    /// Gets the centroid of the vertices in the graph.
+    ///
+    /// Returns `None` if the graph has no vertices.
+    pub fn centroid(&self) -> Option<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        VertexPosition::<G>::centroid(self.vertices().map(|vertex| *vertex.position()))
+    }
+
+    /// Gets a sphere that encloses the graph.
+    ///
+    /// The sphere is centered at the [centroid][`MeshGraph::centroid`] of the
+    /// graph and its radius is the greatest distance from the centroid to any
+    /// vertex. This sphere encloses the graph, but is not necessarily the
+    /// smallest such sphere. The center and radius are returned in that order.
+    ///
+    /// Returns `None` if the graph has no vertices.
+    pub fn bounding_sphere(&self) -> Option<(VertexPosition<G>, Scalar<VertexPosition<G>>)>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let centroid = self.centroid()?;
+        let radius = self
+            .vertices()
+            .map(|vertex| (*vertex.position() - centroid).magnitude())
+            .fold(Zero::zero(), |max, distance| {
+                if distance > max {
+                    distance
+                }
+                else {
+                    max
+                }
+            });
+        Some((centroid, radius))
+    }
+
+    /// Gets the faces that lie at least partially inside of a frustum.
+    ///
+    /// The frustum is bounded by six planes with normals that point into the
+    /// frustum. A face is culled if its axis-aligned bounding box lies
+    /// entirely outside of any one of these planes and all other faces are
+    /// returned. This test is conservative: faces near the edges and corners
+    /// of the frustum may be returned even if they lie outside of it.
+    pub fn faces_in_frustum(&self, planes: &[Plane<VertexPosition<G>>; 6]) -> Vec<FaceKey>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Scalar<VertexPosition<G>>: IntrinsicOrd,
+    {
+        let planes = planes
+            .iter()
+            .map(|plane| {
+                let (x, y, z) = (VertexPosition::<G>::origin() + *plane.normal.get()).into_xyz();
+                (plane, [x, y, z])
+            })
+            .collect::<SmallVec<[_; 6]>>();
+        self.faces()
+            .filter(|face| {
+                let aabb =
+                    Aabb::from_points(face.adjacent_vertices().map(|vertex| *vertex.position()));
+                let [min, max] = [aabb.origin, aabb.endpoint()].map(|position| {
+                    let (x, y, z) = position.into_xyz();
+                    [x, y, z]
+                });
+                planes.iter().all(|(plane, normal)| {
+                    // Test the corner of the bounding box that is farthest
+                    // along the normal of the plane.
+                    let corner = |i: usize| {
+                        if normal[i] < Zero::zero() {
+                            min[i]
+                        }
+                        else {
+                            max[i]
+                        }
+                    };
+                    let corner = VertexPosition::<G>::from_xyz(corner(0), corner(1), corner(2));
+                    (corner - plane.origin).dot(*plane.normal.get()) >= Zero::zero()
+                })
+            })
+            .map(|face| face.key())
+            .collect()
+    }
+
+    /// Gets the sum of the lengths of the edges in the graph.
+    ///
+    /// Returns zero if the graph has no edges.
+    pub fn total_edge_length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.edges()
+            .map(|edge| {
+                let arc = edge.arc();
+                (*arc.destination_vertex().position() - *arc.source_vertex().position())
+                    .magnitude()
+            })
+            .fold(Zero::zero(), |sum, length| sum + length)
+    }
+
+    /// Gets the mean of the lengths of the edges in the graph.
+    ///
+    /// Returns zero if the graph has no edges.
+    pub fn average_edge_length(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        match self.edge_count() {
+            0 => Zero::zero(),
+            n => {
+                self.total_edge_length()
+                    / <Scalar<VertexPosition<G>> as NumCast>::from(n).expect_consistent()
+            }
+        }
+    }
+
+    /// Gets the surface area of the graph.
+    ///
+    /// The surface area is the sum of the [areas][`FaceView::area`] of the
+    /// faces in the graph. Degenerate faces have no area and contribute
+    /// nothing to the sum.
+    ///
+    /// Returns zero if the graph has no faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area of any face cannot be computed.
+    ///
+    /// [`FaceView::area`]: crate::graph::FaceView::area
+    pub fn surface_area(&self) -> Result<Scalar<VertexPosition<G>>, GraphError>
+    where
+        G: FaceArea,
+        G::Vertex: AsPosition,
+    {
+        self.faces().try_fold(Zero::zero(), |sum, face| face.area().map(|area| sum + area))
+    }
+
+    /// Gets an iterator over the keys and [areas][`FaceView::area`] of the
+    /// faces in the graph.
+    ///
+    /// Degenerate faces have an area of zero.
+    ///
+    /// [`FaceView::area`]: crate::graph::FaceView::area
+    pub fn faces_with_area(
+        &self,
+    ) -> impl '_ + Iterator<Item = (FaceKey, Scalar<VertexPosition<G>>)>
+    where
+        G: FaceArea,
+        G::Vertex: AsPosition,
+    {
+        self.faces()
+            .map(|face| (face.key(), face.area().unwrap_or_else(|_| Zero::zero())))
+    }
+
+    /// Gets the volume enclosed by the graph.
+    ///
+    /// The volume is the sum of the signed volumes of the tetrahedra formed
+    /// from the origin and a fan triangulation of each face. This is only
+    /// meaningful for closed and consistently oriented graphs with planar
+    /// faces. The volume is negative if faces are oriented inward.
+    ///
+    /// Returns zero if the graph has no faces.
+    pub fn volume(&self) -> Scalar<VertexPosition<G>>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let origin = VertexPosition::<G>::origin();
+        let volume = self
+            .faces()
+            .flat_map(|face| {
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position() - origin)
+                    .collect::<SmallVec<[_; 4]>>();
+                (1..(positions.len() - 1))
+                    .map(move |i| positions[0].dot(positions[i].cross(positions[i + 1])))
+            })
+            .fold(Scalar::<VertexPosition<G>>::zero(), |sum, volume| sum + volume);
+        volume / <Scalar<VertexPosition<G>> as NumCast>::from(6).expect_consistent()
     }
 
     // TODO: This triangulation does not consider geometry and exhibits some
@@ -855,271 +1299,516 @@ where
         }
     }
 
-    /// Smooths the positions of vertices in the graph.
+    /// Subdivides the graph using the Catmull-Clark scheme.
     ///
-    /// Each position is translated by its offset from its centroid scaled by
-    /// the given factor. The centroid of a vertex position is the mean of the
-    /// positions of its adjacent vertices. That is, given a factor $k$ and a
-    /// vertex with position $P$ and centroid $Q$, its position becomes
-    /// $P+k(Q-P)$.
-    pub fn smooth<T>(&mut self, factor: T)
-    where
-        T: Into<Scalar<VertexPosition<G>>>,
-        G: VertexCentroid,
-        G::Vertex: AsPositionMut,
-        VertexPosition<G>: EuclideanSpace,
-    {
-        let factor = factor.into();
-        let mut positions = HashMap::with_capacity(self.vertex_count());
-        for vertex in self.vertices() {
-            let position = *vertex.position();
-            positions.insert(
-                vertex.key(),
-                position + ((vertex.centroid() - position) * factor),
-            );
-        }
-        for mut vertex in self.vertex_orphans() {
-            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
-        }
-    }
-
-    /// Splits the graph along a path.
+    /// Each face is replaced by quadrilaterals formed from its face point (its
+    /// centroid), the edge points of its edges, and its vertices. Edge points
+    /// are the mean of the vertices of an edge and the face points of its
+    /// incident faces. The positions of the original vertices are moved
+    /// toward the mean of their adjacent face points and vertices. Boundary
+    /// edge points are the midpoints of their edges and boundary vertices are
+    /// moved only toward their adjacent boundary vertices, so boundaries are
+    /// smoothed as curves.
     ///
-    /// Splitting a graph creates boundaries along the given path and copies any
-    /// necessary vertex, arc, and edge data.
+    /// The graph is subdivided `iterations` times. Each iteration produces a
+    /// graph composed entirely of quadrilaterals, so a face of arity $n$ is
+    /// replaced by $n \cdot 4^{i-1}$ faces after $i$ iterations.
     ///
-    /// If the path bisects the graph, then splitting will result in disjointed
-    /// sub-graphs.
+    /// Face data is copied into each quadrilateral formed from a face. Arc and
+    /// edge data is not preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any vertex is not adjacent to a face. If an error is
+    /// returned, then the graph is not modified.
     ///
     /// # Examples
     ///
-    /// ```rust,no_run
+    /// Subdividing a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use nalgebra::Point2;
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
-    /// use plexus::primitive::Trigon;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
     ///
-    /// type E2 = Point2<f64>;
+    /// type E3 = Point3<R64>;
     ///
-    /// // Create a graph from two triangles.
-    /// let mut graph = MeshGraph::<E2>::from_raw_buffers(
-    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
-    ///     vec![(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)],
-    /// )
-    /// .unwrap();
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.subdivide_catmull_clark(1).unwrap();
     ///
-    /// // Find the shared edge that bisects the triangles and then construct a path
-    /// // along the edge and split the graph.
-    /// let key = graph
-    ///     .edges()
-    ///     .find(|edge| !edge.is_boundary_edge())
-    ///     .map(|edge| edge.into_arc().key())
-    ///     .unwrap();
-    /// let mut path = graph.arc_mut(key).unwrap().into_path();
-    /// MeshGraph::split_at_path(path).unwrap();
+    /// assert_eq!(24, graph.face_count());
     /// ```
-    pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
-        let _ = path;
-        unimplemented!()
+    pub fn subdivide_catmull_clark(&mut self, iterations: usize) -> Result<(), GraphError>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        for _ in 0..iterations {
+            self.subdivide_with::<CatmullClark>()?;
+        }
+        Ok(())
     }
 
-    /// Gets an iterator over a vertex within each disjoint sub-graph.
+    /// Subdivides a triangular graph using the Loop scheme.
     ///
-    /// Traverses the graph and returns an arbitrary vertex within each
-    /// _disjoint sub-graph_. A sub-graph is _disjoint_ if it cannot be reached
-    /// from all other topology in the graph.
+    /// Each triangle is replaced by four triangles formed from its vertices
+    /// and the edge points of its edges. Given an edge $\overline{AB}$ with
+    /// opposite vertices $C$ and $D$, its edge point is
+    /// $\frac{3}{8}(A+B)+\frac{1}{8}(C+D)$. Given a valence $n$, the positions
+    /// of the original vertices become $(1-n\beta)P+\beta\sum{Q_i}$, where
+    /// $Q_i$ are the adjacent vertices and
+    /// $\beta=\frac{1}{n}(\frac{5}{8}-(\frac{3}{8}+\frac{1}{4}\cos{\frac{2\pi}{n}})^2)$.
+    /// Boundary edge points are the midpoints of their edges and boundary
+    /// vertices are moved only toward their adjacent boundary vertices.
+    ///
+    /// Face data is copied into each triangle formed from a face. Arc and edge
+    /// data is not preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular. Graphs can be
+    /// triangulated using [`MeshGraph::triangulate`]. If an error is returned,
+    /// then the graph is not modified.
     ///
     /// # Examples
     ///
+    /// Subdividing a triangulated cube:
+    ///
     /// ```rust
+    /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use nalgebra::Point2;
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
-    /// use plexus::primitive::Trigon;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
     ///
-    /// type E2 = Point2<f64>;
+    /// type E3 = Point3<R64>;
     ///
-    /// // Create a graph from two disjoint triangles.
-    /// let graph = MeshGraph::<E2>::from_raw_buffers(
-    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
-    ///     vec![
-    ///         (-2.0, 0.0),
-    ///         (-1.0, 0.0),
-    ///         (-1.0, 1.0),
-    ///         (1.0, 0.0),
-    ///         (2.0, 0.0),
-    ///         (1.0, 1.0),
-    ///     ],
-    /// )
-    /// .unwrap();
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.triangulate();
+    /// graph.subdivide_loop().unwrap();
     ///
-    /// // A vertex from each disjoint triangle is returned.
-    /// for vertex in graph.disjoint_subgraph_vertices() {
-    ///     // ...
-    /// }
+    /// assert_eq!(48, graph.face_count());
     /// ```
-    pub fn disjoint_subgraph_vertices(&self) -> impl ExactSizeIterator<Item = VertexView<&Self>> {
-        let keys = self
-            .core
-            .vertices
-            .iter()
-            .map(|(key, _)| key)
-            .collect::<HashSet<_>>();
-        let mut subkeys = HashSet::with_capacity(self.vertex_count());
-        let mut vertices = SmallVec::<[VertexView<_>; 4]>::new();
-        while let Some(key) = keys.difference(&subkeys).nth(0) {
-            let vertex = VertexView::from(View::bind_unchecked(self, *key));
-            vertices.push(vertex);
-            subkeys.extend(vertex.traverse_by_depth().map(|vertex| vertex.key()));
-        }
-        vertices.into_iter()
-    }
-
-    /// Moves disjoint sub-graphs into separate graphs.
-    pub fn into_disjoint_subgraphs(self) -> Vec<Self> {
-        unimplemented!()
-    }
-
-    /// Shrinks the capacity of the graph's underlying storage as much as
-    /// possible.
-    pub fn shrink_to_fit(&mut self) {
-        self.core.vertices.shrink_to_fit();
-        self.core.arcs.shrink_to_fit();
-        self.core.edges.shrink_to_fit();
-        self.core.faces.shrink_to_fit();
+    pub fn subdivide_loop(&mut self) -> Result<(), GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.subdivide_with::<Loop>()
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Subdivides a triangular graph using the $\sqrt{3}$ scheme.
     ///
-    /// The output is created from each unique vertex in the graph. No face data
-    /// is used, and the `Facet` type is always the unit type `()`.
+    /// A vertex is inserted at the centroid of each triangle and joined to its
+    /// corners, and then each original edge is flipped such that it joins the
+    /// centroids of its adjacent triangles. Each step triples the number of
+    /// triangles. Given a valence $n$, the positions of the original vertices
+    /// become $(1-\alpha)P+\frac{\alpha}{n}\sum{Q_i}$, where $Q_i$ are the
+    /// adjacent vertices and $\alpha=\frac{1}{9}(4-2\cos{\frac{2\pi}{n}})$.
+    /// Boundary edges are not flipped and boundary vertices are not moved.
+    ///
+    /// Face data is copied into the triangles formed from a face. Arc and edge
+    /// data is not preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular. Graphs can be
+    /// triangulated using [`MeshGraph::triangulate`]. If an error is returned,
+    /// then the graph is not modified.
     ///
     /// # Examples
     ///
-    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to modify a cube:
+    /// Subdividing a triangulated cube:
     ///
     /// ```rust
     /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use decorum::N64;
+    /// use decorum::R64;
     /// use nalgebra::Point3;
-    /// use plexus::buffer::MeshBufferN;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
     /// use plexus::primitive::cube::Cube;
     /// use plexus::primitive::generate::Position;
     ///
-    /// type E3 = Point3<N64>;
+    /// type E3 = Point3<R64>;
     ///
     /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
-    /// let key = graph.faces().nth(0).unwrap().key();
-    /// graph
-    ///     .face_mut(key)
-    ///     .unwrap()
-    ///     .extrude_with_offset(1.0)
-    ///     .unwrap();
+    /// graph.triangulate();
+    /// graph.subdivide_sqrt3().unwrap();
     ///
-    /// let buffer: MeshBufferN<usize, E3> = graph.to_mesh_by_vertex().unwrap();
+    /// assert_eq!(36, graph.face_count());
     /// ```
-    ///
-    /// # Errors
-    ///
-    /// Returns an error if the graph does not have constant arity that is
-    /// compatible with the index buffer. Typically, a graph is triangulated
-    /// before being converted to a buffer.
-    ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`Buildable`]: crate::builder::Buildable
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    pub fn to_mesh_by_vertex<B>(&self) -> Result<B, B::Error>
+    pub fn subdivide_sqrt3(&mut self) -> Result<(), GraphError>
     where
-        B: Buildable<Facet = ()>,
-        B::Vertex: FromGeometry<G::Vertex>,
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
     {
-        self.to_mesh_by_vertex_with(|vertex| vertex.get().clone().into_geometry())
+        if self.faces().any(|face| face.arity() != 3) {
+            return Err(GraphError::TopologyNotTriangular);
+        }
+        let into_scalar = |x: f64| {
+            <Scalar<VertexPosition<G>> as NumCast>::from(x).expect_consistent()
+        };
+        let with_position = |data: &G::Vertex, position| {
+            let mut data = data.clone();
+            *data.as_position_mut() = position;
+            data
+        };
+
+        let vertices = self
+            .vertices()
+            .map(|vertex| {
+                let position = *vertex.position();
+                let position = if vertex
+                    .outgoing_arcs()
+                    .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+                {
+                    position
+                }
+                else {
+                    let n = vertex.valence() as f64;
+                    let alpha = (4.0 - (2.0 * (2.0 * std::f64::consts::PI / n).cos())) / 9.0;
+                    let q = VertexPosition::<G>::centroid(
+                        vertex.adjacent_vertices().map(|vertex| *vertex.position()),
+                    )
+                    .expect_consistent();
+                    position + ((q - position) * into_scalar(alpha))
+                };
+                (vertex.key(), with_position(vertex.get(), position))
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|(key, data)| (key, mutation::vertex::insert(&mut mutation, data)))
+            .collect::<HashMap<_, _>>();
+        let centroids = self
+            .faces()
+            .map(|face| {
+                let data = with_position(face.arc().source_vertex().get(), face.centroid());
+                (face.key(), mutation::vertex::insert(&mut mutation, data))
+            })
+            .collect::<HashMap<_, _>>();
+        for edge in self.edges() {
+            // Orient the edge such that its arc is part of a face.
+            let arc = edge.arc();
+            let (arc, face) = match (arc.face(), arc.opposite_arc().face()) {
+                (Some(face), _) => (arc, face),
+                (None, Some(face)) => (arc.into_opposite_arc(), face),
+                (None, None) => continue,
+            };
+            let a = vertices[&arc.source_vertex().key()];
+            let b = vertices[&arc.destination_vertex().key()];
+            let c = centroids[&face.key()];
+            let mut triangles = SmallVec::<[([VertexKey; 3], G::Face); 2]>::new();
+            if let Some(opposite) = arc.opposite_arc().face() {
+                // Flip the edge such that it joins the centroids of its faces.
+                let d = centroids[&opposite.key()];
+                triangles.push(([c, a, d], face.get().clone()));
+                triangles.push(([d, b, c], opposite.get().clone()));
+            }
+            else {
+                triangles.push(([a, b, c], face.get().clone()));
+            }
+            for (perimeter, data) in triangles {
+                let cache =
+                    FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+                mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))
+                    .expect_consistent();
+            }
+        }
+        *self = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(())
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Subdivides the graph using the given [`SubdivisionScheme`].
     ///
-    /// The output is created from each unique vertex in the graph, which is
-    /// converted by the given function. No face data is used, and the `Facet`
-    /// type is always the unit type `()`.
+    /// The scheme determines the positions of vertices in the subdivided
+    /// graph and whether faces are split about a face point. See
+    /// [`SubdivisionScheme`].
+    ///
+    /// Face data is copied into each face formed from a face. Arc and edge
+    /// data is not preserved.
     ///
     /// # Errors
     ///
-    /// Returns an error if the vertex data cannot be inserted into the output,
-    /// there are arity conflicts, or the output does not support topology found
-    /// in the graph.
+    /// Returns an error if the scheme cannot be applied to the graph. If an
+    /// error is returned, then the graph is not modified.
     ///
-    /// [`Buildable`]: crate::builder::Buildable
-    pub fn to_mesh_by_vertex_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    /// # Examples
+    ///
+    /// Subdividing a cube using the Catmull-Clark scheme:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{CatmullClark, MeshGraph};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.subdivide_with::<CatmullClark>().unwrap();
+    ///
+    /// assert_eq!(24, graph.face_count());
+    /// ```
+    ///
+    /// [`SubdivisionScheme`]: crate::graph::SubdivisionScheme
+    pub fn subdivide_with<S>(&mut self) -> Result<(), GraphError>
     where
-        B: Buildable<Facet = ()>,
-        F: FnMut(VertexView<&Self>) -> B::Vertex,
+        S: SubdivisionScheme<G>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
     {
-        let mut builder = B::builder();
-        builder.surface_with(|builder| {
-            let mut keys = HashMap::with_capacity(self.vertex_count());
-            for vertex in self.vertices() {
-                keys.insert(vertex.key(), builder.insert_vertex(f(vertex))?);
+        S::validate(self)?;
+        let with_position = |data: &G::Vertex, position| {
+            let mut data = data.clone();
+            *data.as_position_mut() = position;
+            data
+        };
+
+        let faces = self
+            .faces()
+            .filter_map(|face| S::face_point(face).map(|position| (face.key(), position)))
+            .collect::<FacePoints<G>>();
+        let edges = self
+            .edges()
+            .map(|edge| {
+                let data = edge.arc().source_vertex().get();
+                (edge.key(), with_position(data, S::edge_point(edge, &faces)))
+            })
+            .collect::<HashMap<_, _>>();
+        let vertices = self
+            .vertices()
+            .map(|vertex| {
+                let data = with_position(vertex.get(), S::vertex_point(vertex, &faces));
+                (vertex.key(), data)
+            })
+            .collect::<HashMap<_, _>>();
+
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|(key, data)| (key, mutation::vertex::insert(&mut mutation, data)))
+            .collect::<HashMap<_, _>>();
+        let edges = edges
+            .into_iter()
+            .map(|(key, data)| (key, mutation::vertex::insert(&mut mutation, data)))
+            .collect::<HashMap<_, _>>();
+        for face in self.faces() {
+            let arcs = face
+                .adjacent_arcs()
+                .map(|arc| (arc.source_vertex().key(), arc.edge().key()))
+                .collect::<SmallVec<[_; 4]>>();
+            let n = arcs.len();
+            let mut perimeters = SmallVec::<[SmallVec<[VertexKey; 4]>; 5]>::new();
+            if let Some(position) = faces.get(&face.key()) {
+                // Split the face into quadrilaterals about its face point.
+                let c = mutation::vertex::insert(
+                    &mut mutation,
+                    with_position(face.arc().source_vertex().get(), *position),
+                );
+                for (i, (a, ab)) in arcs.iter().enumerate() {
+                    let (_, xa) = arcs[(i + n - 1) % n];
+                    perimeters.push(smallvec![vertices[a], edges[ab], c, edges[&xa]]);
+                }
             }
-            builder.facets_with(|builder| {
-                for face in self.faces() {
-                    let indices = face
-                        .adjacent_vertices()
-                        .map(|vertex| keys[&vertex.key()])
-                        .collect::<SmallVec<[_; 8]>>();
-                    builder.insert_facet(indices.as_slice(), ())?;
+            else {
+                // Split the face into triangles at its corners and a face
+                // formed from its edge points.
+                for (i, (a, ab)) in arcs.iter().enumerate() {
+                    let (_, xa) = arcs[(i + n - 1) % n];
+                    perimeters.push(smallvec![vertices[a], edges[ab], edges[&xa]]);
                 }
-                Ok(())
-            })
-        })?;
-        builder.build()
+                perimeters.push(arcs.iter().map(|(_, ab)| edges[ab]).collect());
+            }
+            for perimeter in perimeters {
+                let cache =
+                    FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+                mutation::face::insert_with(&mut mutation, cache, || {
+                    (Default::default(), face.get().clone())
+                })
+                .expect_consistent();
+            }
+        }
+        *self = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(())
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Subdivides the given faces at the midpoints of their edges.
     ///
-    /// The output is created from each face in the graph. For each face, the
-    /// face data and data for each of its vertices is inserted into the mesh
-    /// via [`FromGeometry`]. This means that a vertex is inserted for each of
-    /// its adjacent faces.
+    /// Triangles are replaced by four triangles formed from their vertices
+    /// and the midpoints of their edges. Other faces are replaced by
+    /// quadrilaterals, each formed from a vertex, the midpoints of its
+    /// adjacent edges, and the centroid of the face. The positions of existing
+    /// vertices are not changed.
     ///
-    /// # Errors
+    /// Faces that are not selected but share an edge with a selected face
+    /// include the midpoint of that edge in their perimeter, so the graph
+    /// remains conforming and its surface has no cracks. The arity of these
+    /// faces increases by one for each such edge.
     ///
-    /// Returns an error if the vertex data cannot be inserted into the output,
-    /// there are arity conflicts, or the output does not support topology found
-    /// in the graph.
+    /// The entire graph is rebuilt rather than only the selected faces, so
+    /// **all keys into the graph are invalidated**, including the keys of
+    /// faces that are not selected and their vertices. Vertex data is
+    /// preserved and face data is copied into each face formed from a face.
+    /// Arc and edge data is not preserved anywhere in the graph, including
+    /// edges far from the selected faces.
     ///
-    /// [`Buildable`]: crate::builder::Buildable
-    /// [`FromGeometry`]: crate::geometry::FromGeometry
-    pub fn to_mesh_by_face<B>(&self) -> Result<B, B::Error>
+    /// # Errors
+    ///
+    /// Returns an error if any of the given faces cannot be found. If an error
+    /// is returned, then the graph is not modified.
+    pub fn subdivide_faces(&mut self, faces: &HashSet<FaceKey>) -> Result<(), GraphError>
     where
-        B: Buildable,
-        B::Vertex: FromGeometry<G::Vertex>,
-        B::Facet: FromGeometry<G::Face>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
     {
-        self.to_mesh_by_face_with(|_, vertex| vertex.get().clone().into_geometry())
+        let with_position = |data: &G::Vertex, position| {
+            let mut data = data.clone();
+            *data.as_position_mut() = position;
+            data
+        };
+        let centroid = |positions: &[VertexPosition<G>]| {
+            VertexPosition::<G>::centroid(positions.iter().cloned()).expect_consistent()
+        };
+
+        let mut edges = HashMap::new();
+        let mut centers = HashMap::new();
+        for key in faces {
+            let face = self.face(*key).ok_or(GraphError::TopologyNotFound)?;
+            for arc in face.adjacent_arcs() {
+                let a = *arc.source_vertex().position();
+                let b = *arc.destination_vertex().position();
+                edges
+                    .entry(arc.edge().key())
+                    .or_insert_with(|| with_position(arc.source_vertex().get(), centroid(&[a, b])));
+            }
+            if face.arity() != 3 {
+                let vertex = face.adjacent_vertices().next().expect_consistent();
+                let positions = face
+                    .adjacent_vertices()
+                    .map(|vertex| *vertex.position())
+                    .collect::<SmallVec<[_; 4]>>();
+                centers.insert(*key, with_position(vertex.get(), centroid(&positions)));
+            }
+        }
+
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = self
+            .vertices()
+            .map(|vertex| {
+                let data = vertex.get().clone();
+                (vertex.key(), mutation::vertex::insert(&mut mutation, data))
+            })
+            .collect::<HashMap<_, _>>();
+        let edges = edges
+            .into_iter()
+            .map(|(key, data)| (key, mutation::vertex::insert(&mut mutation, data)))
+            .collect::<HashMap<_, _>>();
+        let centers = centers
+            .into_iter()
+            .map(|(key, data)| (key, mutation::vertex::insert(&mut mutation, data)))
+            .collect::<HashMap<_, _>>();
+        for face in self.faces() {
+            let arcs = face
+                .adjacent_arcs()
+                .map(|arc| (vertices[&arc.source_vertex().key()], edges.get(&arc.edge().key())))
+                .collect::<SmallVec<[_; 4]>>();
+            let n = arcs.len();
+            let mut perimeters = SmallVec::<[SmallVec<[VertexKey; 4]>; 4]>::new();
+            if faces.contains(&face.key()) {
+                // Each corner is formed from a vertex and the midpoints of its
+                // outgoing and incoming edges.
+                let midpoints = arcs
+                    .iter()
+                    .map(|(_, ab)| *ab.expect_consistent())
+                    .collect::<SmallVec<[_; 4]>>();
+                let center = centers.get(&face.key());
+                for (i, (a, _)) in arcs.iter().enumerate() {
+                    let (ab, xa) = (midpoints[i], midpoints[(i + n - 1) % n]);
+                    perimeters.push(match center {
+                        Some(center) => smallvec![*a, ab, *center, xa],
+                        None => smallvec![*a, ab, xa],
+                    });
+                }
+                if center.is_none() {
+                    perimeters.push(midpoints);
+                }
+            }
+            else {
+                perimeters.push(
+                    arcs.iter()
+                        .flat_map(|(a, ab)| iter::once(*a).chain(ab.copied()))
+                        .collect(),
+                );
+            }
+            for perimeter in perimeters {
+                let cache =
+                    FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+                mutation::face::insert_with(&mut mutation, cache, || {
+                    (Default::default(), face.get().clone())
+                })
+                .expect_consistent();
+            }
+        }
+        *self = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(())
     }
 
-    /// Creates a [`Buildable`] mesh data structure from the graph.
+    /// Constructs the dual of the graph.
     ///
-    /// The output is created from each face in the graph. For each face, the
-    /// face data and data for each of its vertices is converted into the output
-    /// vertex data by the given function. This means that a vertex is inserted
-    /// for each of its adjacent faces. The data of each face is is inserted
-    /// into the output via [`FromGeometry`].
+    /// Each face of the graph becomes a vertex in the dual positioned at the
+    /// centroid of the face. Each interior vertex of the graph becomes a face
+    /// in the dual formed from the dual vertices of its adjacent faces, so
+    /// faces that share an edge are connected in the dual. The dual of a cube,
+    /// for example, is an octahedron.
     ///
-    /// # Examples
+    /// Boundary vertices of the graph are not enclosed by faces and are
+    /// skipped, as are vertices with fewer than three adjacent faces. Faces
+    /// of the graph that are not adjacent to any dual face do not appear in
+    /// the dual.
     ///
-    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to compute normals:
+    /// The data of each dual vertex is copied from a vertex of its face. Face,
+    /// arc, and edge data is not preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the dual is not manifold.
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # extern crate decorum;
@@ -1128,653 +1817,4512 @@ where
     /// #
     /// use decorum::R64;
     /// use nalgebra::Point3;
-    /// use plexus::buffer::MeshBuffer;
-    /// use plexus::geometry::Vector;
     /// use plexus::graph::MeshGraph;
     /// use plexus::prelude::*;
     /// use plexus::primitive::cube::Cube;
     /// use plexus::primitive::generate::Position;
-    /// use plexus::primitive::BoundedPolygon;
     ///
     /// type E3 = Point3<R64>;
     ///
-    /// pub struct Vertex {
-    ///     pub position: E3,
-    ///     pub normal: Vector<E3>,
-    /// }
-    ///
     /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let dual = graph.dual().unwrap();
     ///
-    /// let buffer: MeshBuffer<BoundedPolygon<usize>, _> = graph
-    ///     .to_mesh_by_face_with(|face, vertex| Vertex {
-    ///         position: *vertex.position(),
-    ///         normal: face.normal().unwrap(),
-    ///     })
-    ///     .unwrap();
+    /// assert_eq!(6, dual.vertex_count());
+    /// assert_eq!(8, dual.face_count());
     /// ```
+    pub fn dual(&self) -> Result<Self, GraphError>
+    where
+        G: FaceCentroid,
+        G::Vertex: AsPositionMut,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::with_capacity(self.face_count());
+        for vertex in self.vertices() {
+            // Circulate the faces about the vertex in the same order as the
+            // arcs of those faces, so that dual faces are consistently
+            // oriented.
+            let mut faces = SmallVec::<[_; 8]>::new();
+            let start = vertex.outgoing_arc();
+            let mut arc = start;
+            let is_closed = loop {
+                match arc.face() {
+                    Some(face) => faces.push(face),
+                    None => break false,
+                }
+                arc = arc.previous_arc().into_opposite_arc();
+                if arc.key() == start.key() {
+                    break true;
+                }
+            };
+            if !is_closed || faces.len() < 3 {
+                continue;
+            }
+            let perimeter = faces
+                .into_iter()
+                .map(|face| {
+                    *vertices.entry(face.key()).or_insert_with(|| {
+                        let mut data = face.arc().source_vertex().get().clone();
+                        *data.as_position_mut() = face.centroid();
+                        mutation::vertex::insert(&mut mutation, data)
+                    })
+                })
+                .collect::<SmallVec<[_; 8]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+
+    /// Merges adjacent faces with normals that agree within the given angle.
     ///
-    /// # Errors
+    /// The edge between adjacent faces is dissolved if the angle between
+    /// their normals is no more than the given tolerance (in radians),
+    /// merging the faces into a single face. This is roughly the inverse of
+    /// triangulation and simplifies flat regions of a graph. Faces are merged
+    /// repeatedly, so flat regions may be merged into faces with high arity.
     ///
-    /// Returns an error if the vertex data cannot be inserted into the output,
-    /// there are arity conflicts, or the output does not support topology found
-    /// in the graph.
+    /// Faces are compared using the normals of the original faces rather than
+    /// the normals of merged faces. A face is merged into an adjacent face only
+    /// if the normals of all of the original faces that it contains agree with
+    /// the normal of the first original face of the adjacent face, so the
+    /// deviation of a merged face does not accumulate and curved surfaces are
+    /// not flattened.
     ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`Buildable`]: crate::builder::Buildable
-    /// [`FromGeometry`]: crate::geometry::FromGeometry
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    pub fn to_mesh_by_face_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    /// Faces are only merged if they share exactly one edge and no other
+    /// vertices, so the perimeters of merged faces are simple and never
+    /// enclose holes. Faces with normals that cannot be computed are not
+    /// merged.
+    ///
+    /// Returns the number of dissolved edges.
+    ///
+    /// # Examples
+    ///
+    /// Merging the triangles of a triangulated cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.triangulate();
+    ///
+    /// assert_eq!(6, graph.merge_coplanar_faces(1e-6));
+    /// assert_eq!(6, graph.face_count());
+    /// ```
+    pub fn merge_coplanar_faces(&mut self, angle_tolerance: f64) -> usize
     where
-        B: Buildable,
-        B::Facet: FromGeometry<G::Face>,
-        F: FnMut(FaceView<&Self>, VertexView<&Self>) -> B::Vertex,
+        G: FaceNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
     {
-        let mut builder = B::builder();
-        builder.surface_with(|builder| {
-            for face in self.faces() {
-                let indices = face
-                    .adjacent_vertices()
-                    .map(|vertex| builder.insert_vertex(f(face, vertex)))
-                    .collect::<Result<SmallVec<[_; 8]>, _>>()?;
-                builder.facets_with(|builder| {
-                    builder.insert_facet(indices.as_slice(), face.get().clone())
-                })?;
+        let threshold = angle_tolerance.cos();
+        // Map each face to the normals of the original faces that it contains.
+        // The first normal is that of the seed face into which the others
+        // have been merged.
+        let mut normals = self
+            .faces()
+            .flat_map(|face| {
+                face.normal()
+                    .ok()
+                    .map(|normal| (face.key(), SmallVec::<[_; 4]>::from_elem(normal, 1)))
+            })
+            .collect::<HashMap<_, _>>();
+        let mut n = 0;
+        loop {
+            let m = n;
+            let edges = self.edges().map(|edge| edge.key()).collect::<Vec<_>>();
+            for key in edges {
+                // Edges are dissolved as faces are merged, so the edge may no
+                // longer be present.
+                let faces = self.edge(key).and_then(|edge| {
+                    let arc = edge.arc();
+                    let (face, opposite) = (arc.face()?, arc.opposite_arc().face()?);
+                    let seed = normals.get(&face.key())?[0];
+                    for normal in normals.get(&opposite.key())? {
+                        if seed.dot(*normal).to_f64()? < threshold {
+                            return None;
+                        }
+                    }
+                    let vertices = face
+                        .adjacent_vertices()
+                        .map(|vertex| vertex.key())
+                        .collect::<HashSet<_>>();
+                    let shared = opposite
+                        .adjacent_vertices()
+                        .filter(|vertex| vertices.contains(&vertex.key()))
+                        .count();
+                    (shared == 2).then(|| (face.key(), opposite.key()))
+                });
+                if let Some((face, opposite)) = faces {
+                    let merged = self
+                        .face_mut(face)
+                        .expect_consistent()
+                        .merge(ByKey(opposite))
+                        .expect_consistent()
+                        .key();
+                    let mut seeds = normals.remove(&face).expect_consistent();
+                    seeds.extend(normals.remove(&opposite).expect_consistent());
+                    normals.insert(merged, seeds);
+                    n += 1;
+                }
             }
-            Ok(())
-        })?;
-        builder.build()
+            if n == m {
+                break n;
+            }
+        }
     }
-}
 
-impl<G> AsStorage<Vertex<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &StorageTarget<Vertex<G>> {
-        self.core.as_storage_of::<Vertex<_>>()
+    /// Decimates a triangular graph until it has no more than the given
+    /// number of faces.
+    ///
+    /// Decimation greedily collapses the edges with the least quadric error.
+    /// The quadric of a vertex is the sum of the squared distances to the
+    /// planes of its adjacent faces and the error of an edge is the quadric
+    /// of its vertices evaluated at its midpoint, which is where
+    /// [`EdgeView::collapse`] places the remaining vertex. After each
+    /// collapse, the quadric of the remaining vertex becomes the sum of the
+    /// quadrics of the edge's vertices and the errors of its edges are
+    /// recomputed.
+    ///
+    /// Collapses that would invert faces or produce non-manifold topology are
+    /// skipped, so decimation stops early if no valid collapse remains.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph is not triangular or a position cannot
+    /// be represented as an `f64`. Graphs can be triangulated using
+    /// [`MeshGraph::triangulate`].
+    ///
+    /// [`EdgeView::collapse`]: crate::graph::EdgeView::collapse
+    pub fn decimate_to(&mut self, target: usize) -> Result<(), GraphError>
+    where
+        G: EdgeMidpoint + FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        self.decimate_with_policy(target, None)
     }
-}
 
-impl<G> AsStorage<Arc<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &StorageTarget<Arc<G>> {
-        self.core.as_storage_of::<Arc<_>>()
+    /// Decimates a triangular graph until it has no more than the given
+    /// number of faces without producing vertices with more than the given
+    /// valence.
+    ///
+    /// This function behaves like [`MeshGraph::decimate_to`], but collapses
+    /// edges with [`EdgeView::collapse_with_max_valence`]. Collapses that
+    /// would produce a vertex with more than `max_valence` adjacent vertices
+    /// are skipped, so decimation may stop before reaching the target.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error under the conditions described by
+    /// [`MeshGraph::decimate_to`].
+    ///
+    /// [`EdgeView::collapse_with_max_valence`]: crate::graph::EdgeView::collapse_with_max_valence
+    pub fn decimate_to_with_max_valence(
+        &mut self,
+        target: usize,
+        max_valence: usize,
+    ) -> Result<(), GraphError>
+    where
+        G: EdgeMidpoint + FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        self.decimate_with_policy(target, Some(max_valence))
     }
-}
 
-impl<G> AsStorage<Edge<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &StorageTarget<Edge<G>> {
-        self.core.as_storage_of::<Edge<_>>()
+    fn decimate_with_policy(
+        &mut self,
+        target: usize,
+        max_valence: Option<usize>,
+    ) -> Result<(), GraphError>
+    where
+        G: EdgeMidpoint + FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        type Quadric = [[f64; 4]; 4];
+
+        fn accumulate(sum: &mut Quadric, quadric: &Quadric) {
+            for (sum, quadric) in sum.iter_mut().zip(quadric.iter()) {
+                for (sum, quadric) in sum.iter_mut().zip(quadric.iter()) {
+                    *sum += quadric;
+                }
+            }
+        }
+
+        if self.faces().any(|face| face.arity() != 3) {
+            return Err(GraphError::TopologyNotTriangular);
+        }
+        let into_f64 = |position: VertexPosition<G>| -> Result<[f64; 3], GraphError> {
+            let (x, y, z) = position.into_xyz();
+            Ok([
+                x.to_f64().ok_or(GraphError::Geometry)?,
+                y.to_f64().ok_or(GraphError::Geometry)?,
+                z.to_f64().ok_or(GraphError::Geometry)?,
+            ])
+        };
+        let cost = |edge: EdgeView<&Self>,
+                    quadrics: &HashMap<VertexKey, Quadric>|
+         -> Result<R64, GraphError> {
+            let arc = edge.arc();
+            let mut quadric = Quadric::default();
+            for vertex in [arc.source_vertex(), arc.destination_vertex()] {
+                if let Some(other) = quadrics.get(&vertex.key()) {
+                    accumulate(&mut quadric, other);
+                }
+            }
+            let [x, y, z] = into_f64(edge.midpoint())?;
+            let p = [x, y, z, 1.0];
+            let error = quadric
+                .iter()
+                .zip(p.iter())
+                .map(|(row, a)| a * row.iter().zip(p.iter()).map(|(q, b)| q * b).sum::<f64>())
+                .sum();
+            Ok(R64::from_inner(error))
+        };
+
+        let mut quadrics: HashMap<_, Quadric> = HashMap::with_capacity(self.vertex_count());
+        for face in self.faces() {
+            let normal = match face.normal() {
+                Ok(normal) => into_f64(VertexPosition::<G>::origin() + normal)?,
+                // Degenerate faces have no plane and do not contribute.
+                Err(GraphError::Geometry) => continue,
+                Err(error) => return Err(error),
+            };
+            let point = into_f64(*face.arc().source_vertex().position())?;
+            let d = -((normal[0] * point[0]) + (normal[1] * point[1]) + (normal[2] * point[2]));
+            let plane = [normal[0], normal[1], normal[2], d];
+            let quadric: Quadric = plane.map(|a| plane.map(|b| a * b));
+            for vertex in face.adjacent_vertices() {
+                accumulate(quadrics.entry(vertex.key()).or_default(), &quadric);
+            }
+        }
+        // Edges are queued by serial numbers, because keys are not ordered.
+        // When the error of an edge is recomputed, it is queued with a new
+        // serial and its previous entries in the queue become stale.
+        let mut edges = Vec::with_capacity(self.edge_count());
+        let mut serials = HashMap::with_capacity(self.edge_count());
+        let mut queue = BinaryHeap::with_capacity(self.edge_count());
+        for edge in self.edges() {
+            queue.push((Reverse(cost(edge, &quadrics)?), edges.len()));
+            serials.insert(edge.key(), edges.len());
+            edges.push(edge.key());
+        }
+        while self.face_count() > target {
+            let serial = match queue.pop() {
+                Some((_, serial)) => serial,
+                None => break,
+            };
+            let key = edges[serial];
+            if serials.get(&key) != Some(&serial) {
+                continue;
+            }
+            serials.remove(&key);
+            let (a, b) = match self.edge(key) {
+                Some(edge) => {
+                    let arc = edge.arc();
+                    (arc.source_vertex().key(), arc.destination_vertex().key())
+                }
+                None => continue,
+            };
+            let edge = self.edge_mut(key).expect_consistent();
+            let collapse = match max_valence {
+                Some(max_valence) => edge.collapse_with_max_valence(max_valence),
+                None => edge.collapse(),
+            };
+            let c = match collapse {
+                Ok(vertex) => vertex.key(),
+                Err(_) => continue,
+            };
+            let removed = if c == a { b } else { a };
+            if let Some(quadric) = quadrics.remove(&removed) {
+                accumulate(quadrics.entry(c).or_default(), &quadric);
+            }
+            for arc in self.vertex(c).expect_consistent().outgoing_arcs() {
+                let edge = arc.into_edge();
+                queue.push((Reverse(cost(edge, &quadrics)?), edges.len()));
+                serials.insert(edge.key(), edges.len());
+                edges.push(edge.key());
+            }
+        }
+        Ok(())
     }
-}
 
-impl<G> AsStorage<Face<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage(&self) -> &StorageTarget<Face<G>> {
-        self.core.as_storage_of::<Face<_>>()
+    /// Maps the positions of all vertices in the graph with the given
+    /// function.
+    ///
+    /// # Examples
+    ///
+    /// Scaling a graph non-uniformly along the $x$-axis:
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.map_positions(|position| Point3::new(position.x * 2.0, position.y, position.z));
+    /// ```
+    pub fn map_positions<F>(&mut self, mut f: F)
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        F: FnMut(VertexPosition<G>) -> VertexPosition<G>,
+    {
+        for mut vertex in self.vertex_orphans() {
+            let position = vertex.get_mut().as_position_mut();
+            *position = f(*position);
+        }
     }
-}
 
-impl<G> AsStorageMut<Vertex<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Vertex<G>> {
-        self.core.as_storage_mut_of::<Vertex<_>>()
+    /// Transforms the positions of all vertices in the graph.
+    ///
+    /// The transform is applied to the position of each vertex in place. This
+    /// can be used to bake a transform into the geometry of a graph. See
+    /// [`Transform`].
+    ///
+    /// Closures implement [`Transform`], but the types of their parameters
+    /// cannot be inferred and must be annotated, such as
+    /// `|position: Point3<f64>| ...`. Use [`MeshGraph::map_positions`] to
+    /// apply a closure without annotations.
+    ///
+    /// # Examples
+    ///
+    /// Translating a cube with an isometry:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::{Isometry3, Point3, Vector3};
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+    /// graph.transform(&Isometry3::translation(0.0, 0.0, 1.0));
+    ///
+    /// assert_eq!(Point3::new(0.0, 0.0, 1.0), graph.centroid().unwrap());
+    /// ```
+    ///
+    /// [`Transform`]: crate::geometry::Transform
+    pub fn transform<T>(&mut self, transform: &T)
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+        T: Transform<VertexPosition<G>>,
+    {
+        self.map_positions(|position| transform.transform(position))
     }
-}
 
-impl<G> AsStorageMut<Arc<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Arc<G>> {
-        self.core.as_storage_mut_of::<Arc<_>>()
+    /// Smooths the positions of vertices in the graph.
+    ///
+    /// Each position is translated by its offset from its centroid scaled by
+    /// the given factor. The centroid of a vertex position is the mean of the
+    /// positions of its adjacent vertices. That is, given a factor $k$ and a
+    /// vertex with position $P$ and centroid $Q$, its position becomes
+    /// $P+k(Q-P)$.
+    pub fn smooth<T>(&mut self, factor: T)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G: VertexCentroid,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let factor = factor.into();
+        let mut positions = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let position = *vertex.position();
+            positions.insert(
+                vertex.key(),
+                position + ((vertex.centroid() - position) * factor),
+            );
+        }
+        for mut vertex in self.vertex_orphans() {
+            *vertex.get_mut().as_position_mut() = positions.remove(&vertex.key()).unwrap();
+        }
     }
-}
 
-impl<G> AsStorageMut<Edge<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Edge<G>> {
-        self.core.as_storage_mut_of::<Edge<_>>()
+    /// Smooths the positions of vertices in the graph over the given number of
+    /// iterations.
+    ///
+    /// In each iteration, every position is translated by its offset from the
+    /// centroid of its adjacent vertices scaled by the given factor, as in
+    /// [`MeshGraph::smooth`]. All positions are computed before any are
+    /// written, so an iteration does not depend on the order in which vertices
+    /// are visited.
+    ///
+    /// Boundary vertices are either fixed or smoothed along the boundary
+    /// depending on the given [`SmoothBoundary`].
+    pub fn smooth_laplacian<T>(&mut self, iterations: usize, factor: T, boundary: SmoothBoundary)
+    where
+        T: Into<Scalar<VertexPosition<G>>>,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let factor = factor.into();
+        for _ in 0..iterations {
+            let mut positions = HashMap::with_capacity(self.vertex_count());
+            for vertex in self.vertices() {
+                let is_boundary_arc = |arc: &ArcView<_>| {
+                    arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc()
+                };
+                let centroid = if vertex.outgoing_arcs().any(|arc| is_boundary_arc(&arc)) {
+                    match boundary {
+                        SmoothBoundary::Fixed => None,
+                        SmoothBoundary::Along => VertexPosition::<G>::centroid(
+                            vertex
+                                .outgoing_arcs()
+                                .filter(is_boundary_arc)
+                                .map(|arc| *arc.destination_vertex().position()),
+                        ),
+                    }
+                }
+                else {
+                    VertexPosition::<G>::centroid(
+                        vertex.adjacent_vertices().map(|vertex| *vertex.position()),
+                    )
+                };
+                if let Some(centroid) = centroid {
+                    let position = *vertex.position();
+                    positions.insert(vertex.key(), position + ((centroid - position) * factor));
+                }
+            }
+            for mut vertex in self.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.get_mut().as_position_mut() = position;
+                }
+            }
+        }
     }
-}
 
-impl<G> AsStorageMut<Face<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn as_storage_mut(&mut self) -> &mut StorageTarget<Face<G>> {
-        self.core.as_storage_mut_of::<Face<_>>()
+    /// Smooths the graph using bilateral filtering of face normals.
+    ///
+    /// In each iteration, the normal of each face is replaced by an average of
+    /// the normals of faces that share a vertex with it. The normals are
+    /// weighted by the areas of the faces and by Gaussian functions of the
+    /// distance between the centroids of the faces and of the difference
+    /// between their normals, with the given standard deviations. Vertex
+    /// positions are then moved toward the planes described by the filtered
+    /// normals and centroids of their adjacent faces.
+    ///
+    /// Unlike [`MeshGraph::smooth_laplacian`], this removes noise while
+    /// preserving sharp features: faces on either side of a crease have
+    /// dissimilar normals and so have little influence on each other. A
+    /// spatial deviation near the average edge length and a normal deviation
+    /// of about $0.35$ are typical. Degenerate faces are ignored.
+    pub fn bilateral_normal_smooth(
+        &mut self,
+        iterations: usize,
+        sigma_spatial: f64,
+        sigma_normal: f64,
+    ) where
+        G: FaceArea + FaceCentroid + FaceNormal,
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let into_scalar = |x: f64| {
+            <Scalar<VertexPosition<G>> as NumCast>::from(x).expect_consistent()
+        };
+        let gaussian = |x: Scalar<VertexPosition<G>>, sigma: f64| {
+            let x = x.to_f64().expect_consistent();
+            (-(x * x) / (2.0 * sigma * sigma)).exp()
+        };
+        for _ in 0..iterations {
+            let faces = self
+                .faces()
+                .flat_map(|face| {
+                    let normal = face.normal().ok()?;
+                    let area = face.area().ok()?;
+                    Some((face.key(), (face.centroid(), normal, area)))
+                })
+                .collect::<HashMap<_, _>>();
+            let normals = faces
+                .iter()
+                .map(|(key, (centroid, normal, _))| {
+                    let neighbors = self
+                        .face(*key)
+                        .expect_consistent()
+                        .adjacent_vertices()
+                        .flat_map(|vertex| {
+                            vertex
+                                .adjacent_faces()
+                                .map(|face| face.key())
+                                .collect::<SmallVec<[_; 8]>>()
+                        })
+                        .collect::<HashSet<_>>();
+                    let filtered = neighbors
+                        .iter()
+                        .flat_map(|key| faces.get(key))
+                        .map(|(other, neighbor, area)| {
+                            let weight = area.to_f64().expect_consistent()
+                                * gaussian((*other - *centroid).magnitude(), sigma_spatial)
+                                * gaussian((*neighbor - *normal).magnitude(), sigma_normal);
+                            *neighbor * into_scalar(weight)
+                        })
+                        .reduce(|sum, normal| sum + normal)
+                        .and_then(|normal| normal.normalize())
+                        .unwrap_or(*normal);
+                    (*key, filtered)
+                })
+                .collect::<HashMap<_, _>>();
+            let mut positions = HashMap::with_capacity(self.vertex_count());
+            for vertex in self.vertices() {
+                let position = *vertex.position();
+                let translations = vertex
+                    .adjacent_faces()
+                    .flat_map(|face| {
+                        let (centroid, _, _) = faces.get(&face.key())?;
+                        let normal = normals[&face.key()];
+                        Some(normal * normal.dot(*centroid - position))
+                    })
+                    .collect::<SmallVec<[_; 8]>>();
+                let n = translations.len();
+                if let Some(translation) = translations.into_iter().reduce(|sum, t| sum + t) {
+                    positions.insert(
+                        vertex.key(),
+                        position + (translation * into_scalar(1.0 / n as f64)),
+                    );
+                }
+            }
+            for mut vertex in self.vertex_orphans() {
+                if let Some(position) = positions.remove(&vertex.key()) {
+                    *vertex.get_mut().as_position_mut() = position;
+                }
+            }
+        }
     }
-}
 
-/// Exposes a [`MeshBuilder`] that can be used to construct a [`MeshGraph`]
-/// incrementally from _surfaces_ and _facets_.
-///
-/// See the [`builder`] module documentation for more.
-///
-/// # Examples
-///
-/// Creating a [`MeshGraph`] from a triangle:
-///
-/// ```rust
-/// # extern crate nalgebra;
-/// # extern crate plexus;
-/// #
-/// use nalgebra::Point2;
-/// use plexus::builder::Buildable;
-/// use plexus::graph::MeshGraph;
-/// use plexus::prelude::*;
-///
-/// let mut builder = MeshGraph::<Point2<f64>>::builder();
-/// let graph = builder
-///     .surface_with(|builder| {
-///         let a = builder.insert_vertex((0.0, 0.0))?;
-///         let b = builder.insert_vertex((1.0, 0.0))?;
-///         let c = builder.insert_vertex((0.0, 1.0))?;
-///         builder.facets_with(|builder| builder.insert_facet(&[a, b, c], ()))
-///     })
-///     .and_then(|_| builder.build())
-///     .unwrap();
-/// ```
-///
-/// [`MeshBuilder`]: crate::builder::MeshBuilder
-/// [`builder`]: crate::builder
-/// [`MeshGraph`]: crate::graph::MeshGraph
-impl<G> Buildable for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Builder = GraphBuilder<G>;
-    type Error = GraphError;
-
-    type Vertex = G::Vertex;
-    type Facet = G::Face;
-
-    fn builder() -> Self::Builder {
-        Default::default()
+    /// Computes per-vertex tangents from the given texture coordinates.
+    ///
+    /// The tangent and bitangent of each face are computed from the positions
+    /// and texture coordinates of its vertices and accumulated into its
+    /// vertices. The accumulated tangent of each vertex is then
+    /// orthogonalized with respect to the vertex normal and normalized. This
+    /// is Lengyel's method.
+    ///
+    /// The tangents are emitted as $(x, y, z, w)$, where $w$ is either $1$ or
+    /// $-1$ and encodes the handedness of the tangent basis. The bitangent can
+    /// be recovered as $w(\vec{n} \times \vec{t})$. Faces with degenerate
+    /// texture coordinates do not contribute to tangents.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a vertex has no texture coordinates or its tangent
+    /// cannot be computed.
+    pub fn compute_tangents(
+        &self,
+        uvs: &VertexAttribute<[f32; 2]>,
+    ) -> Result<VertexAttribute<[f32; 4]>, GraphError>
+    where
+        G: FaceTangent + VertexNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        Vector<VertexPosition<G>>: Cross<Output = Vector<VertexPosition<G>>>,
+    {
+        let into_f32 = |x: Scalar<VertexPosition<G>>| x.to_f32().ok_or(GraphError::Geometry);
+        let mut bases: HashMap<_, (Vector<VertexPosition<G>>, Vector<VertexPosition<G>>)> =
+            HashMap::with_capacity(self.vertex_count());
+        for face in self.faces() {
+            let (tangent, bitangent) = match <G as FaceTangent>::tangent(face, uvs) {
+                Ok(basis) => basis,
+                // Skip faces with degenerate texture coordinates.
+                Err(GraphError::Geometry) => continue,
+                Err(error) => return Err(error),
+            };
+            for vertex in face.adjacent_vertices() {
+                bases
+                    .entry(vertex.key())
+                    .and_modify(|basis| {
+                        basis.0 = basis.0 + tangent;
+                        basis.1 = basis.1 + bitangent;
+                    })
+                    .or_insert((tangent, bitangent));
+            }
+        }
+        let mut tangents = VertexAttribute::with_capacity(bases.len());
+        for (key, (tangent, bitangent)) in bases {
+            let normal = self.vertex(key).expect_consistent().normal()?;
+            let tangent = (tangent - (normal * normal.dot(tangent)))
+                .normalize()
+                .ok_or(GraphError::Geometry)?;
+            let w = if normal.cross(tangent).dot(bitangent) < Zero::zero() {
+                -1.0
+            }
+            else {
+                1.0
+            };
+            let (x, y, z) = (VertexPosition::<G>::origin() + tangent).into_xyz();
+            tangents.insert(key, [into_f32(x)?, into_f32(y)?, into_f32(z)?, w]);
+        }
+        Ok(tangents)
     }
-}
-
-impl<G> Consistent for MeshGraph<G> where G: GraphData {}
 
-impl<G> Default for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn default() -> Self {
-        MeshGraph::new()
+    /// Gets an iterator over the normals of vertices in the graph.
+    ///
+    /// Normals are computed as described by [`VertexView::normal`]. Vertices
+    /// for which a normal cannot be computed, such as vertices with no
+    /// adjacent faces, are skipped.
+    ///
+    /// [`VertexView::normal`]: crate::graph::VertexView::normal
+    pub fn vertex_normals(
+        &self,
+    ) -> impl '_ + Iterator<Item = (VertexKey, Vector<VertexPosition<G>>)>
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+    {
+        self.vertices()
+            .filter_map(|vertex| vertex.normal().ok().map(|normal| (vertex.key(), normal)))
     }
-}
 
-impl<G> DynamicArity for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Dynamic = MeshArity;
-
-    fn arity(&self) -> Self::Dynamic {
-        MeshArity::from_components::<FaceView<_>, _>(self.faces())
+    /// Computes the normals of vertices in the graph.
+    ///
+    /// Normals are computed as described by [`VertexView::normal`] and are
+    /// keyed by vertex. Vertices for which a normal cannot be computed are
+    /// omitted.
+    ///
+    /// [`VertexView::normal`]: crate::graph::VertexView::normal
+    pub fn compute_vertex_normals(&self) -> VertexAttribute<Vector<VertexPosition<G>>>
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+    {
+        self.vertex_normals().collect()
     }
-}
 
-impl<P, G> From<P> for MeshGraph<G>
-where
-    P: Polygonal,
-    G: GraphData,
-    G::Vertex: FromGeometry<P::Vertex>,
-{
-    fn from(polygon: P) -> Self {
-        let arity = polygon.arity();
-        MeshGraph::from_raw_buffers_with_arity(0..arity, polygon, arity)
-            .expect("inconsistent polygon")
+    /// Computes the discrete Gaussian curvature of vertices in the graph.
+    ///
+    /// The curvature of a vertex is its angle defect: the difference between
+    /// $2\pi$ (or $\pi$ for boundary vertices) and the sum of the angles
+    /// formed at the vertex by its adjacent faces. This is the integrated
+    /// Gaussian curvature about the vertex, so it is zero in flat regions,
+    /// positive at convex or concave peaks, and negative at saddles.
+    pub fn compute_gaussian_curvatures(&self) -> VertexAttribute<f64>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let angle = |u: Vector<VertexPosition<G>>, w: Vector<VertexPosition<G>>| {
+            let (u, w) = (u.normalize()?, w.normalize()?);
+            let cosine = u.dot(w).to_f64()?;
+            Some(cosine.max(-1.0).min(1.0).acos())
+        };
+        self.vertices()
+            .map(|vertex| {
+                let position = *vertex.position();
+                let mut boundary = false;
+                let mut sum = 0.0;
+                for arc in vertex.outgoing_arcs() {
+                    if arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc() {
+                        boundary = true;
+                    }
+                    if arc.is_boundary_arc() {
+                        continue;
+                    }
+                    let b = *arc.destination_vertex().position();
+                    let c = *arc.previous_arc().source_vertex().position();
+                    sum += angle(b - position, c - position).unwrap_or(0.0);
+                }
+                let total = if boundary {
+                    std::f64::consts::PI
+                }
+                else {
+                    std::f64::consts::TAU
+                };
+                (vertex.key(), total - sum)
+            })
+            .collect()
     }
-}
 
-impl<G> From<OwnedCore<G>> for MeshGraph<G>
-where
-    G: GraphData,
-{
-    fn from(core: OwnedCore<G>) -> Self {
-        MeshGraph { core }
+    /// Computes colors of vertices in the graph from their curvature.
+    ///
+    /// The Gaussian curvature of each vertex (see
+    /// [`MeshGraph::compute_gaussian_curvatures`]) is mapped onto a ramp from
+    /// blue to white to red, where curvature at or below `min` is blue,
+    /// curvature midway between `min` and `max` is white, and curvature at or
+    /// above `max` is red. Colors are RGBA with components in $[0, 1]$ and are
+    /// intended for visualizing and debugging curvature.
+    pub fn curvature_colors(&self, min: f64, max: f64) -> VertexAttribute<[f32; 4]>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        self.compute_gaussian_curvatures()
+            .into_iter()
+            .map(|(key, curvature)| {
+                let t = if max > min {
+                    ((curvature - min) / (max - min)).max(0.0).min(1.0) as f32
+                }
+                else {
+                    0.5
+                };
+                let color = if t < 0.5 {
+                    [2.0 * t, 2.0 * t, 1.0, 1.0]
+                }
+                else {
+                    [1.0, 2.0 * (1.0 - t), 2.0 * (1.0 - t), 1.0]
+                };
+                (key, color)
+            })
+            .collect()
     }
-}
 
-impl<G> From<MeshGraph<G>> for OwnedCore<G>
-where
-    G: GraphData,
-{
-    fn from(graph: MeshGraph<G>) -> Self {
-        let MeshGraph { core, .. } = graph;
-        core
+    /// Computes the valences of vertices in the graph.
+    ///
+    /// This is equivalent to [`VertexView::valence`] for each vertex, but
+    /// visits each arc of the graph exactly once rather than circulating
+    /// about each vertex. This is useful for algorithms that query valences
+    /// repeatedly, such as subdivision schemes.
+    ///
+    /// [`VertexView::valence`]: crate::graph::VertexView::valence
+    pub fn compute_valences(&self) -> VertexAttribute<usize> {
+        let mut valences = self
+            .vertices()
+            .map(|vertex| (vertex.key(), 0))
+            .collect::<VertexAttribute<_>>();
+        for arc in self.arcs() {
+            let (_, destination) = arc.key().into();
+            *valences.entry(destination).or_insert(0) += 1;
+        }
+        valences
     }
-}
-
-impl<E, G> FromEncoding<E> for MeshGraph<G>
-where
-    E: FaceDecoder + VertexDecoder,
-    G: GraphData,
-    G::Face: FromGeometry<E::Face>,
-    G::Vertex: FromGeometry<E::Vertex>,
-{
-    type Error = GraphError;
 
-    fn from_encoding(
-        vertices: <E as VertexDecoder>::Output,
-        faces: <E as FaceDecoder>::Output,
-    ) -> Result<Self, Self::Error> {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let keys = vertices
-            .into_iter()
-            .map(|data| mutation::vertex::insert(&mut mutation, data.into_geometry()))
-            .collect::<Vec<_>>();
-        for (perimeter, data) in faces {
-            let perimeter = perimeter
-                .into_iter()
-                .map(|index| keys[index])
-                .collect::<SmallVec<[_; 4]>>();
-            let cache = FaceInsertCache::from_storage(&mutation, perimeter.as_slice())?;
-            let data = data.into_geometry();
-            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+    /// Gets the vertices with the highest and lowest valence in the graph.
+    ///
+    /// Returns a tuple of the keys of the vertices with the highest valence
+    /// and the keys of the vertices with the lowest valence, in that order.
+    /// These are typically the irregular (extraordinary) vertices of a graph,
+    /// such as the poles of a $uv$-sphere. Both sets are empty if the graph
+    /// has no vertices and are the same if all vertices have the same valence.
+    pub fn valence_extremes(&self) -> (Vec<VertexKey>, Vec<VertexKey>) {
+        let valences = self.compute_valences();
+        let extremes = |valence: usize| -> Vec<_> {
+            valences
+                .iter()
+                .filter(|(_, n)| **n == valence)
+                .map(|(key, _)| *key)
+                .collect()
+        };
+        match (valences.values().max(), valences.values().min()) {
+            (Some(max), Some(min)) => (extremes(*max), extremes(*min)),
+            _ => (Vec::new(), Vec::new()),
         }
-        mutation.commit().map_err(|(_, error)| error)
     }
-}
-
-impl<G, P> FromIndexer<P, P> for MeshGraph<G>
-where
-    G: GraphData,
-    G::Vertex: FromGeometry<P::Vertex>,
-    P: Map<usize> + Polygonal,
-    P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
-    Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
-{
-    type Error = GraphError;
 
-    // TODO: This appears to be a false positive. The `collect` is necessary,
-    //       because the data is transformed and read randomly by index. See
-    //       https://github.com/rust-lang/rust-clippy/issues/5991
-    #[allow(clippy::needless_collect)]
-    fn from_indexer<I, N>(input: I, indexer: N) -> Result<Self, Self::Error>
+    /// Interpolates a vertex attribute at a point on a face.
+    ///
+    /// The attribute is interpolated by the barycentric coordinates of the
+    /// point with respect to the vertices of the face. See
+    /// [`FaceView::barycentric`].
+    ///
+    /// Returns `None` if the face cannot be found, the point lies outside of
+    /// the face, or any vertex of the face has no attribute.
+    ///
+    /// [`FaceView::barycentric`]: crate::graph::FaceView::barycentric
+    pub fn sample_attribute<T>(
+        &self,
+        face: FaceKey,
+        point: VertexPosition<G>,
+        attribute: &VertexAttribute<T>,
+    ) -> Option<T>
     where
-        I: IntoIterator<Item = P>,
-        N: Indexer<P, P::Vertex>,
+        T: Clone + Interpolate<Output = T>,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
     {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let (indices, vertices) = input.into_iter().index_vertices(indexer);
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in indices {
-            let perimeter = face
-                .into_vertices()
-                .into_iter()
-                .map(|index| vertices[index])
-                .collect::<SmallVec<[_; 4]>>();
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        let face = self.face(face)?;
+        let weights = face.barycentric(point)?;
+        // Accumulate the weighted attributes pairwise by interpolating each
+        // attribute into the mean of the preceding attributes.
+        let mut sample: Option<(T, f64)> = None;
+        for (vertex, weight) in face.adjacent_vertices().zip(weights) {
+            let value = attribute.get(&vertex.key())?.clone();
+            sample = Some(match sample {
+                Some((sample, total)) => {
+                    let total = total + weight;
+                    if total > 0.0 {
+                        (sample.lerp(value, R64::from_inner(weight / total)), total)
+                    }
+                    else {
+                        (sample, total)
+                    }
+                }
+                None => (value, weight),
+            });
         }
-        mutation.commit().map_err(|(_, error)| error)
+        sample.map(|(sample, _)| sample)
     }
-}
 
-impl<G, P> FromIterator<P> for MeshGraph<G>
-where
-    G: GraphData,
-    G::Vertex: FromGeometry<P::Vertex>,
-    P: Polygonal,
-    P::Vertex: Clone + Eq + Hash,
-    Self: FromIndexer<P, P>,
-{
-    fn from_iter<I>(input: I) -> Self
-    where
-        I: IntoIterator<Item = P>,
-    {
-        Self::from_indexer(input, HashIndexer::default()).unwrap_or_else(|_| Self::default())
+    /// Gets the key of the edge shared by two faces.
+    ///
+    /// Returns `None` if either face cannot be found or if the faces are not
+    /// adjacent.
+    pub fn shared_edge(&self, a: FaceKey, b: FaceKey) -> Option<EdgeKey> {
+        self.face(a)?
+            .adjacent_arcs()
+            .find(|arc| arc.opposite_arc().face().map(|face| face.key()) == Some(b))
+            .map(|arc| arc.edge().key())
     }
-}
 
-impl<P, G, H> FromRawBuffers<P, H> for MeshGraph<G>
-where
-    P: IntoVertices + Polygonal,
-    P::Vertex: Integer + ToPrimitive + Unsigned,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
+    /// Gets an iterator of immutable views over the boundary edges in the
+    /// graph.
+    ///
+    /// An edge is on a boundary if either of its arcs has no face. See
+    /// [`EdgeView::is_boundary_edge`].
+    ///
+    /// [`EdgeView::is_boundary_edge`]: crate::graph::EdgeView::is_boundary_edge
+    pub fn boundary_edges(&self) -> impl Iterator<Item = EdgeView<&Self>> {
+        self.edges().filter(|edge| edge.is_boundary_edge())
+    }
 
-    fn from_raw_buffers<I, J>(indices: I, vertices: J) -> Result<Self, Self::Error>
-    where
-        I: IntoIterator<Item = P>,
-        J: IntoIterator<Item = H>,
-    {
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let vertices = vertices
-            .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in indices {
-            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
-            for index in face.into_vertices() {
-                let index = <usize as NumCast>::from(index).unwrap();
-                perimeter.push(*vertices.get(index).ok_or(GraphError::TopologyNotFound)?);
+    /// Gets the boundaries of the graph as loops of edges.
+    ///
+    /// Each loop is formed from the edges of a ring of boundary arcs and its
+    /// edges are ordered by traversing that ring. A graph with more than one
+    /// hole has more than one loop and an enclosed graph has no loops.
+    pub fn boundary_loops(&self) -> Vec<Vec<EdgeKey>> {
+        let mut visited = HashSet::new();
+        let mut loops = vec![];
+        for arc in self.arcs().filter(|arc| arc.is_boundary_arc()) {
+            if visited.contains(&arc.key()) {
+                continue;
             }
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+            let mut edges = vec![];
+            let mut next = arc;
+            while visited.insert(next.key()) {
+                edges.push(next.edge().key());
+                next = next.into_next_arc();
+            }
+            loops.push(edges);
         }
-        mutation.commit().map_err(|(_, error)| error)
+        loops
     }
-}
 
-impl<N, G, H> FromRawBuffersWithArity<N, H> for MeshGraph<G>
-where
-    N: Integer + ToPrimitive + Unsigned,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
+    /// Gets pairs of faces that are formed from the same set of vertices.
+    ///
+    /// Faces are paired regardless of their winding. Such faces typically
+    /// arise from importing overlapping geometry, such as a two-sided
+    /// triangle. If more than two faces share the same vertices, then each
+    /// pairing of those faces is included.
+    pub fn duplicate_faces(&self) -> Vec<(FaceKey, FaceKey)> {
+        let mut visited = HashSet::with_capacity(self.face_count());
+        let mut duplicates = vec![];
+        for face in self.faces() {
+            let keys = face
+                .adjacent_vertices()
+                .map(|vertex| vertex.key())
+                .collect::<HashSet<_>>();
+            // Any duplicate of the face must be adjacent to each of its
+            // vertices, so only the faces of one vertex are examined.
+            let vertex = face.adjacent_vertices().next().expect_consistent();
+            for duplicate in vertex.adjacent_faces() {
+                if duplicate.key() == face.key() || visited.contains(&duplicate.key()) {
+                    continue;
+                }
+                if duplicate.arity() == keys.len()
+                    && duplicate
+                        .adjacent_vertices()
+                        .all(|vertex| keys.contains(&vertex.key()))
+                {
+                    duplicates.push((face.key(), duplicate.key()));
+                }
+            }
+            visited.insert(face.key());
+        }
+        duplicates
+    }
 
-    /// Creates a [`MeshGraph`] from [raw buffers][`buffer`]. The arity of the
-    /// polygons in the index buffer must be given and constant.
+    /// Removes faces that are formed from the same set of vertices as another
+    /// face.
     ///
-    /// # Errors
+    /// For each set of duplicate faces, one face is retained. See
+    /// [`MeshGraph::duplicate_faces`].
     ///
-    /// Returns an error if the arity of the index buffer is not constant, any
-    /// index is out of bounds, or there is an error inserting topology into the
-    /// graph.
+    /// Returns the number of removed faces.
+    pub fn remove_duplicate_faces(&mut self) -> usize {
+        let mut n = 0;
+        for (_, key) in self.duplicate_faces() {
+            if let Some(face) = self.face_mut(key) {
+                face.remove();
+                n += 1;
+            }
+        }
+        n
+    }
+
+    /// Retains only the faces for which the given predicate returns `true`.
+    ///
+    /// Faces for which the predicate returns `false` are removed and their
+    /// edges become boundaries of any remaining adjacent faces. Edges that no
+    /// longer bound any face are removed, as are any vertices that become
+    /// disjoint as a result. Edges and vertices that did not bound a face
+    /// before retaining are not affected.
     ///
     /// # Examples
     ///
+    /// Retaining the upper hemisphere of a sphere:
+    ///
     /// ```rust
+    /// # extern crate decorum;
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
+    /// use decorum::R64;
     /// use nalgebra::Point3;
     /// use plexus::graph::MeshGraph;
-    /// use plexus::index::{Flat3, LruIndexer};
     /// use plexus::prelude::*;
     /// use plexus::primitive::generate::Position;
     /// use plexus::primitive::sphere::UvSphere;
     ///
-    /// type E3 = Point3<f64>;
+    /// type E3 = Point3<R64>;
     ///
-    /// let (indices, positions) = UvSphere::new(16, 16)
-    ///     .polygons::<Position<E3>>()
-    ///     .triangulate()
-    ///     .index_vertices::<Flat3, _>(LruIndexer::with_capacity(256));
-    /// let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(indices, positions, 3).unwrap();
+    /// let mut graph: MeshGraph<Point3<f64>> =
+    ///     UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+    /// graph.retain_faces(|face| face.centroid().z > 0.0);
     /// ```
-    ///
-    /// [`buffer`]: crate::buffer
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    fn from_raw_buffers_with_arity<I, J>(
-        indices: I,
-        vertices: J,
-        arity: usize,
-    ) -> Result<Self, Self::Error>
+    pub fn retain_faces<F>(&mut self, mut f: F)
     where
-        I: IntoIterator<Item = N>,
-        J: IntoIterator<Item = H>,
+        F: FnMut(FaceView<&Self>) -> bool,
     {
-        use itertools::Itertools;
-
-        if arity < 3 {
-            return Err(GraphError::ArityNonPolygonal);
+        let faces = self
+            .faces()
+            .filter(|face| !f(*face))
+            .map(|face| face.key())
+            .collect::<Vec<_>>();
+        let mut edges = HashSet::new();
+        for key in faces {
+            if let Some(face) = self.face_mut(key) {
+                edges.extend(face.adjacent_arcs().map(|arc| arc.edge().key()));
+                face.remove();
+            }
         }
-        let mut mutation = Mutation::from(MeshGraph::new());
-        let vertices = vertices
+        let edges = edges
             .into_iter()
-            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
-            .collect::<Vec<_>>();
-        for face in &indices
+            .filter(|key| {
+                self.edge(*key).map_or(false, |edge| {
+                    let arc = edge.arc();
+                    arc.face().is_none() && arc.opposite_arc().face().is_none()
+                })
+            })
+            .collect::<HashSet<_>>();
+        // Vertices become disjoint if all of their edges are removed.
+        let vertices = edges
+            .iter()
+            .flat_map(|key| {
+                let arc = self.edge(*key).expect_consistent().into_arc();
+                [arc.source_vertex().key(), arc.destination_vertex().key()]
+            })
+            .collect::<HashSet<_>>()
             .into_iter()
-            .map(|index| <usize as NumCast>::from(index).unwrap())
-            .chunks(arity)
-        {
-            let face = face.collect::<Vec<_>>();
-            if face.len() != arity {
-                // Index buffer length is not a multiple of arity.
-                return Err(GraphError::ArityConflict {
-                    expected: arity,
-                    actual: face.len(),
-                });
-            }
-            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(arity);
-            for index in face {
-                perimeter.push(*vertices.get(index).ok_or(GraphError::TopologyNotFound)?);
+            .filter(|key| {
+                self.vertex(*key)
+                    .expect_consistent()
+                    .outgoing_arcs()
+                    .all(|arc| edges.contains(&arc.edge().key()))
+            })
+            .collect::<Vec<_>>();
+        for key in edges {
+            if let Some(edge) = self.edge_mut(key) {
+                edge.into_arc().remove();
             }
-            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
-            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
         }
-        mutation.commit().map_err(|(_, error)| error)
+        Mutation::take(self)
+            .bypass_or_commit_with(|mutation| {
+                vertices.into_iter().try_for_each(|key| {
+                    mutation::vertex::remove_disjoint(&mut *mutation, key).map(|_| ())
+                })
+            })
+            .map_err(|(_, error)| error)
+            .expect_consistent();
     }
-}
-
-impl<G> IntoPolygons for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Output = vec::IntoIter<Self::Polygon>;
-    type Polygon = UnboundedPolygon<G::Vertex>;
-
-    fn into_polygons(self) -> Self::Output {
-        use crate::IteratorExt as _;
 
-        self.faces()
-            .map(|face| {
-                // The arity of a face in a graph must be polygonal (three or
-                // higher) so this should never fail.
-                face.adjacent_vertices()
-                    .map(|vertex| vertex.get().clone())
-                    .try_collect()
-                    .expect_consistent()
+    /// Reverses the orientation of the graph.
+    ///
+    /// The winding of every face is reversed, so the vertices of each face
+    /// are visited in the opposite order and face normals (and any normals
+    /// computed from them) are negated. Each face and the data of its arcs
+    /// move to the opposite arcs of its perimeter. Keys, vertices, and edges
+    /// are unaffected.
+    ///
+    /// See also [`FaceView::flip`], which reverses a single face.
+    ///
+    /// [`FaceView::flip`]: crate::graph::FaceView::flip
+    pub fn flip_orientation(&mut self) {
+        // The arc opposite an arc AB takes on the face and data of AB. Its
+        // next and previous arcs are the arcs opposite the previous and next
+        // arcs of AB, respectively.
+        let arcs = self
+            .core
+            .arcs
+            .iter()
+            .map(|(key, arc)| {
+                (
+                    key.into_opposite(),
+                    arc.previous.map(ArcKey::into_opposite),
+                    arc.next.map(ArcKey::into_opposite),
+                    arc.face,
+                    arc.data.clone(),
+                )
             })
-            .collect::<Vec<_>>()
-            .into_iter()
+            .collect::<Vec<_>>();
+        for (key, next, previous, face, data) in arcs {
+            let arc = self.core.arcs.get_mut(&key).expect_consistent();
+            arc.next = next;
+            arc.previous = previous;
+            arc.face = face;
+            arc.data = data;
+        }
+        let faces = self.core.faces.iter().map(|(key, _)| key).collect::<Vec<_>>();
+        for key in faces {
+            let face = self.core.faces.get_mut(&key).expect_consistent();
+            face.arc = face.arc.into_opposite();
+        }
     }
-}
-
-impl<G> Parametric for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Data = G;
-}
-
-impl<G> StaticArity for MeshGraph<G>
-where
-    G: GraphData,
-{
-    type Static = (usize, Option<usize>);
-
-    const ARITY: Self::Static = (3, None);
-}
 
-impl<T, H, G, const A: usize> TryFrom<MeshBuffer<Flat<T, A>, H>> for MeshGraph<G>
-where
-    Constant<A>: ToType,
-    TypeOf<A>: NonZero,
-    T: Copy + Integer + NumCast + Unsigned,
-    H: Clone,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
-
-    /// Creates a [`MeshGraph`] from a flat [`MeshBuffer`]. The arity of the
-    /// polygons in the index buffer must be known and constant.
+    /// Welds vertices with positions within the given distance of each other.
     ///
-    /// # Errors
+    /// Vertices are grouped using a spatial hash grid (see [`SpatialIndexer`])
+    /// and each group is merged into the first vertex of the group, which
+    /// retains its data. Faces and edges are rewired to the merged vertices.
+    /// Faces that collapse into fewer than three distinct vertices or that
+    /// would visit a vertex more than once are degenerate and are removed along
+    /// with any vertices that no longer have adjacent arcs. Edges that are not
+    /// adjacent to any faces are kept unless they collapse into a single
+    /// vertex.
     ///
-    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
-    /// [`MeshBuffer`].
+    /// Groups that cannot be merged without producing non-manifold topology,
+    /// such as an edge with more than two faces, are skipped. See
+    /// [`MeshGraph::weld_with_report`].
     ///
-    /// # Examples
+    /// This is useful for graphs constructed from unindexed "triangle soup",
+    /// where [`HashIndexer`] only merges vertices with exactly equal keys.
     ///
-    /// ```rust
-    /// # extern crate nalgebra;
-    /// # extern crate plexus;
-    /// #
-    /// use nalgebra::Point2;
-    /// use plexus::buffer::MeshBuffer;
-    /// use plexus::graph::MeshGraph;
-    /// use plexus::index::Flat4;
-    /// use plexus::prelude::*;
-    /// use std::convert::TryFrom;
+    /// If any vertices are merged, then the graph is rebuilt and **all keys
+    /// into the graph are invalidated**, including the keys of faces. Face data
+    /// and the data of edges that are not adjacent to any faces is preserved.
+    /// The data of all other arcs and edges is not preserved.
     ///
-    /// type E2 = Point2<f64>;
+    /// Returns the number of vertices removed from the graph, which includes
+    /// both merged vertices and vertices removed along with degenerate faces.
     ///
-    /// let buffer = MeshBuffer::<Flat4, E2>::from_raw_buffers(
-    ///     vec![0u64, 1, 2, 3],
-    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
-    /// )
-    /// .unwrap();
-    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
-    /// ```
+    /// # Errors
     ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    fn try_from(buffer: MeshBuffer<Flat<T, A>, H>) -> Result<Self, Self::Error> {
-        let arity = buffer.arity();
-        let (indices, vertices) = buffer.into_raw_buffers();
-        MeshGraph::from_raw_buffers_with_arity(indices, vertices, arity)
+    /// This function currently never returns an error, because groups that
+    /// cannot be merged are skipped rather than failing the weld.
+    ///
+    /// [`HashIndexer`]: crate::index::HashIndexer
+    /// [`MeshGraph::weld_with_report`]: crate::graph::MeshGraph::weld_with_report
+    /// [`SpatialIndexer`]: crate::index::SpatialIndexer
+    pub fn weld(&mut self, tolerance: f64) -> Result<usize, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        Ok(self.weld_with_report(tolerance).merged())
     }
-}
-
-impl<P, H, G> TryFrom<MeshBuffer<P, H>> for MeshGraph<G>
-where
-    P: Grouping<Group = P> + IntoVertices + Polygonal,
-    P::Vertex: Copy + Integer + NumCast + Unsigned,
-    H: Clone,
-    G: GraphData,
-    G::Vertex: FromGeometry<H>,
-{
-    type Error = GraphError;
 
-    /// Creates a [`MeshGraph`] from a structured [`MeshBuffer`].
+    /// Welds vertices with positions within the given distance of each other
+    /// and reports the groups of vertices that could not be merged.
     ///
-    /// # Errors
+    /// Welding is the same as [`MeshGraph::weld`]. All groups are first merged
+    /// at once without modifying the graph. Any arc that is then shared by
+    /// more than one face is non-manifold, so the latest group merged into one
+    /// of its vertices is rejected and only the faces adjacent to that group
+    /// are examined again. This repeats until no such arcs remain and the
+    /// graph is then rebuilt once.
     ///
-    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
-    /// [`MeshBuffer`].
+    /// In rare cases, the rebuild may still fail, such as when merging groups
+    /// joins the boundaries of faces in a way that cannot be represented. In
+    /// that case, the graph is not modified and all groups are rejected.
     ///
     /// # Examples
     ///
+    /// Welding the vertices of three triangles that share an edge. The edge
+    /// cannot have three faces, so only one of its vertices is merged.
+    ///
     /// ```rust
     /// # extern crate nalgebra;
     /// # extern crate plexus;
     /// #
-    /// use nalgebra::Point2;
-    /// use plexus::buffer::MeshBuffer;
+    /// use nalgebra::Point3;
     /// use plexus::graph::MeshGraph;
-    /// use plexus::prelude::*;
-    /// use plexus::primitive::Tetragon;
-    /// use std::convert::TryFrom;
-    ///
-    /// type E2 = Point2<f64>;
+    /// use plexus::primitive::Trigon;
     ///
-    /// let buffer = MeshBuffer::<Tetragon<u64>, E2>::from_raw_buffers(
-    ///     vec![Tetragon::new(0u64, 1, 2, 3)],
-    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+    ///     vec![
+    ///         Trigon::new(0usize, 1, 2),
+    ///         Trigon::new(3, 4, 5),
+    ///         Trigon::new(6, 7, 8),
+    ///     ],
+    ///     vec![
+    ///         (0.0, 0.0, 1.0),
+    ///         (0.0, 0.0, -1.0),
+    ///         (1.0, 0.0, 0.0),
+    ///         (0.0, 0.0, 1.0),
+    ///         (0.0, 0.0, -1.0),
+    ///         (0.0, 1.0, 0.0),
+    ///         (0.0, 0.0, 1.0),
+    ///         (0.0, 0.0, -1.0),
+    ///         (1.0, 1.0, 0.0),
+    ///     ],
     /// )
     /// .unwrap();
-    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
+    /// let report = graph.weld_with_report(1e-6);
+    ///
+    /// assert_eq!(2, report.merged());
+    /// assert_eq!(1, report.rejected().len());
+    /// assert_eq!(7, graph.vertex_count());
     /// ```
     ///
-    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
-    /// [`MeshGraph`]: crate::graph::MeshGraph
-    fn try_from(buffer: MeshBuffer<P, H>) -> Result<Self, Self::Error> {
-        let (indices, vertices) = buffer.into_raw_buffers();
-        MeshGraph::from_raw_buffers(indices, vertices)
+    /// [`MeshGraph::weld`]: crate::graph::MeshGraph::weld
+    pub fn weld_with_report(&mut self, tolerance: f64) -> WeldReport
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        // The indexer is used only to group positions, so the topology type
+        // merely describes the vertex data given to it.
+        let mut indexer =
+            SpatialIndexer::<Trigon<(VertexKey, VertexPosition<G>)>, _>::with_tolerance(tolerance);
+        let mut groups = Vec::<SmallVec<[VertexKey; 2]>>::new();
+        let mut representatives = Vec::with_capacity(self.vertex_count());
+        let mut indices = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let (index, _) =
+                indexer.index((vertex.key(), *vertex.position()), |(_, position)| position);
+            if index == groups.len() {
+                groups.push(SmallVec::new());
+            }
+            groups[index].push(vertex.key());
+            // Initially, each vertex is its own representative.
+            indices.insert(vertex.key(), representatives.len());
+            representatives.push(vertex.get().clone());
+        }
+        let groups = groups
+            .into_iter()
+            .filter(|group| group.len() > 1)
+            .collect::<Vec<_>>();
+        if groups.is_empty() {
+            return WeldReport::default();
+        }
+        let mut merged = indices.clone();
+        // Map the representatives of merged groups to their groups.
+        let mut owners = HashMap::with_capacity(groups.len());
+        for (n, group) in groups.iter().enumerate() {
+            let index = indices[&group[0]];
+            for key in &group[1..] {
+                merged.insert(*key, index);
+            }
+            owners.insert(index, n);
+        }
+        // Count the faces that occupy each arc after merging. Arcs occupied by
+        // more than one face are conflicts.
+        let mut perimeters = HashMap::with_capacity(self.face_count());
+        let mut arcs = HashMap::<_, SmallVec<[FaceKey; 2]>>::new();
+        let mut conflicts = Vec::new();
+        for face in self.faces() {
+            let perimeter = Self::grouped_perimeter(face, &merged).unwrap_or_default();
+            for ab in perimeter.iter().cloned().perimeter() {
+                let faces = arcs.entry(ab).or_default();
+                faces.push(face.key());
+                if faces.len() == 2 {
+                    conflicts.push(ab);
+                }
+            }
+            perimeters.insert(face.key(), perimeter);
+        }
+        let mut rejected = Vec::new();
+        while let Some((a, b)) = conflicts.pop() {
+            if arcs.get(&(a, b)).map_or(0, |faces| faces.len()) < 2 {
+                continue;
+            }
+            let n = match (owners.get(&a), owners.get(&b)) {
+                (Some(m), Some(n)) => *m.max(n),
+                (Some(n), None) | (None, Some(n)) => *n,
+                // Without a group to reject, the rebuild fails below.
+                (None, None) => continue,
+            };
+            let group = &groups[n];
+            owners.remove(&indices[&group[0]]);
+            for key in &group[1..] {
+                merged.insert(*key, indices[key]);
+            }
+            rejected.push(n);
+            let faces = group
+                .iter()
+                .flat_map(|key| self.vertex(*key))
+                .flat_map(|vertex| vertex.adjacent_faces().keys().collect::<Vec<_>>())
+                .collect::<HashSet<_>>();
+            for abc in faces {
+                let face = self.face(abc).expect_consistent();
+                let perimeter = Self::grouped_perimeter(face, &merged).unwrap_or_default();
+                for ab in perimeters[&abc].iter().cloned().perimeter() {
+                    if let Some(faces) = arcs.get_mut(&ab) {
+                        faces.retain(|key| *key != abc);
+                    }
+                }
+                for ab in perimeter.iter().cloned().perimeter() {
+                    let faces = arcs.entry(ab).or_default();
+                    faces.push(abc);
+                    if faces.len() > 1 {
+                        conflicts.push(ab);
+                    }
+                }
+                perimeters.insert(abc, perimeter);
+            }
+            // Rejecting a group may not resolve the conflict.
+            conflicts.push((a, b));
+        }
+        if rejected.len() == groups.len() {
+            return WeldReport {
+                merged: 0,
+                rejected: groups.into_iter().map(SmallVec::into_vec).collect(),
+            };
+        }
+        match self.rebuild_with_vertex_groups(&merged, &representatives) {
+            Ok((graph, vertices)) => {
+                let rejected = rejected
+                    .into_iter()
+                    .map(|n| {
+                        groups[n]
+                            .iter()
+                            .flat_map(|key| vertices.get(&merged[key]).copied())
+                            .collect::<Vec<_>>()
+                    })
+                    .filter(|group| !group.is_empty())
+                    .collect();
+                let n = self.vertex_count() - graph.vertex_count();
+                *self = graph;
+                WeldReport {
+                    merged: n,
+                    rejected,
+                }
+            }
+            Err(_) => WeldReport {
+                merged: 0,
+                rejected: groups.into_iter().map(SmallVec::into_vec).collect(),
+            },
+        }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use decorum::R64;
-    use nalgebra::{Point2, Point3, Vector3};
-    use num::Zero;
+    /// Snaps vertex positions to a grid and welds vertices that share a cell.
+    ///
+    /// Each component of each position is rounded to the nearest multiple of
+    /// the given resolution. Vertices with positions in the same grid cell then
+    /// have exactly equal positions and are merged using a single hashing pass
+    /// rather than the distance queries used by [`MeshGraph::weld`], which is
+    /// much faster. The first vertex of each cell retains its data.
+    ///
+    /// As with [`MeshGraph::weld`], degenerate faces are removed, edges that
+    /// are not adjacent to any faces are kept, and face data is preserved. If
+    /// any vertices are merged, then the graph is rebuilt and **all keys into
+    /// the graph are invalidated**.
+    ///
+    /// Returns the number of vertices removed from the graph, which includes
+    /// both merged vertices and vertices removed along with degenerate faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a position cannot be quantized or if welding
+    /// produces non-manifold topology. If an error is returned, then the graph
+    /// is not modified.
+    ///
+    /// [`MeshGraph::weld`]: crate::graph::MeshGraph::weld
+    pub fn quantize_and_weld(&mut self, resolution: f64) -> Result<usize, GraphError>
+    where
+        G::Vertex: AsPositionMut,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let into_cell = |x: Scalar<VertexPosition<G>>| {
+            x.to_f64()
+                .and_then(|x| (x / resolution).round().to_i64())
+                .ok_or(GraphError::Geometry)
+        };
+        let from_cell = |x: i64| {
+            <Scalar<VertexPosition<G>> as NumCast>::from(x as f64 * resolution)
+                .ok_or(GraphError::Geometry)
+        };
+        let mut cells = HashMap::new();
+        let mut representatives = Vec::new();
+        let mut groups = HashMap::with_capacity(self.vertex_count());
+        let mut positions = HashMap::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            let (x, y, z) = vertex.position().into_xyz();
+            let cell = (into_cell(x)?, into_cell(y)?, into_cell(z)?);
+            let position = VertexPosition::<G>::from_xyz(
+                from_cell(cell.0)?,
+                from_cell(cell.1)?,
+                from_cell(cell.2)?,
+            );
+            let index = *cells.entry(cell).or_insert_with(|| {
+                let mut data = vertex.get().clone();
+                *data.as_position_mut() = position;
+                representatives.push(data);
+                representatives.len() - 1
+            });
+            groups.insert(vertex.key(), index);
+            positions.insert(vertex.key(), position);
+        }
+        if representatives.len() == self.vertex_count() {
+            for mut vertex in self.vertex_orphans() {
+                *vertex.get_mut().as_position_mut() = positions[&vertex.key()];
+            }
+            Ok(0)
+        }
+        else {
+            let (graph, _) = self.rebuild_with_vertex_groups(&groups, &representatives)?;
+            let n = self.vertex_count() - graph.vertex_count();
+            *self = graph;
+            Ok(n)
+        }
+    }
+
+    // Gets the perimeter of a face in which each vertex is replaced by the
+    // index of the representative of its group or `None` if the face is
+    // degenerate after this replacement.
+    fn grouped_perimeter(
+        face: FaceView<&Self>,
+        groups: &HashMap<VertexKey, usize>,
+    ) -> Option<SmallVec<[usize; 4]>> {
+        let mut perimeter = face
+            .adjacent_vertices()
+            .map(|vertex| groups[&vertex.key()])
+            .collect::<SmallVec<[_; 4]>>();
+        perimeter.dedup();
+        while perimeter.len() > 1 && perimeter.first() == perimeter.last() {
+            perimeter.pop();
+        }
+        let set = perimeter.iter().collect::<HashSet<_>>();
+        if perimeter.len() < 3 || set.len() != perimeter.len() {
+            None
+        }
+        else {
+            Some(perimeter)
+        }
+    }
+
+    // Gets a graph in which each vertex is replaced by the representative of
+    // its group along with the keys of the inserted representatives.
+    // Representatives are only inserted if they are used by a face or by an
+    // edge that is not adjacent to any faces.
+    fn rebuild_with_vertex_groups(
+        &self,
+        groups: &HashMap<VertexKey, usize>,
+        representatives: &[G::Vertex],
+    ) -> Result<(Self, HashMap<usize, VertexKey>), GraphError> {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut vertices = HashMap::with_capacity(representatives.len());
+        for face in self.faces() {
+            let perimeter = match Self::grouped_perimeter(face, groups) {
+                Some(perimeter) => perimeter,
+                None => continue,
+            };
+            let perimeter = perimeter
+                .into_iter()
+                .map(|index| {
+                    *vertices.entry(index).or_insert_with(|| {
+                        mutation::vertex::insert(&mut mutation, representatives[index].clone())
+                    })
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, || {
+                (Default::default(), face.get().clone())
+            })?;
+        }
+        // Edges that are not adjacent to any faces are not reached via faces,
+        // so they are inserted after all faces along with their data.
+        for edge in self.edges() {
+            let arc = edge.arc();
+            let opposite = arc.opposite_arc();
+            if !(arc.is_boundary_arc() && opposite.is_boundary_arc()) {
+                continue;
+            }
+            let (a, b) = (
+                groups[&arc.source_vertex().key()],
+                groups[&arc.destination_vertex().key()],
+            );
+            if a == b {
+                continue;
+            }
+            let mut insert = |index: usize| {
+                *vertices.entry(index).or_insert_with(|| {
+                    mutation::vertex::insert(&mut mutation, representatives[index].clone())
+                })
+            };
+            let endpoints = (insert(a), insert(b));
+            mutation::edge::get_or_insert_disjoint_with(&mut mutation, endpoints, || {
+                (
+                    edge.get().clone(),
+                    (arc.get().clone(), opposite.get().clone()),
+                )
+            })?;
+        }
+        let graph = mutation.commit().map_err(|(_, error)| error)?;
+        Ok((graph, vertices))
+    }
+
+    /// Inserts a triangle with the given vertex data.
+    ///
+    /// A vertex is reused if its position is exactly equal to the position of
+    /// the given vertex data. Otherwise, a vertex is inserted. This allows a
+    /// graph to be built from an unindexed "triangle soup" without an
+    /// [`Indexer`], such that triangles that share positions also share
+    /// vertices and edges. Vertices are found by comparing the given positions
+    /// with the position of each vertex in the graph, so building a large
+    /// graph this way is slow.
+    ///
+    /// Returns the key of the inserted face.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any two positions are equal or if the triangle
+    /// cannot be inserted, such as when an arc of the triangle is already
+    /// occupied by a face. If an error is returned, then the graph is not
+    /// modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    ///
+    /// let mut graph = MeshGraph::<Point2<f64>>::default();
+    /// graph
+    ///     .push_triangle(
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(1.0, 0.0),
+    ///         Point2::new(1.0, 1.0),
+    ///     )
+    ///     .unwrap();
+    /// graph
+    ///     .push_triangle(
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(1.0, 1.0),
+    ///         Point2::new(0.0, 1.0),
+    ///     )
+    ///     .unwrap();
+    ///
+    /// assert_eq!(4, graph.vertex_count());
+    /// ```
+    ///
+    /// [`Indexer`]: crate::index::Indexer
+    pub fn push_triangle(
+        &mut self,
+        a: G::Vertex,
+        b: G::Vertex,
+        c: G::Vertex,
+    ) -> Result<FaceKey, GraphError>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: PartialEq,
+    {
+        if a.as_position() == b.as_position()
+            || b.as_position() == c.as_position()
+            || c.as_position() == a.as_position()
+        {
+            return Err(GraphError::TopologyMalformed);
+        }
+        let find = |data: &G::Vertex| {
+            self.vertices()
+                .find(|vertex| vertex.position() == data.as_position())
+                .map(|vertex| vertex.key())
+        };
+        let keys = [find(&a), find(&b), find(&c)];
+        let (_, face) = Mutation::take(self)
+            .bypass_or_commit_with(|mutation| {
+                let mut inserted = SmallVec::<[VertexKey; 3]>::new();
+                let perimeter = [(a, keys[0]), (b, keys[1]), (c, keys[2])].map(|(data, key)| {
+                    key.unwrap_or_else(|| {
+                        let key = mutation::vertex::insert(&mut *mutation, data);
+                        inserted.push(key);
+                        key
+                    })
+                });
+                // Vertices are inserted before the face so that the face can
+                // be validated. If the face is rejected, then the inserted
+                // vertices are removed and the error is returned only after
+                // the mutation is committed, so that the graph is not
+                // modified.
+                match FaceInsertCache::from_storage(mutation.as_mut(), &perimeter) {
+                    Ok(cache) => {
+                        mutation::face::insert_with(mutation, cache, Default::default).map(Ok)
+                    }
+                    Err(error) => {
+                        for key in inserted {
+                            mutation::vertex::remove_disjoint(&mut *mutation, key)?;
+                        }
+                        Ok(Err(error))
+                    }
+                }
+            })
+            .map_err(|(_, error)| error)?;
+        face
+    }
+
+    /// Bridges two faces with quadrilaterals.
+    ///
+    /// Bridging removes the source and destination faces and inserts a
+    /// quadrilateral between each pair of corresponding arcs in their
+    /// perimeters. The faces must have the same arity and should have
+    /// opposing orientations, such as the open ends of a tube.
+    ///
+    /// The offset rotates the correspondence of vertices between the faces.
+    /// This can be used to prevent the bridge from twisting.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either face cannot be found, the faces are the
+    /// same, or the arity of the faces differs. Differing arities are reported
+    /// as [`GraphError::ArityConflict`] with the arity of the source face as
+    /// the expected arity.
+    pub fn bridge(
+        &mut self,
+        source: FaceKey,
+        destination: FaceKey,
+        offset: usize,
+    ) -> Result<(), GraphError> {
+        let face = self.face(source).ok_or(GraphError::TopologyNotFound)?;
+        let expected = face.arity();
+        let actual = self
+            .face(destination)
+            .ok_or(GraphError::TopologyNotFound)?
+            .arity();
+        if expected != actual {
+            return Err(GraphError::ArityConflict { expected, actual });
+        }
+        let cache = FaceBridgeCache::from_face(face, destination, offset)?;
+        Mutation::take(self)
+            .bypass_or_commit_with(|mutation| mutation::face::bridge(mutation, cache))
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        Ok(())
+    }
+
+    /// Splits the graph along a path.
+    ///
+    /// Splitting a graph creates boundaries along the given path and copies any
+    /// necessary vertex, arc, and edge data.
+    ///
+    /// If the path bisects the graph, then splitting will result in disjointed
+    /// sub-graphs.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two triangles.
+    /// let mut graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0usize, 1, 2), Trigon::new(2, 1, 3)],
+    ///     vec![(-1.0, 0.0), (0.0, -1.0), (0.0, 1.0), (1.0, 0.0)],
+    /// )
+    /// .unwrap();
+    ///
+    /// // Find the shared edge that bisects the triangles and then construct a path
+    /// // along the edge and split the graph.
+    /// let key = graph
+    ///     .edges()
+    ///     .find(|edge| !edge.is_boundary_edge())
+    ///     .map(|edge| edge.into_arc().key())
+    ///     .unwrap();
+    /// let mut path = graph.arc_mut(key).unwrap().into_path();
+    /// MeshGraph::split_at_path(path).unwrap();
+    /// ```
+    pub fn split_at_path(path: Path<&mut Self>) -> Result<(), GraphError> {
+        let _ = path;
+        unimplemented!()
+    }
+
+    /// Gets the number of connected components in the graph.
+    ///
+    /// Faces are connected if they share an edge. Unlike
+    /// [`MeshGraph::connected_components`], this does not construct any
+    /// graphs.
+    pub fn component_count(&self) -> usize {
+        self.face_components().len()
+    }
+
+    /// Partitions the faces of the graph into connected components.
+    ///
+    /// Faces are connected if they share an edge. Each set contains the keys
+    /// of the faces in a component. Unlike [`MeshGraph::connected_components`],
+    /// this does not construct any graphs.
+    pub fn component_faces(&self) -> Vec<HashSet<FaceKey>> {
+        self.face_components()
+            .into_iter()
+            .map(|keys| keys.into_iter().collect())
+            .collect()
+    }
+
+    /// Gets the Euler characteristic of the graph.
+    ///
+    /// The Euler characteristic is $V-E+F$, where $E$ is the number of
+    /// (undirected) edges.
+    pub fn euler_characteristic(&self) -> isize {
+        self.vertex_count() as isize - self.edge_count() as isize + self.face_count() as isize
+    }
+
+    /// Gets the genus of the graph.
+    ///
+    /// The genus is derived from the Euler characteristic and is only defined
+    /// for enclosed and orientable graphs. If the graph has more than one
+    /// connected component, then the genus is the sum of the genera of those
+    /// components.
+    ///
+    /// Components are connected faces, so vertices that are not part of any
+    /// face would not be counted as components. Such vertices cannot affect the
+    /// genus: a graph never contains isolated vertices, because every vertex
+    /// must have a leading arc, and a vertex with arcs but no faces is on a
+    /// boundary.
+    ///
+    /// Returns `None` if the graph has boundaries or if the genus cannot be
+    /// derived from the Euler characteristic.
+    pub fn genus(&self) -> Option<usize> {
+        if self.arcs().any(|arc| arc.is_boundary_arc()) {
+            return None;
+        }
+        let n = (2 * self.component_count() as isize) - self.euler_characteristic();
+        if n >= 0 && n % 2 == 0 {
+            Some((n / 2) as usize)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Copies each connected component of the graph into a separate graph.
+    ///
+    /// Faces are connected if they share an edge, so faces that share only a
+    /// vertex are placed into different graphs along with a copy of that
+    /// vertex. Vertex, arc, edge, and face data is copied into each graph.
+    /// Keys are not preserved.
+    pub fn connected_components(&self) -> Vec<Self> {
+        self.face_components()
+            .into_iter()
+            .map(|faces| self.copy_subgraph(iter::empty(), &faces))
+            .collect()
+    }
+
+    // Copies the given vertices and faces into a new graph. The vertices of
+    // faces are always copied, so `vertices` need only include vertices that
+    // may have no faces.
+    fn copy_subgraph<I>(&self, vertices: I, faces: &[FaceKey]) -> Self
+    where
+        I: IntoIterator<Item = VertexKey>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let mut copies = HashMap::new();
+        for key in vertices {
+            let vertex = self.vertex(key).expect_consistent();
+            copies.insert(
+                key,
+                mutation::vertex::insert(&mut mutation, vertex.get().clone()),
+            );
+        }
+        for key in faces.iter() {
+            let face = self.face(*key).expect_consistent();
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| {
+                    *copies.entry(vertex.key()).or_insert_with(|| {
+                        mutation::vertex::insert(&mut mutation, vertex.get().clone())
+                    })
+                })
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter).expect_consistent();
+            mutation::face::insert_with(&mut mutation, cache, || {
+                (Default::default(), face.get().clone())
+            })
+            .expect_consistent();
+        }
+        let mut graph: Self = mutation
+            .commit()
+            .map_err(|(_, error)| error)
+            .expect_consistent();
+        let arcs = faces.iter().flat_map(|key| {
+            let face = self.face(*key).expect_consistent();
+            face.adjacent_arcs()
+                .flat_map(|arc| [arc.key(), arc.opposite_arc().key()])
+                .collect::<SmallVec<[_; 8]>>()
+        });
+        for key in arcs {
+            let arc = self.arc(key).expect_consistent();
+            let (a, b): (VertexKey, VertexKey) = key.into();
+            let mut copy = graph
+                .arc_mut((copies[&a], copies[&b]).into())
+                .expect_consistent();
+            copy.set_geometry(arc.get().clone());
+            copy.into_edge().set_geometry(arc.edge().get().clone());
+        }
+        graph
+    }
+
+    fn face_components(&self) -> Vec<Vec<FaceKey>> {
+        let mut visited = HashSet::with_capacity(self.face_count());
+        let mut components = vec![];
+        for face in self.faces() {
+            if visited.contains(&face.key()) {
+                continue;
+            }
+            let keys = face
+                .traverse_by_depth()
+                .map(|face| face.key())
+                .collect::<Vec<_>>();
+            visited.extend(keys.iter().cloned());
+            components.push(keys);
+        }
+        components
+    }
+
+    /// Gets an iterator over a vertex within each disjoint sub-graph.
+    ///
+    /// Traverses the graph and returns an arbitrary vertex within each
+    /// _disjoint sub-graph_. A sub-graph is _disjoint_ if it cannot be reached
+    /// from all other topology in the graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// // Create a graph from two disjoint triangles.
+    /// let graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (-2.0, 0.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    ///
+    /// // A vertex from each disjoint triangle is returned.
+    /// for vertex in graph.disjoint_subgraph_vertices() {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn disjoint_subgraph_vertices(&self) -> impl ExactSizeIterator<Item = VertexView<&Self>> {
+        let keys = self
+            .core
+            .vertices
+            .iter()
+            .map(|(key, _)| key)
+            .collect::<HashSet<_>>();
+        let mut subkeys = HashSet::with_capacity(self.vertex_count());
+        let mut vertices = SmallVec::<[VertexView<_>; 4]>::new();
+        while let Some(key) = keys.difference(&subkeys).nth(0) {
+            let vertex = VertexView::from(View::bind_unchecked(self, *key));
+            vertices.push(vertex);
+            subkeys.extend(vertex.traverse_by_depth().map(|vertex| vertex.key()));
+        }
+        vertices.into_iter()
+    }
+
+    /// Moves disjoint sub-graphs into separate graphs.
+    ///
+    /// Sub-graphs are disjoint if they share no vertices, as with
+    /// [`MeshGraph::disjoint_subgraph_vertices`]. Unlike
+    /// [`MeshGraph::connected_components`], faces that share only a vertex are
+    /// placed into the same graph. Vertex, arc, edge, and face data is copied
+    /// into each graph, but keys are not preserved. Arcs that are not part of
+    /// a face on either side are not copied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Trigon;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let graph = MeshGraph::<E2>::from_raw_buffers(
+    ///     vec![Trigon::new(0u32, 1, 2), Trigon::new(3, 4, 5)],
+    ///     vec![
+    ///         (-2.0, 0.0),
+    ///         (-1.0, 0.0),
+    ///         (-1.0, 1.0),
+    ///         (1.0, 0.0),
+    ///         (2.0, 0.0),
+    ///         (1.0, 1.0),
+    ///     ],
+    /// )
+    /// .unwrap();
+    /// let graphs = graph.into_disjoint_subgraphs();
+    ///
+    /// assert_eq!(2, graphs.len());
+    /// ```
+    pub fn into_disjoint_subgraphs(self) -> Vec<Self> {
+        self.disjoint_subgraph_vertices()
+            .map(|vertex| {
+                let vertices = vertex
+                    .traverse_by_depth()
+                    .map(|vertex| vertex.key())
+                    .collect::<Vec<_>>();
+                let mut faces = HashSet::new();
+                for key in vertices.iter() {
+                    let vertex = self.vertex(*key).expect_consistent();
+                    faces.extend(vertex.adjacent_faces().map(|face| face.key()));
+                }
+                let mut faces = faces.into_iter().collect::<Vec<_>>();
+                faces.sort_by_key(|key| key.into_inner());
+                self.copy_subgraph(vertices, &faces)
+            })
+            .collect()
+    }
+
+    /// Returns `true` if the graph is a manifold.
+    ///
+    /// A graph is a manifold if the link of each vertex is a single simple
+    /// path (for boundary vertices) or cycle (for interior vertices). Faces
+    /// that are joined only at a vertex, such as a "bowtie" of two triangles,
+    /// are not manifold. Every edge in a graph has at most two incident faces,
+    /// so only vertices are examined. Vertices with no adjacent faces are not
+    /// manifold.
+    ///
+    /// See [`VertexView::link`].
+    ///
+    /// [`VertexView::link`]: crate::graph::VertexView::link
+    pub fn is_manifold(&self) -> bool {
+        self.vertices().all(|vertex| {
+            let mut adjacency = HashMap::<_, SmallVec<[_; 2]>>::new();
+            let mut n = 0;
+            for edge in vertex.link() {
+                let arc = edge.arc();
+                let (a, b) = (arc.source_vertex().key(), arc.destination_vertex().key());
+                adjacency.entry(a).or_default().push(b);
+                adjacency.entry(b).or_default().push(a);
+                n += 1;
+            }
+            // A path has one more vertex than edges and a cycle has as many
+            // vertices as edges.
+            let m = adjacency.len();
+            if adjacency.values().any(|keys| keys.len() > 2) || (m != n && m != n + 1) {
+                return false;
+            }
+            // A link with no edges is neither a path nor a cycle.
+            let start = match adjacency.keys().next() {
+                Some(key) => *key,
+                _ => return false,
+            };
+            // A connected link with no branches is a single path or cycle.
+            let mut visited = HashSet::with_capacity(m);
+            let mut stack = vec![start];
+            while let Some(key) = stack.pop() {
+                if visited.insert(key) {
+                    stack.extend(adjacency[&key].iter().cloned());
+                }
+            }
+            visited.len() == m
+        })
+    }
+
+    /// Verifies the consistency of the graph.
+    ///
+    /// A graph is consistent if its keys refer to entities in the graph and
+    /// the connectivity of those entities is coherent: each arc has an
+    /// opposite arc with which it shares an edge, the next and previous arcs
+    /// of each arc refer back to that arc, and the arcs of each face form a
+    /// closed ring. Graphs are always consistent unless an operation is
+    /// defective, so this is mostly useful for testing and debugging.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`GraphError::TopologyNotFound`] if a key refers to an entity
+    /// that is not in the graph or [`GraphError::TopologyMalformed`] if the
+    /// connectivity of entities is not coherent.
+    ///
+    /// [`GraphError::TopologyMalformed`]: crate::graph::GraphError::TopologyMalformed
+    /// [`GraphError::TopologyNotFound`]: crate::graph::GraphError::TopologyNotFound
+    pub fn validate(&self) -> Result<(), GraphError> {
+        let vertices = &self.core.vertices;
+        let arcs = &self.core.arcs;
+        let edges = &self.core.edges;
+        let faces = &self.core.faces;
+        let arc = |key: ArcKey| arcs.get(&key).ok_or(GraphError::TopologyNotFound);
+        let is = |condition: bool| {
+            if condition {
+                Ok(())
+            }
+            else {
+                Err(GraphError::TopologyMalformed)
+            }
+        };
+        for (key, vertex) in vertices.iter() {
+            if let Some(outgoing) = vertex.arc {
+                arc(outgoing)?;
+                let (source, _): (VertexKey, VertexKey) = outgoing.into();
+                is(source == key)?;
+            }
+        }
+        for (key, entity) in arcs.iter() {
+            let (source, destination): (VertexKey, VertexKey) = key.into();
+            if !(vertices.contains_key(&source) && vertices.contains_key(&destination)) {
+                return Err(GraphError::TopologyNotFound);
+            }
+            let opposite = arc(key.into_opposite())?;
+            let next = entity.next.ok_or(GraphError::TopologyMalformed)?;
+            let previous = entity.previous.ok_or(GraphError::TopologyMalformed)?;
+            let (next_source, _): (VertexKey, VertexKey) = next.into();
+            let (_, previous_destination): (VertexKey, VertexKey) = previous.into();
+            is(next_source == destination && previous_destination == source)?;
+            let (next, previous) = (arc(next)?, arc(previous)?);
+            is(next.previous == Some(key) && previous.next == Some(key))?;
+            is(next.face == entity.face)?;
+            let edge = entity.edge.ok_or(GraphError::TopologyMalformed)?;
+            let leading = edges.get(&edge).ok_or(GraphError::TopologyNotFound)?.arc;
+            is(opposite.edge == Some(edge))?;
+            is(leading == key || leading == key.into_opposite())?;
+            if let Some(face) = entity.face {
+                if !faces.contains_key(&face) {
+                    return Err(GraphError::TopologyNotFound);
+                }
+            }
+        }
+        for (key, edge) in edges.iter() {
+            is(arc(edge.arc)?.edge == Some(key))?;
+        }
+        for (key, face) in faces.iter() {
+            // Walk the ring of the face. It must close within as many steps as
+            // there are arcs in the graph.
+            let mut next = face.arc;
+            let mut is_closed = false;
+            for _ in 0..arcs.len() {
+                let entity = arc(next)?;
+                is(entity.face == Some(key))?;
+                next = entity.next.ok_or(GraphError::TopologyMalformed)?;
+                if next == face.arc {
+                    is_closed = true;
+                    break;
+                }
+            }
+            is(is_closed)?;
+        }
+        Ok(())
+    }
+
+    /// Shrinks the capacity of the graph's underlying storage as much as
+    /// possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.core.vertices.shrink_to_fit();
+        self.core.arcs.shrink_to_fit();
+        self.core.edges.shrink_to_fit();
+        self.core.faces.shrink_to_fit();
+    }
+
+    /// Creates flat vertex and index buffers of triangles from the graph.
+    ///
+    /// The vertex buffer contains the data of each unique vertex in the graph
+    /// and the index buffer contains three indices into the vertex buffer for
+    /// each triangle. Faces are decomposed into triangles using the given
+    /// [`Triangulation`]; the graph itself is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, Triangulation};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let (vertices, indices) = graph.to_flat_buffers(Triangulation::ShortestDiagonal);
+    ///
+    /// assert_eq!(8, vertices.len());
+    /// assert_eq!(36, indices.len());
+    /// ```
+    ///
+    /// [`Triangulation`]: crate::graph::Triangulation
+    pub fn to_flat_buffers(&self, triangulation: Triangulation) -> (Vec<G::Vertex>, Vec<usize>)
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace,
+    {
+        let mut keys = HashMap::with_capacity(self.vertex_count());
+        let mut vertices = Vec::with_capacity(self.vertex_count());
+        for vertex in self.vertices() {
+            keys.insert(vertex.key(), vertices.len());
+            vertices.push(vertex.get().clone());
+        }
+        let mut indices = Vec::with_capacity(self.face_count() * 3);
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| (keys[&vertex.key()], *vertex.position()))
+                .collect::<SmallVec<[_; 8]>>();
+            // Fan from the vertex that begins the shortest diagonal of
+            // quadrilaterals. Otherwise, fan from the leading vertex.
+            let offset = match (triangulation, perimeter.as_slice()) {
+                (Triangulation::ShortestDiagonal, [(_, a), (_, b), (_, c), (_, d)])
+                    if (*b - *d).magnitude() < (*a - *c).magnitude() =>
+                {
+                    1
+                }
+                _ => 0,
+            };
+            indices.extend(
+                fan(&perimeter, offset).flat_map(|triangle| triangle.map(|(index, _)| index)),
+            );
+        }
+        (vertices, indices)
+    }
+
+    /// Gets the vertex indices of the triangles of the graph.
+    ///
+    /// Indices refer to the order in which vertices are visited by
+    /// [`MeshGraph::vertices`], which is the same as the vertex buffer of
+    /// [`MeshGraph::to_flat_buffers`]. Faces are decomposed into triangles by
+    /// [`Triangulation::Fan`]; the graph itself is not modified.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let triangles = graph.triangle_indices();
+    ///
+    /// assert_eq!(12, triangles.len());
+    /// ```
+    ///
+    /// [`Triangulation::Fan`]: crate::graph::Triangulation::Fan
+    pub fn triangle_indices(&self) -> Vec<[usize; 3]> {
+        self.triangulate_with_parent_map().0
+    }
+
+    /// Gets the vertex indices of the triangles of the graph and the faces
+    /// from which they are formed.
+    ///
+    /// The triangles are the same as those of [`MeshGraph::triangle_indices`].
+    /// The second vector is parallel to the first and names the face that
+    /// each triangle decomposes, which can be used to associate per-face
+    /// data (such as materials) with triangles.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let (triangles, faces) = graph.triangulate_with_parent_map();
+    ///
+    /// assert_eq!(triangles.len(), faces.len());
+    /// ```
+    pub fn triangulate_with_parent_map(&self) -> (Vec<[usize; 3]>, Vec<FaceKey>) {
+        let keys = self
+            .vertices()
+            .enumerate()
+            .map(|(index, vertex)| (vertex.key(), index))
+            .collect::<HashMap<_, _>>();
+        let mut triangles = Vec::with_capacity(self.face_count());
+        let mut parents = Vec::with_capacity(self.face_count());
+        for face in self.faces() {
+            let perimeter = face
+                .adjacent_vertices()
+                .map(|vertex| keys[&vertex.key()])
+                .collect::<SmallVec<[_; 8]>>();
+            for triangle in fan(&perimeter, 0) {
+                triangles.push(triangle);
+                parents.push(face.key());
+            }
+        }
+        (triangles, parents)
+    }
+
+    /// Creates an interleaved `f32` vertex buffer and a `u32` index buffer
+    /// from the graph.
+    ///
+    /// For each vertex, the components of each attribute in the given layout
+    /// are written in order. For example, the layout
+    /// `[VertexBufferAttribute::Position, VertexBufferAttribute::Normal]`
+    /// writes six values per vertex. Vertices are written in the order of
+    /// [`MeshGraph::vertices`] and the index buffer is the same as
+    /// [`MeshGraph::triangle_indices`]. Normals that cannot be computed are
+    /// written as zero.
+    ///
+    /// These buffers can be used directly with graphics APIs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more vertices than can be indexed by a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::{MeshGraph, VertexBufferAttribute};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let layout = [VertexBufferAttribute::Position, VertexBufferAttribute::Normal];
+    /// let (vertices, indices) = graph.to_interleaved_f32(&layout);
+    ///
+    /// assert_eq!(8 * 6, vertices.len());
+    /// assert_eq!(36, indices.len());
+    /// ```
+    pub fn to_interleaved_f32(&self, layout: &[VertexBufferAttribute]) -> (Vec<f32>, Vec<u32>)
+    where
+        G: VertexNormal,
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let into_f32 = |position: VertexPosition<G>| {
+            let (x, y, z) = position.into_xyz();
+            [x, y, z].map(|x| x.to_f32().unwrap_or(0.0))
+        };
+        let mut vertices = Vec::with_capacity(self.vertex_count() * layout.len() * 3);
+        for vertex in self.vertices() {
+            for attribute in layout {
+                vertices.extend(match attribute {
+                    VertexBufferAttribute::Position => into_f32(*vertex.position()),
+                    VertexBufferAttribute::Normal => vertex
+                        .normal()
+                        .map(|normal| into_f32(VertexPosition::<G>::origin() + normal))
+                        .unwrap_or([0.0; 3]),
+                });
+            }
+        }
+        let indices = self
+            .triangle_indices()
+            .into_iter()
+            .flatten()
+            .map(|index| u32::try_from(index).expect("vertex index exceeds `u32`"))
+            .collect();
+        (vertices, indices)
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each unique vertex in the graph. No face data
+    /// is used, and the `Facet` type is always the unit type `()`.
+    ///
+    /// # Examples
+    ///
+    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to modify a cube:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::N64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBufferN;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<N64>;
+    ///
+    /// let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let key = graph.faces().nth(0).unwrap().key();
+    /// graph
+    ///     .face_mut(key)
+    ///     .unwrap()
+    ///     .extrude_with_offset(1.0)
+    ///     .unwrap();
+    ///
+    /// let buffer: MeshBufferN<usize, E3> = graph.to_mesh_by_vertex().unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the graph does not have constant arity that is
+    /// compatible with the index buffer. Typically, a graph is triangulated
+    /// before being converted to a buffer.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    pub fn to_mesh_by_vertex<B>(&self) -> Result<B, B::Error>
+    where
+        B: Buildable<Facet = ()>,
+        B::Vertex: FromGeometry<G::Vertex>,
+    {
+        self.to_mesh_by_vertex_with(|vertex| vertex.get().clone().into_geometry())
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each unique vertex in the graph, which is
+    /// converted by the given function. No face data is used, and the `Facet`
+    /// type is always the unit type `()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex data cannot be inserted into the output,
+    /// there are arity conflicts, or the output does not support topology found
+    /// in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    pub fn to_mesh_by_vertex_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    where
+        B: Buildable<Facet = ()>,
+        F: FnMut(VertexView<&Self>) -> B::Vertex,
+    {
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            let mut keys = HashMap::with_capacity(self.vertex_count());
+            for vertex in self.vertices() {
+                keys.insert(vertex.key(), builder.insert_vertex(f(vertex))?);
+            }
+            builder.facets_with(|builder| {
+                for face in self.faces() {
+                    let indices = face
+                        .adjacent_vertices()
+                        .map(|vertex| keys[&vertex.key()])
+                        .collect::<SmallVec<[_; 8]>>();
+                    builder.insert_facet(indices.as_slice(), ())?;
+                }
+                Ok(())
+            })
+        })?;
+        builder.build()
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each face in the graph. For each face, the
+    /// face data and data for each of its vertices is inserted into the mesh
+    /// via [`FromGeometry`]. This means that a vertex is inserted for each of
+    /// its adjacent faces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex data cannot be inserted into the output,
+    /// there are arity conflicts, or the output does not support topology found
+    /// in the graph.
+    ///
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    pub fn to_mesh_by_face<B>(&self) -> Result<B, B::Error>
+    where
+        B: Buildable,
+        B::Vertex: FromGeometry<G::Vertex>,
+        B::Facet: FromGeometry<G::Face>,
+    {
+        self.to_mesh_by_face_with(|_, vertex| vertex.get().clone().into_geometry())
+    }
+
+    /// Creates a [`Buildable`] mesh data structure from the graph.
+    ///
+    /// The output is created from each face in the graph. For each face, the
+    /// face data and data for each of its vertices is converted into the output
+    /// vertex data by the given function. This means that a vertex is inserted
+    /// for each of its adjacent faces. The data of each face is is inserted
+    /// into the output via [`FromGeometry`].
+    ///
+    /// # Examples
+    ///
+    /// Creating a [`MeshBuffer`] from a [`MeshGraph`] used to compute normals:
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::geometry::Vector;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::BoundedPolygon;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// pub struct Vertex {
+    ///     pub position: E3,
+    ///     pub normal: Vector<E3>,
+    /// }
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    ///
+    /// let buffer: MeshBuffer<BoundedPolygon<usize>, _> = graph
+    ///     .to_mesh_by_face_with(|face, vertex| Vertex {
+    ///         position: *vertex.position(),
+    ///         normal: face.normal().unwrap(),
+    ///     })
+    ///     .unwrap();
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vertex data cannot be inserted into the output,
+    /// there are arity conflicts, or the output does not support topology found
+    /// in the graph.
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`Buildable`]: crate::builder::Buildable
+    /// [`FromGeometry`]: crate::geometry::FromGeometry
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    pub fn to_mesh_by_face_with<B, F>(&self, mut f: F) -> Result<B, B::Error>
+    where
+        B: Buildable,
+        B::Facet: FromGeometry<G::Face>,
+        F: FnMut(FaceView<&Self>, VertexView<&Self>) -> B::Vertex,
+    {
+        let mut builder = B::builder();
+        builder.surface_with(|builder| {
+            for face in self.faces() {
+                let indices = face
+                    .adjacent_vertices()
+                    .map(|vertex| builder.insert_vertex(f(face, vertex)))
+                    .collect::<Result<SmallVec<[_; 8]>, _>>()?;
+                builder.facets_with(|builder| {
+                    builder.insert_facet(indices.as_slice(), face.get().clone())
+                })?;
+            }
+            Ok(())
+        })?;
+        builder.build()
+    }
+}
+
+impl<G> AsStorage<Vertex<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &StorageTarget<Vertex<G>> {
+        self.core.as_storage_of::<Vertex<_>>()
+    }
+}
+
+impl<G> AsStorage<Arc<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &StorageTarget<Arc<G>> {
+        self.core.as_storage_of::<Arc<_>>()
+    }
+}
+
+impl<G> AsStorage<Edge<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &StorageTarget<Edge<G>> {
+        self.core.as_storage_of::<Edge<_>>()
+    }
+}
+
+impl<G> AsStorage<Face<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage(&self) -> &StorageTarget<Face<G>> {
+        self.core.as_storage_of::<Face<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Vertex<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Vertex<G>> {
+        self.core.as_storage_mut_of::<Vertex<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Arc<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Arc<G>> {
+        self.core.as_storage_mut_of::<Arc<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Edge<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Edge<G>> {
+        self.core.as_storage_mut_of::<Edge<_>>()
+    }
+}
+
+impl<G> AsStorageMut<Face<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn as_storage_mut(&mut self) -> &mut StorageTarget<Face<G>> {
+        self.core.as_storage_mut_of::<Face<_>>()
+    }
+}
+
+/// Exposes a [`MeshBuilder`] that can be used to construct a [`MeshGraph`]
+/// incrementally from _surfaces_ and _facets_.
+///
+/// See the [`builder`] module documentation for more.
+///
+/// # Examples
+///
+/// Creating a [`MeshGraph`] from a triangle:
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point2;
+/// use plexus::builder::Buildable;
+/// use plexus::graph::MeshGraph;
+/// use plexus::prelude::*;
+///
+/// let mut builder = MeshGraph::<Point2<f64>>::builder();
+/// let graph = builder
+///     .surface_with(|builder| {
+///         let a = builder.insert_vertex((0.0, 0.0))?;
+///         let b = builder.insert_vertex((1.0, 0.0))?;
+///         let c = builder.insert_vertex((0.0, 1.0))?;
+///         builder.facets_with(|builder| builder.insert_facet(&[a, b, c], ()))
+///     })
+///     .and_then(|_| builder.build())
+///     .unwrap();
+/// ```
+///
+/// [`MeshBuilder`]: crate::builder::MeshBuilder
+/// [`builder`]: crate::builder
+/// [`MeshGraph`]: crate::graph::MeshGraph
+impl<G> Buildable for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Builder = GraphBuilder<G>;
+    type Error = GraphError;
+
+    type Vertex = G::Vertex;
+    type Facet = G::Face;
+
+    fn builder() -> Self::Builder {
+        Default::default()
+    }
+}
+
+impl<G> Consistent for MeshGraph<G> where G: GraphData {}
+
+impl<G> Default for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn default() -> Self {
+        MeshGraph::new()
+    }
+}
+
+// Graphs are serialized as raw buffers of vertex data and faces. Faces are
+// described by indices into the vertex buffer and their data. Arc and edge
+// data is not serialized.
+#[cfg(feature = "serde")]
+#[derive(Deserialize, Serialize)]
+#[serde(rename = "MeshGraph")]
+struct RawMeshGraph<V, F> {
+    vertices: Vec<V>,
+    faces: Vec<(Vec<usize>, F)>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, G> Deserialize<'de> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: Deserialize<'de>,
+    G::Face: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let RawMeshGraph { vertices, faces } =
+            RawMeshGraph::<G::Vertex, G::Face>::deserialize(deserializer)?;
+        // Connectivity is rebuilt and validated in the same way as
+        // `FromRawBuffers`, so malformed data is reported as an error.
+        let build = move || {
+            let mut mutation = Mutation::from(MeshGraph::new());
+            let vertices = vertices
+                .into_iter()
+                .map(|data| mutation::vertex::insert(&mut mutation, data))
+                .collect::<Vec<_>>();
+            for (indices, data) in faces {
+                let perimeter = indices
+                    .into_iter()
+                    .map(|index| vertices.get(index).copied())
+                    .collect::<Option<SmallVec<[_; 4]>>>()
+                    .ok_or(GraphError::TopologyNotFound)?;
+                let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+                mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+            }
+            mutation.commit().map_err(|(_, error)| error)
+        };
+        build().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<G> Serialize for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: Serialize,
+    G::Face: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut indices = HashMap::with_capacity(self.vertex_count());
+        let mut vertices = Vec::with_capacity(self.vertex_count());
+        for (key, vertex) in self.core.vertices.iter() {
+            indices.insert(key, vertices.len());
+            vertices.push(&vertex.data);
+        }
+        let faces = self
+            .core
+            .faces
+            .iter()
+            .map(|(key, face)| {
+                let perimeter = self
+                    .face(key)
+                    .expect_consistent()
+                    .adjacent_vertices()
+                    .map(|vertex| indices[&vertex.key()])
+                    .collect::<Vec<_>>();
+                (perimeter, &face.data)
+            })
+            .collect();
+        RawMeshGraph { vertices, faces }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "encoding-obj")]
+impl<G> MeshGraph<G>
+where
+    G: GraphData,
+{
+    /// Writes the graph to an [OBJ] encoding.
+    ///
+    /// This is equivalent to [`obj::to_obj`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let mut obj = Vec::new();
+    /// graph.to_obj(&mut obj).unwrap();
+    /// ```
+    ///
+    /// [OBJ]: https://en.wikipedia.org/wiki/Wavefront_.obj_file
+    ///
+    /// [`obj::to_obj`]: crate::encoding::obj::to_obj
+    pub fn to_obj<W>(&self, writer: W) -> io::Result<()>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        W: Write,
+    {
+        obj::to_obj(self, writer)
+    }
+}
+
+#[cfg(feature = "encoding-stl")]
+impl<G> MeshGraph<G>
+where
+    G: FaceNormal,
+{
+    /// Writes the graph to an [STL] encoding of the given format.
+    ///
+    /// This is equivalent to [`stl::to_stl`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails or if there are more triangles than
+    /// can be represented by a binary encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::encoding::stl::StlFormat;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let mut stl = Vec::new();
+    /// graph.to_stl(&mut stl, StlFormat::Binary).unwrap();
+    /// ```
+    ///
+    /// [STL]: https://en.wikipedia.org/wiki/STL_(file_format)
+    ///
+    /// [`stl::to_stl`]: crate::encoding::stl::to_stl
+    pub fn to_stl<W>(&self, writer: W, format: StlFormat) -> io::Result<()>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        W: Write,
+    {
+        stl::to_stl(self, writer, format)
+    }
+}
+
+#[cfg(feature = "encoding-ply")]
+impl<G> MeshGraph<G>
+where
+    G: GraphData,
+{
+    /// Writes the graph to a [PLY] encoding of the given format.
+    ///
+    /// This is equivalent to [`ply::to_ply`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails, if the arity of a face exceeds 255,
+    /// or if there are more vertices than can be indexed by the encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::encoding::ply::PlyFormat;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// type E3 = Point3<R64>;
+    ///
+    /// let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+    /// let mut ply = Vec::new();
+    /// graph.to_ply(&mut ply, PlyFormat::Ascii).unwrap();
+    /// ```
+    ///
+    /// [PLY]: https://en.wikipedia.org/wiki/PLY_(file_format)
+    ///
+    /// [`ply::to_ply`]: crate::encoding::ply::to_ply
+    pub fn to_ply<W>(&self, writer: W, format: PlyFormat) -> io::Result<()>
+    where
+        G::Vertex: AsPosition,
+        VertexPosition<G>: EuclideanSpace + FiniteDimensional<N = U3>,
+        W: Write,
+    {
+        ply::to_ply(self, writer, format)
+    }
+}
+
+impl<G> DynamicArity for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Dynamic = MeshArity;
+
+    fn arity(&self) -> Self::Dynamic {
+        MeshArity::from_components::<FaceView<_>, _>(self.faces())
+    }
+}
+
+impl<P, G> From<P> for MeshGraph<G>
+where
+    P: Polygonal,
+    G: GraphData,
+    G::Vertex: FromGeometry<P::Vertex>,
+{
+    fn from(polygon: P) -> Self {
+        let arity = polygon.arity();
+        MeshGraph::from_raw_buffers_with_arity(0..arity, polygon, arity)
+            .expect("inconsistent polygon")
+    }
+}
+
+impl<G> From<OwnedCore<G>> for MeshGraph<G>
+where
+    G: GraphData,
+{
+    fn from(core: OwnedCore<G>) -> Self {
+        MeshGraph { core }
+    }
+}
+
+impl<G> From<MeshGraph<G>> for OwnedCore<G>
+where
+    G: GraphData,
+{
+    fn from(graph: MeshGraph<G>) -> Self {
+        let MeshGraph { core, .. } = graph;
+        core
+    }
+}
+
+impl<E, G> FromEncoding<E> for MeshGraph<G>
+where
+    E: FaceDecoder + VertexDecoder,
+    G: GraphData,
+    G::Face: FromGeometry<E::Face>,
+    G::Vertex: FromGeometry<E::Vertex>,
+{
+    type Error = GraphError;
+
+    fn from_encoding(
+        vertices: <E as VertexDecoder>::Output,
+        faces: <E as FaceDecoder>::Output,
+    ) -> Result<Self, Self::Error> {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let keys = vertices
+            .into_iter()
+            .map(|data| mutation::vertex::insert(&mut mutation, data.into_geometry()))
+            .collect::<Vec<_>>();
+        for (perimeter, data) in faces {
+            let perimeter = perimeter
+                .into_iter()
+                .map(|index| keys[index])
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, perimeter.as_slice())?;
+            let data = data.into_geometry();
+            mutation::face::insert_with(&mut mutation, cache, || (Default::default(), data))?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+}
+
+impl<G, P> FromIndexer<P, P> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: FromGeometry<P::Vertex>,
+    P: Map<usize> + Polygonal,
+    P::Output: Grouping<Group = P::Output> + IntoVertices + Polygonal<Vertex = usize>,
+    Vec<P::Output>: IndexBuffer<P::Output, Index = usize>,
+{
+    type Error = GraphError;
+
+    // TODO: This appears to be a false positive. The `collect` is necessary,
+    //       because the data is transformed and read randomly by index. See
+    //       https://github.com/rust-lang/rust-clippy/issues/5991
+    #[allow(clippy::needless_collect)]
+    fn from_indexer<I, N>(input: I, indexer: N) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = P>,
+        N: Indexer<P, P::Vertex>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let (indices, vertices) = input.into_iter().index_vertices(indexer);
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        for face in indices {
+            let perimeter = face
+                .into_vertices()
+                .into_iter()
+                .map(|index| vertices[index])
+                .collect::<SmallVec<[_; 4]>>();
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+}
+
+impl<G, P> FromIterator<P> for MeshGraph<G>
+where
+    G: GraphData,
+    G::Vertex: FromGeometry<P::Vertex>,
+    P: Polygonal,
+    P::Vertex: Clone + Eq + Hash,
+    Self: FromIndexer<P, P>,
+{
+    fn from_iter<I>(input: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+    {
+        Self::from_indexer(input, HashIndexer::default()).unwrap_or_else(|_| Self::default())
+    }
+}
+
+impl<P, G, H> FromRawBuffers<P, H> for MeshGraph<G>
+where
+    P: IntoVertices + Polygonal,
+    P::Vertex: Integer + ToPrimitive + Unsigned,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    fn from_raw_buffers<I, J>(indices: I, vertices: J) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = P>,
+        J: IntoIterator<Item = H>,
+    {
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        for face in indices {
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(face.arity());
+            for index in face.into_vertices() {
+                let index = <usize as NumCast>::from(index).unwrap();
+                perimeter.push(*vertices.get(index).ok_or(GraphError::TopologyNotFound)?);
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+}
+
+impl<N, G, H> FromRawBuffersWithArity<N, H> for MeshGraph<G>
+where
+    N: Integer + ToPrimitive + Unsigned,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from [raw buffers][`buffer`]. The arity of the
+    /// polygons in the index buffer must be given and constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the arity of the index buffer is not constant, any
+    /// index is out of bounds, or there is an error inserting topology into the
+    /// graph.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::{Flat3, LruIndexer};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// type E3 = Point3<f64>;
+    ///
+    /// let (indices, positions) = UvSphere::new(16, 16)
+    ///     .polygons::<Position<E3>>()
+    ///     .triangulate()
+    ///     .index_vertices::<Flat3, _>(LruIndexer::with_capacity(256));
+    /// let mut graph = MeshGraph::<E3>::from_raw_buffers_with_arity(indices, positions, 3).unwrap();
+    /// ```
+    ///
+    /// [`buffer`]: crate::buffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn from_raw_buffers_with_arity<I, J>(
+        indices: I,
+        vertices: J,
+        arity: usize,
+    ) -> Result<Self, Self::Error>
+    where
+        I: IntoIterator<Item = N>,
+        J: IntoIterator<Item = H>,
+    {
+        use itertools::Itertools;
+
+        if arity < 3 {
+            return Err(GraphError::ArityNonPolygonal);
+        }
+        let mut mutation = Mutation::from(MeshGraph::new());
+        let vertices = vertices
+            .into_iter()
+            .map(|vertex| mutation::vertex::insert(&mut mutation, vertex.into_geometry()))
+            .collect::<Vec<_>>();
+        for face in &indices
+            .into_iter()
+            .map(|index| <usize as NumCast>::from(index).unwrap())
+            .chunks(arity)
+        {
+            let face = face.collect::<Vec<_>>();
+            if face.len() != arity {
+                // Index buffer length is not a multiple of arity.
+                return Err(GraphError::ArityConflict {
+                    expected: arity,
+                    actual: face.len(),
+                });
+            }
+            let mut perimeter = SmallVec::<[_; 4]>::with_capacity(arity);
+            for index in face {
+                perimeter.push(*vertices.get(index).ok_or(GraphError::TopologyNotFound)?);
+            }
+            let cache = FaceInsertCache::from_storage(&mutation, &perimeter)?;
+            mutation::face::insert_with(&mut mutation, cache, Default::default)?;
+        }
+        mutation.commit().map_err(|(_, error)| error)
+    }
+}
+
+impl<G> IntoPolygons for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Output = vec::IntoIter<Self::Polygon>;
+    type Polygon = UnboundedPolygon<G::Vertex>;
+
+    fn into_polygons(self) -> Self::Output {
+        use crate::IteratorExt as _;
+
+        self.faces()
+            .map(|face| {
+                // The arity of a face in a graph must be polygonal (three or
+                // higher) so this should never fail.
+                face.adjacent_vertices()
+                    .map(|vertex| vertex.get().clone())
+                    .try_collect()
+                    .expect_consistent()
+            })
+            .collect::<Vec<_>>()
+            .into_iter()
+    }
+}
+
+impl<G> Parametric for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Data = G;
+}
+
+impl<G> StaticArity for MeshGraph<G>
+where
+    G: GraphData,
+{
+    type Static = (usize, Option<usize>);
+
+    const ARITY: Self::Static = (3, None);
+}
+
+impl<T, H, G, const A: usize> TryFrom<MeshBuffer<Flat<T, A>, H>> for MeshGraph<G>
+where
+    Constant<A>: ToType,
+    TypeOf<A>: NonZero,
+    T: Copy + Integer + NumCast + Unsigned,
+    H: Clone,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from a flat [`MeshBuffer`]. The arity of the
+    /// polygons in the index buffer must be known and constant.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
+    /// [`MeshBuffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::Flat4;
+    /// use plexus::prelude::*;
+    /// use std::convert::TryFrom;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let buffer = MeshBuffer::<Flat4, E2>::from_raw_buffers(
+    ///     vec![0u64, 1, 2, 3],
+    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
+    /// ```
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn try_from(buffer: MeshBuffer<Flat<T, A>, H>) -> Result<Self, Self::Error> {
+        let arity = buffer.arity();
+        let (indices, vertices) = buffer.into_raw_buffers();
+        MeshGraph::from_raw_buffers_with_arity(indices, vertices, arity)
+    }
+}
+
+impl<P, H, G> TryFrom<MeshBuffer<P, H>> for MeshGraph<G>
+where
+    P: Grouping<Group = P> + IntoVertices + Polygonal,
+    P::Vertex: Copy + Integer + NumCast + Unsigned,
+    H: Clone,
+    G: GraphData,
+    G::Vertex: FromGeometry<H>,
+{
+    type Error = GraphError;
+
+    /// Creates a [`MeshGraph`] from a structured [`MeshBuffer`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a [`MeshGraph`] cannot represent the topology in the
+    /// [`MeshBuffer`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point2;
+    /// use plexus::buffer::MeshBuffer;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::Tetragon;
+    /// use std::convert::TryFrom;
+    ///
+    /// type E2 = Point2<f64>;
+    ///
+    /// let buffer = MeshBuffer::<Tetragon<u64>, E2>::from_raw_buffers(
+    ///     vec![Tetragon::new(0u64, 1, 2, 3)],
+    ///     vec![(0.0f64, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+    /// )
+    /// .unwrap();
+    /// let mut graph = MeshGraph::<E2>::try_from(buffer).unwrap();
+    /// ```
+    ///
+    /// [`MeshBuffer`]: crate::buffer::MeshBuffer
+    /// [`MeshGraph`]: crate::graph::MeshGraph
+    fn try_from(buffer: MeshBuffer<P, H>) -> Result<Self, Self::Error> {
+        let (indices, vertices) = buffer.into_raw_buffers();
+        MeshGraph::from_raw_buffers(indices, vertices)
+    }
+}
+
+// Decomposes a perimeter into triangles fanned from the element at the given
+// offset. Perimeters with fewer than three elements yield no triangles.
+pub(crate) fn fan<T>(perimeter: &[T], offset: usize) -> impl '_ + Iterator<Item = [T; 3]>
+where
+    T: Copy,
+{
+    let n = perimeter.len();
+    (1..n.saturating_sub(1)).map(move |i| [0, i, i + 1].map(|j| perimeter[(j + offset) % n]))
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::abs_diff_eq;
+    use decorum::R64;
+    use nalgebra::{Isometry3, Matrix4, Point2, Point3, Vector3};
+    use num::Zero;
+    use std::collections::HashSet;
+
+    use crate::buffer::{MeshBuffer3, MeshBuffer4};
+    use crate::entity::storage::Remove;
+    use crate::geometry::{AsPosition, FromGeometry, Plane, Transform, Unit};
+    use crate::graph::{
+        EdgeKey, EdgeView, FacePoints, FaceView, GraphData, GraphError, MeshGraph, SmoothBoundary,
+        SubdivisionScheme, Triangulation, VertexAttribute, VertexBufferAttribute, VertexView,
+    };
+    use crate::index::HashIndexer;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::{NGon, Tetragon, Trigon, UnboundedPolygon};
+
+    type E2 = Point2<R64>;
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn collect() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(3, 2)
+            .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+            .collect();
+
+        assert_eq!(5, graph.vertex_count());
+        assert_eq!(18, graph.arc_count());
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn iterate() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(4, 2)
+            .polygons::<Position<E3>>() // 8 triangles, 24 vertices.
+            .collect();
+
+        assert_eq!(6, graph.vertices().count());
+        assert_eq!(24, graph.arcs().count());
+        assert_eq!(8, graph.faces().count());
+        for vertex in graph.vertices() {
+            // Every vertex is connected to 4 triangles with 4 (incoming) arcs.
+            // Traversal of topology should be possible.
+            assert_eq!(4, vertex.incoming_arcs().count());
+        }
+        for mut vertex in graph.vertex_orphans() {
+            // Data should be mutable.
+            *vertex.get_mut() += Vector3::zero();
+        }
+    }
+
+    #[test]
+    fn translate_with_orphans() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(8, graph.vertex_orphans().len());
+        assert_eq!(24, graph.arc_orphans().len());
+        assert_eq!(12, graph.edge_orphans().len());
+        assert_eq!(6, graph.face_orphans().len());
+
+        let offset = Vector3::new(1.0, 2.0, 3.0);
+        for mut vertex in graph.vertex_orphans() {
+            *vertex.get_mut() += offset;
+        }
+        assert_eq!(Point3::new(1.0, 2.0, 3.0), graph.centroid().unwrap());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn isolate_disjoint_subgraphs() {
+        // Construct a graph from a quadrilateral.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2, 3])],
+            vec![(1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0)],
+        )
+        .unwrap();
+
+        assert_eq!(1, graph.disjoint_subgraph_vertices().count());
+
+        // Construct a graph with two disjoint quadrilaterals.
+        let graph = MeshGraph::<E2>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2, 3]), NGon([4, 5, 6, 7])],
+            vec![
+                (-2.0, 0.0),
+                (-1.0, 0.0),
+                (-1.0, 1.0),
+                (-2.0, 1.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (2.0, 1.0),
+                (1.0, 1.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+    }
+
+    #[test]
+    fn compute_tangents() {
+        // Construct a plane from two quadrilaterals with texture coordinates
+        // that span the plane along the X- and Y-axes.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 4, 5]), NGon([1, 2, 3, 4])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (2.0, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let uvs = graph
+            .vertices()
+            .map(|vertex| {
+                let position = vertex.position();
+                (vertex.key(), [(position.x / 2.0) as f32, position.y as f32])
+            })
+            .collect::<VertexAttribute<_>>();
+        let tangents = graph.compute_tangents(&uvs).unwrap();
+
+        assert_eq!(graph.vertex_count(), tangents.len());
+        for [x, y, z, w] in tangents.values().cloned() {
+            // Tangents should be unit vectors along the U-axis (X-axis).
+            assert!(abs_diff_eq!(1.0, (x * x + y * y + z * z).sqrt(), epsilon = 1e-6));
+            assert!(abs_diff_eq!(1.0, x, epsilon = 1e-6));
+            assert_eq!(1.0, w);
+        }
+    }
+
+    #[test]
+    fn subdivide_catmull_clark() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.subdivide_catmull_clark(1).unwrap();
+
+        assert_eq!(26, graph.vertex_count());
+        assert_eq!(48, graph.edge_count());
+        assert_eq!(24, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+        // The corners of the cube are pulled toward its center.
+        assert!(graph
+            .vertices()
+            .filter(|vertex| vertex.valence() == 3)
+            .all(|vertex| vertex.position().iter().all(|x| x.abs() < 0.5)));
+
+        graph.subdivide_catmull_clark(2).unwrap();
+
+        assert_eq!(384, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn subdivide_catmull_clark_with_boundary() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)],
+        )
+        .unwrap();
+        graph.subdivide_catmull_clark(1).unwrap();
+
+        assert_eq!(9, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+        // Corners joining two boundary edges are moved a quarter of the way
+        // toward the mean of their adjacent boundary vertices. For the corners
+        // of a square, this mean is the center of the square, so the corners
+        // move strictly inside of it, such as from $(0,0)$ to $(0.25,0.25)$.
+        let corners = graph
+            .vertices()
+            .filter(|vertex| vertex.valence() == 2)
+            .map(|vertex| *vertex.position())
+            .collect::<Vec<_>>();
+        assert_eq!(4, corners.len());
+        assert!(corners.iter().all(|position| {
+            abs_diff_eq!(0.25, position.x.min(2.0 - position.x), epsilon = 1e-9)
+                && abs_diff_eq!(0.25, position.y.min(2.0 - position.y), epsilon = 1e-9)
+        }));
+    }
+
+    #[test]
+    fn subdivide_loop() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2])],
+            vec![(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)],
+        )
+        .unwrap();
+        graph.subdivide_loop().unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(4, graph.face_count());
+        // Boundary vertices are moved toward their adjacent boundary vertices.
+        assert!(graph
+            .vertices()
+            .any(|vertex| *vertex.position() == Point2::new(0.5, 0.5)));
+        // Boundary edge points are midpoints.
+        assert!(graph
+            .vertices()
+            .any(|vertex| *vertex.position() == Point2::new(2.0, 2.0)));
+
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.triangulate();
+        graph.subdivide_loop().unwrap();
+
+        assert_eq!(26, graph.vertex_count());
+        assert_eq!(48, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+    }
+
+    #[test]
+    fn reject_subdivide_loop_non_triangular() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(
+            Err(GraphError::TopologyNotTriangular),
+            graph.subdivide_loop()
+        );
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_sqrt3() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(8, 6)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .collect();
+        let n = graph.face_count();
+        let m = graph.vertex_count();
+        graph.subdivide_sqrt3().unwrap();
+
+        assert_eq!(3 * n, graph.face_count());
+        assert_eq!(m + n, graph.vertex_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(
+            Err(GraphError::TopologyNotTriangular),
+            graph.subdivide_sqrt3()
+        );
+        assert_eq!(6, graph.face_count());
+    }
+
+    #[test]
+    fn subdivide_with_scheme() {
+        // Splits edges at their midpoints without moving any vertices.
+        enum Midpoint {}
+
+        impl SubdivisionScheme<Point2<f64>> for Midpoint {
+            fn face_point(_: FaceView<&MeshGraph<Point2<f64>>>) -> Option<Point2<f64>> {
+                None
+            }
+
+            fn edge_point(
+                edge: EdgeView<&MeshGraph<Point2<f64>>>,
+                _: &FacePoints<Point2<f64>>,
+            ) -> Point2<f64> {
+                let arc = edge.arc();
+                nalgebra::center(
+                    arc.source_vertex().position(),
+                    arc.destination_vertex().position(),
+                )
+            }
+
+            fn vertex_point(
+                vertex: VertexView<&MeshGraph<Point2<f64>>>,
+                _: &FacePoints<Point2<f64>>,
+            ) -> Point2<f64> {
+                *vertex.position()
+            }
+        }
+
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2])],
+            vec![(0.0, 0.0), (4.0, 0.0), (0.0, 4.0)],
+        )
+        .unwrap();
+        graph.subdivide_with::<Midpoint>().unwrap();
+
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(4, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        for position in &[
+            Point2::new(0.0, 0.0),
+            Point2::new(4.0, 0.0),
+            Point2::new(0.0, 4.0),
+            Point2::new(2.0, 0.0),
+            Point2::new(2.0, 2.0),
+            Point2::new(0.0, 2.0),
+        ] {
+            assert!(graph
+                .vertices()
+                .any(|vertex| vertex.position() == position));
+        }
+    }
+
+    #[test]
+    fn subdivide_faces() {
+        // Construct a graph from a $3\times3$ grid of quadrilaterals.
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            (0..3)
+                .flat_map(|y| (0..3).map(move |x| (y * 4) + x))
+                .map(|i: usize| NGon([i, i + 1, i + 5, i + 4]))
+                .collect::<Vec<_>>(),
+            (0..4)
+                .flat_map(|y| (0..4).map(move |x| (f64::from(x), f64::from(y))))
+                .collect::<Vec<_>>(),
+        )
+        .unwrap();
+        let center = graph
+            .faces()
+            .find(|face| face.centroid() == Point2::new(1.5, 1.5))
+            .unwrap()
+            .key();
+        graph
+            .subdivide_faces(&[center].into_iter().collect())
+            .unwrap();
+
+        assert_eq!(21, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        // The center quadrilateral is replaced by four quadrilaterals and
+        // neighboring faces include the midpoints of shared edges.
+        assert_eq!(8, graph.faces().filter(|face| face.arity() == 4).count());
+        assert_eq!(4, graph.faces().filter(|face| face.arity() == 5).count());
+        // Only the perimeter of the grid forms a boundary, so there are no
+        // cracks.
+        assert_eq!(
+            12,
+            graph
+                .edges()
+                .filter(|edge| edge.is_boundary_edge())
+                .count()
+        );
+        assert!(graph
+            .vertices()
+            .any(|vertex| *vertex.position() == Point2::new(1.5, 1.5)));
+    }
+
+    #[test]
+    fn reject_subdivide_faces_not_found() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().remove();
+
+        assert_eq!(
+            Err(GraphError::TopologyNotFound),
+            graph.subdivide_faces(&[key].into_iter().collect())
+        );
+        assert_eq!(5, graph.face_count());
+    }
+
+    #[test]
+    fn dual() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let dual = graph.dual().unwrap();
+
+        // The dual of a cube is an octahedron.
+        assert_eq!(6, dual.vertex_count());
+        assert_eq!(12, dual.edge_count());
+        assert_eq!(8, dual.face_count());
+        assert!(dual.faces().all(|face| face.arity() == 3));
+        assert!(dual.vertices().all(|vertex| vertex.valence() == 4));
+        // Dual vertices are positioned at the centroids of the faces of the
+        // cube.
+        assert!(dual.vertices().all(|vertex| {
+            let mut xs = vertex.position().iter().map(|x| x.abs()).collect::<Vec<_>>();
+            xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            xs == vec![0.0, 0.0, 0.5]
+        }));
+    }
+
+    #[test]
+    fn dual_skip_boundary() {
+        // Construct a graph with a single interior vertex.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![
+                NGon([0usize, 1, 4, 3]),
+                NGon([1, 2, 5, 4]),
+                NGon([3, 4, 7, 6]),
+                NGon([4, 5, 8, 7]),
+            ],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (2.0, 0.0),
+                (0.0, 1.0),
+                (1.0, 1.0),
+                (2.0, 1.0),
+                (0.0, 2.0),
+                (1.0, 2.0),
+                (2.0, 2.0),
+            ],
+        )
+        .unwrap();
+        let dual = graph.dual().unwrap();
+
+        assert_eq!(4, dual.vertex_count());
+        assert_eq!(1, dual.face_count());
+    }
+
+    #[test]
+    fn decimate_to() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>() // 32 triangles and 96 quadrilaterals.
+            .collect();
+        graph.triangulate();
+        assert_eq!(224, graph.face_count());
+
+        graph.decimate_to(100).unwrap();
+
+        // Each collapse removes two faces.
+        assert_eq!(100, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        // The graph remains a closed surface with genus zero.
+        assert_eq!(
+            2,
+            (graph.vertex_count() + graph.face_count()) as isize - graph.edge_count() as isize
+        );
+    }
+
+    #[test]
+    fn decimate_to_with_max_valence() {
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>()
+            .collect();
+        graph.triangulate();
+        // The poles have a valence of 16 and cannot be collapsed.
+        let poles = graph
+            .vertices()
+            .filter(|vertex| vertex.valence() > 8)
+            .map(|vertex| vertex.key())
+            .collect::<HashSet<_>>();
+
+        graph.decimate_to_with_max_valence(100, 8).unwrap();
+
+        assert!(graph.face_count() < 224);
+        assert!(graph.face_count() >= 100);
+        assert!(graph
+            .vertices()
+            .all(|vertex| poles.contains(&vertex.key()) || vertex.valence() <= 8));
+    }
+
+    #[test]
+    fn reject_decimate_to_non_triangular() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(
+            Err(GraphError::TopologyNotTriangular),
+            graph.decimate_to(4)
+        );
+    }
+
+    #[test]
+    fn smooth_laplacian() {
+        // Construct a graph with a single interior vertex that is displaced
+        // from the center of its adjacent vertices.
+        let graph = || {
+            MeshGraph::<Point2<f64>>::from_raw_buffers(
+                vec![
+                    NGon([0usize, 1, 4, 3]),
+                    NGon([1, 2, 5, 4]),
+                    NGon([3, 4, 7, 6]),
+                    NGon([4, 5, 8, 7]),
+                ],
+                vec![
+                    (0.0, 0.0),
+                    (1.0, 0.0),
+                    (2.0, 0.0),
+                    (0.0, 1.0),
+                    (1.5, 1.5),
+                    (2.0, 1.0),
+                    (0.0, 2.0),
+                    (1.0, 2.0),
+                    (2.0, 2.0),
+                ],
+            )
+            .unwrap()
+        };
+        let find = |graph: &MeshGraph<Point2<f64>>, x: f64, y: f64| {
+            graph
+                .vertices()
+                .find(|vertex| vertex.data == Point2::new(x, y))
+                .map(|vertex| vertex.key())
+                .unwrap()
+        };
+        let mut fixed = graph();
+        let (a, b) = (find(&fixed, 0.0, 0.0), find(&fixed, 1.5, 1.5));
+        fixed.smooth_laplacian(1, 1.0, SmoothBoundary::Fixed);
+        assert_eq!(Point2::new(0.0, 0.0), fixed.vertex(a).unwrap().data);
+        assert_eq!(Point2::new(1.0, 1.0), fixed.vertex(b).unwrap().data);
+
+        let mut along = graph();
+        let (a, b) = (find(&along, 0.0, 0.0), find(&along, 1.5, 1.5));
+        along.smooth_laplacian(1, 1.0, SmoothBoundary::Along);
+        assert_eq!(Point2::new(0.5, 0.5), along.vertex(a).unwrap().data);
+        assert_eq!(Point2::new(1.0, 1.0), along.vertex(b).unwrap().data);
+    }
+
+    #[test]
+    fn smooth_laplacian_noise() {
+        fn variance(graph: &MeshGraph<Point3<f64>>) -> f64 {
+            let lengths = graph
+                .edges()
+                .map(|edge| {
+                    let arc = edge.arc();
+                    (*arc.destination_vertex().position() - *arc.source_vertex().position())
+                        .norm()
+                })
+                .collect::<Vec<_>>();
+            let mean = lengths.iter().sum::<f64>() / lengths.len() as f64;
+            lengths
+                .iter()
+                .map(|length| (length - mean).powi(2))
+                .sum::<f64>()
+                / lengths.len() as f64
+        }
+
+        // Displace the vertices of a sphere by deterministic noise.
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 8)
+            .polygons::<Position<E3>>()
+            .collect();
+        let mut n = 0.0f64;
+        graph.map_positions(|position| {
+            n += 1.0;
+            position * (1.0 + (0.1 * n.sin()))
+        });
+        let before = variance(&graph);
+        graph.smooth_laplacian(4, 0.5, SmoothBoundary::Fixed);
+
+        assert!(variance(&graph) < before);
+    }
+
+    #[test]
+    fn compute_valences() {
+        let mut graph: MeshGraph<Point3<f64>> = crate::primitive::plane::Plane::new(4, 4)
+            .polygons::<Position<E3>>()
+            .collect();
+        graph.subdivide_catmull_clark(1).unwrap();
+        let valences = graph.compute_valences();
+
+        assert_eq!(graph.vertex_count(), valences.len());
+        assert!(graph
+            .vertices()
+            .all(|vertex| valences[&vertex.key()] == vertex.valence()));
+        assert_eq!(2 * graph.edge_count(), valences.values().sum::<usize>());
+    }
+
+    #[test]
+    fn valence_extremes() {
+        let graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 4).polygons::<Position<E3>>().collect();
+        let (highest, lowest) = graph.valence_extremes();
+
+        // The poles are the only vertices joined to every meridian.
+        assert_eq!(2, highest.len());
+        assert!(highest
+            .iter()
+            .all(|key| graph.vertex(*key).unwrap().valence() == 8));
+        assert_eq!(graph.vertex_count() - 2, lowest.len());
+        assert!(lowest
+            .iter()
+            .all(|key| graph.vertex(*key).unwrap().valence() == 4));
+
+        assert_eq!((vec![], vec![]), MeshGraph::<Point3<f64>>::new().valence_extremes());
+    }
+
+    #[test]
+    fn curvature_colors() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let curvatures = graph.compute_gaussian_curvatures();
+        assert!(curvatures
+            .values()
+            .all(|curvature| (std::f64::consts::FRAC_PI_2 - curvature).abs() < 1e-9));
+        // Corners of the cube saturate the ramp.
+        assert!(graph
+            .curvature_colors(-1.0, 1.0)
+            .values()
+            .all(|color| *color == [1.0, 0.0, 0.0, 1.0]));
+
+        // All vertices of a sphere have positive curvature.
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>()
+            .collect();
+        assert!(graph
+            .curvature_colors(-1.0, 1.0)
+            .values()
+            .all(|color| color[0] == 1.0 && color[2] < 1.0));
+
+        // Construct a flat grid of quadrilaterals about an interior vertex.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers_with_arity(
+            vec![0u32, 1, 4, 3, 1, 2, 5, 4, 3, 4, 7, 6, 4, 5, 8, 7],
+            (0..9)
+                .map(|index| ((index % 3) as f64, (index / 3) as f64, 0.0))
+                .collect::<Vec<_>>(),
+            4,
+        )
+        .unwrap();
+        let key = graph
+            .vertices()
+            .find(|vertex| vertex.valence() == 4)
+            .unwrap()
+            .key();
+        let color = graph.curvature_colors(-1.0, 1.0)[&key];
+        for component in color.iter() {
+            assert!((1.0 - component).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn sample_attribute() {
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2])],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        // Sample the linear field $f(x,y)=x+2y$.
+        let attribute = graph
+            .vertices()
+            .map(|vertex| {
+                let position = vertex.position();
+                (vertex.key(), Point2::new(position.x + (2.0 * position.y), 0.0))
+            })
+            .collect::<VertexAttribute<_>>();
+        let key = graph.faces().nth(0).unwrap().key();
+
+        let sample = graph
+            .sample_attribute(key, Point3::new(0.25, 0.25, 0.0), &attribute)
+            .unwrap();
+        assert!(abs_diff_eq!(0.75, sample.x, epsilon = 1e-9));
+        assert!(graph
+            .sample_attribute(key, Point3::new(1.0, 1.0, 0.0), &attribute)
+            .is_none());
+    }
+
+    #[test]
+    fn shared_edge() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let face = graph.faces().nth(0).unwrap();
+        let adjacent = face.adjacent_faces().nth(0).unwrap();
+        let opposite = graph
+            .faces()
+            .find(|other| {
+                other.key() != face.key()
+                    && other
+                        .adjacent_faces()
+                        .all(|adjacent| adjacent.key() != face.key())
+            })
+            .unwrap();
+
+        let key = graph.shared_edge(face.key(), adjacent.key()).unwrap();
+        let edge = graph.edge(key).unwrap();
+        let arc = edge.arc();
+        let faces = [arc.face().unwrap().key(), arc.opposite_arc().face().unwrap().key()];
+        assert!(faces.contains(&face.key()));
+        assert!(faces.contains(&adjacent.key()));
+        assert_eq!(Some(key), graph.shared_edge(adjacent.key(), face.key()));
+        assert_eq!(None, graph.shared_edge(face.key(), opposite.key()));
+    }
+
+    #[test]
+    fn boundary_loops() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert!(graph.boundary_loops().is_empty());
+        assert_eq!(0, graph.boundary_edges().count());
+
+        // Construct a graph with a disjoint quadrilateral and triangle.
+        let graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::tetragon(0usize, 1, 2, 3),
+                UnboundedPolygon::trigon(4, 5, 6),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (3.0, 0.0, 0.0),
+                (2.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        assert_eq!(7, graph.boundary_edges().count());
+        let mut loops = graph.boundary_loops();
+        loops.sort_by_key(|edges| edges.len());
+
+        assert_eq!(2, loops.len());
+        assert_eq!(3, loops[0].len());
+        assert_eq!(4, loops[1].len());
+        let vertices = |key: &EdgeKey| {
+            graph
+                .edge(*key)
+                .unwrap()
+                .into_arc()
+                .into_adjacent_vertices()
+                .map(|vertex| vertex.key())
+                .collect::<Vec<_>>()
+        };
+        for edges in loops {
+            // Consecutive edges in a loop share exactly one vertex.
+            for (a, b) in edges.iter().zip(edges.iter().cycle().skip(1)) {
+                let a = vertices(a);
+                assert_eq!(1, vertices(b).iter().filter(|key| a.contains(key)).count());
+            }
+        }
+    }
+
+    #[test]
+    fn connected_components() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(1, graph.component_count());
+        assert_eq!(1, graph.connected_components().len());
+        assert_eq!(6, graph.component_faces()[0].len());
+
+        // Construct a graph with a disjoint quadrilateral and triangle.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::tetragon(0usize, 1, 2, 3),
+                UnboundedPolygon::trigon(4, 5, 6),
+            ],
+            vec![
+                (0.0, 0.0),
+                (1.0, 0.0),
+                (1.0, 1.0),
+                (0.0, 1.0),
+                (2.0, 0.0),
+                (3.0, 0.0),
+                (2.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let faces = graph.component_faces();
+        assert_eq!(2, faces.len());
+        assert!(faces.iter().all(|faces| faces.len() == 1));
+        assert!(faces[0].is_disjoint(&faces[1]));
+
+        let mut components = graph.connected_components();
+        components.sort_by_key(|component| component.vertex_count());
+
+        assert_eq!(2, graph.component_count());
+        assert_eq!(2, components.len());
+        assert_eq!(3, components[0].vertex_count());
+        assert_eq!(4, components[1].vertex_count());
+        assert!(components
+            .iter()
+            .all(|component| component.face_count() == 1 && component.component_count() == 1));
+        assert!(components[0]
+            .vertices()
+            .any(|vertex| *vertex.position() == Point2::new(3.0, 0.0)));
+    }
+
+    #[test]
+    fn into_disjoint_subgraphs() {
+        // Construct a graph from two disjoint cubes.
+        let cube = Cube::new();
+        let translation = Vector3::new(3.0.into(), 0.0.into(), 0.0.into());
+        let graph: MeshGraph<E3> = cube
+            .polygons::<Position<E3>>()
+            .chain(
+                cube.polygons::<Position<E3>>()
+                    .map_vertices(|position| position + translation),
+            )
+            .collect();
+        assert_eq!(12, graph.face_count());
+
+        let graphs = graph.into_disjoint_subgraphs();
+        assert_eq!(2, graphs.len());
+        for graph in graphs.iter() {
+            assert_eq!(8, graph.vertex_count());
+            assert_eq!(6, graph.face_count());
+            assert!(graph.validate().is_ok());
+        }
+
+        // Construct a graph with two triangles that share only a vertex. Such
+        // faces are not connected, but their sub-graphs are not disjoint.
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 3, 4)],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (-1.0, 0.0), (-1.0, -1.0)],
+        )
+        .unwrap();
+        assert_eq!(2, graph.connected_components().len());
+        let graphs = graph.into_disjoint_subgraphs();
+        assert_eq!(1, graphs.len());
+        assert_eq!(5, graphs[0].vertex_count());
+        assert_eq!(2, graphs[0].face_count());
+    }
+
+    #[test]
+    fn euler_characteristic_and_genus() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(2, graph.euler_characteristic());
+        assert_eq!(Some(0), graph.genus());
+
+        // Edges are counted once rather than once for each of their arcs.
+        let graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+        assert_eq!(graph.arc_count(), 2 * graph.edge_count());
+        assert_eq!(2, graph.euler_characteristic());
+        assert_eq!(Some(0), graph.genus());
+
+        let graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2, 3])],
+            vec![(0.0, 0.0), (1.0, 0.0), (1.0, 1.0), (0.0, 1.0)],
+        )
+        .unwrap();
+        assert_eq!(1, graph.euler_characteristic());
+        assert_eq!(None, graph.genus());
+
+        // Construct a torus from a $3\times3$ grid of quadrilaterals with
+        // opposing sides identified.
+        let index = |i: usize, j: usize| ((i % 3) * 3) + (j % 3);
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            (0..3)
+                .flat_map(|i| (0..3).map(move |j| (i, j)))
+                .map(|(i, j)| {
+                    NGon([
+                        index(i, j),
+                        index(i + 1, j),
+                        index(i + 1, j + 1),
+                        index(i, j + 1),
+                    ])
+                })
+                .collect::<Vec<_>>(),
+            (0..9).map(|n| (f64::from(n), 0.0, 0.0)).collect::<Vec<_>>(),
+        )
+        .unwrap();
+        assert_eq!(0, graph.euler_characteristic());
+        assert_eq!(Some(1), graph.genus());
+    }
+
+    #[test]
+    fn bilateral_normal_smooth() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        for _ in 0..2 {
+            let keys = graph.faces().map(|face| face.key()).collect();
+            graph.subdivide_faces(&keys).unwrap();
+        }
+        graph.triangulate();
+        // Count the coordinates of a position that lie on the surface of the
+        // cube (all of them at corners and two of them along creases).
+        let on_surface = |position: &Point3<f64>| {
+            position
+                .coords
+                .iter()
+                .filter(|x| (x.abs() - 0.5).abs() < 0.03)
+                .count()
+        };
+        let deviation = |position: &Point3<f64>| {
+            position
+                .coords
+                .iter()
+                .map(|x| (x.abs() - 0.5).abs())
+                .fold(f64::MAX, f64::min)
+        };
+        let (interior, creases): (Vec<_>, Vec<_>) = graph
+            .vertices()
+            .map(|vertex| (vertex.key(), on_surface(vertex.position())))
+            .partition(|(_, n)| *n == 1);
+        // Displace vertices within the faces of the cube along the normals of
+        // those faces in a checkerboard pattern.
+        for (key, _) in interior.iter() {
+            let mut vertex = graph.vertex_mut(*key).unwrap();
+            let parity = vertex
+                .position()
+                .coords
+                .iter()
+                .map(|x| (x * 4.0).round() as i64)
+                .sum::<i64>();
+            let offset = if parity % 2 == 0 { 0.02 } else { -0.02 };
+            for x in vertex.get_mut().coords.iter_mut() {
+                if (x.abs() - 0.5).abs() < 0.001 {
+                    *x += x.signum() * offset;
+                }
+            }
+        }
+        graph.bilateral_normal_smooth(5, 0.25, 0.35);
+
+        // Faces are flattened.
+        assert!(interior
+            .iter()
+            .all(|(key, _)| deviation(graph.vertex(*key).unwrap().position()) < 0.01));
+        // Creases remain sharp.
+        assert!(creases.iter().all(|(key, n)| {
+            let position = graph.vertex(*key).unwrap().position();
+            on_surface(position) == *n && deviation(position) < 0.01
+        }));
+    }
+
+    #[test]
+    fn remove_duplicate_faces() {
+        // Construct a graph with a two-sided triangle and a disjoint triangle.
+        let mut graph = MeshGraph::<E3>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([2, 1, 0]), NGon([3, 4, 5])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (3.0, 0.0, 0.0),
+                (2.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(1, graph.duplicate_faces().len());
+        assert_eq!(1, graph.remove_duplicate_faces());
+        assert_eq!(2, graph.face_count());
+        assert!(graph.duplicate_faces().is_empty());
+    }
+
+    #[test]
+    fn retain_faces() {
+        let mut graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(16, 8).polygons::<Position<E3>>().collect();
+        graph.retain_faces(|face| matches!(face.normal(), Ok(normal) if normal.z > 0.0));
+
+        // Only the faces of one hemisphere remain.
+        assert_eq!(64, graph.face_count());
+        assert_eq!(65, graph.vertex_count());
+        assert!(graph.faces().all(|face| face.normal().unwrap().z > 0.0));
+        assert_eq!(1, graph.boundary_loops().len());
+        assert_eq!(16, graph.boundary_loops()[0].len());
+        assert_eq!(Ok(()), graph.validate());
+    }
+
+    #[test]
+    fn weld() {
+        // Construct unindexed triangles. The first two triangles share an edge
+        // when welded and the third triangle collapses.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 4, 5]), NGon([6, 7, 8])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.0, 1e-6, 0.0),
+                (1.0, 1.0, 0.0),
+                (1e-6, 1.0, 0.0),
+                (5.0, 0.0, 0.0),
+                (5.0 + 1e-6, 0.0, 0.0),
+                (5.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(9, graph.vertex_count());
+        // Three vertices are merged and two more are removed along with the
+        // collapsed triangle.
+        assert_eq!(5, graph.weld(1e-3).unwrap());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert_eq!(Ok(()), graph.validate());
+
+        // Welding again has no effect.
+        assert_eq!(0, graph.weld(1e-3).unwrap());
+    }
 
-    use crate::buffer::MeshBuffer3;
-    use crate::graph::{GraphData, GraphError, MeshGraph};
-    use crate::prelude::*;
-    use crate::primitive::generate::Position;
-    use crate::primitive::sphere::UvSphere;
-    use crate::primitive::NGon;
+    #[test]
+    fn weld_disjoint_edges() {
+        // Construct unindexed triangles. The first two triangles share an edge
+        // when welded and the face of the third triangle is removed, leaving
+        // edges that are not adjacent to any faces and that share a vertex
+        // with the other triangles when welded.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 4, 5]), NGon([6, 7, 8])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.0, 1e-6, 0.0),
+                (1.0, 1.0, 0.0),
+                (1e-6, 1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (2.0, 1.0, 0.0),
+                (2.0, 2.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let key = graph
+            .faces()
+            .find(|face| {
+                face.adjacent_vertices()
+                    .any(|vertex| *vertex.position() == Point3::new(2.0, 2.0, 0.0))
+            })
+            .unwrap()
+            .key();
+        graph.face_mut(key).unwrap().remove();
 
-    type E2 = Point2<R64>;
-    type E3 = Point3<R64>;
+        assert_eq!(9, graph.edge_count());
+        assert_eq!(3, graph.weld(1e-3).unwrap());
+        assert_eq!(6, graph.vertex_count());
+        assert_eq!(8, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert_eq!(
+            3,
+            graph
+                .edges()
+                .filter(|edge| {
+                    let arc = edge.arc();
+                    arc.is_boundary_arc() && arc.opposite_arc().is_boundary_arc()
+                })
+                .count()
+        );
+        assert_eq!(Ok(()), graph.validate());
+    }
 
     #[test]
-    fn collect() {
-        let graph: MeshGraph<Point3<f64>> = UvSphere::new(3, 2)
-            .polygons::<Position<E3>>() // 6 triangles, 18 vertices.
+    fn merge_coplanar_faces() {
+        // Construct a graph from the two triangles of a quadrilateral.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.0, 0.0),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(1, graph.merge_coplanar_faces(1e-6));
+        assert_eq!(1, graph.face_count());
+        assert_eq!(4, graph.edge_count());
+        assert_eq!(4, graph.faces().next().unwrap().arity());
+        assert_eq!(0, graph.merge_coplanar_faces(1e-6));
+
+        // Faces with normals that disagree are not merged.
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(8, 4)
+            .polygons::<Position<E3>>()
+            .triangulate()
             .collect();
+        let n = graph.face_count();
 
-        assert_eq!(5, graph.vertex_count());
-        assert_eq!(18, graph.arc_count());
-        assert_eq!(6, graph.face_count());
+        assert_eq!(8 * 2, graph.merge_coplanar_faces(1e-6));
+        assert_eq!(n - (8 * 2), graph.face_count());
+        assert_eq!(Ok(()), graph.validate());
     }
 
     #[test]
-    fn iterate() {
-        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(4, 2)
-            .polygons::<Position<E3>>() // 8 triangles, 24 vertices.
+    fn merge_coplanar_faces_curved() {
+        // Adjacent faces of the sphere agree within the tolerance, but merged
+        // faces must not drift across the curved surface.
+        let tolerance = 0.25;
+        let mut graph: MeshGraph<Point3<f64>> = UvSphere::new(32, 16)
+            .polygons::<Position<E3>>()
+            .triangulate()
             .collect();
 
-        assert_eq!(6, graph.vertices().count());
-        assert_eq!(24, graph.arcs().count());
-        assert_eq!(8, graph.faces().count());
-        for vertex in graph.vertices() {
-            // Every vertex is connected to 4 triangles with 4 (incoming) arcs.
-            // Traversal of topology should be possible.
-            assert_eq!(4, vertex.incoming_arcs().count());
+        assert!(graph.merge_coplanar_faces(tolerance) > 0);
+        assert_eq!(Ok(()), graph.validate());
+        // Each original face has vertices within about 0.2 radians of its
+        // normal, so the vertices of a merged face cannot be farther apart
+        // than twice this distance and the tolerance.
+        for face in graph.faces() {
+            let directions = face
+                .adjacent_vertices()
+                .map(|vertex| vertex.position().coords.normalize())
+                .collect::<Vec<_>>();
+            for a in directions.iter() {
+                for b in directions.iter() {
+                    assert!(a.dot(b).min(1.0).acos() <= 2.0 * (tolerance + 0.2));
+                }
+            }
         }
-        for mut vertex in graph.vertex_orphans() {
-            // Data should be mutable.
-            *vertex.get_mut() += Vector3::zero();
+    }
+
+    #[test]
+    fn weld_open_shells() {
+        // Construct two open shells that each form half of a cube and touch
+        // along their boundaries.
+        let polygons = Cube::new().polygons::<Position<E3>>().collect::<Vec<_>>();
+        let mut indices = Vec::new();
+        let mut positions = Vec::new();
+        for shell in polygons.chunks(3) {
+            let (shell_indices, shell_positions) = shell
+                .iter()
+                .cloned()
+                .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+            let offset = positions.len();
+            indices.extend(
+                shell_indices
+                    .into_iter()
+                    .map(|NGon(polygon)| NGon(polygon.map(|index| index + offset))),
+            );
+            positions.extend(shell_positions);
         }
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(indices, positions).unwrap();
+
+        assert_eq!(14, graph.vertex_count());
+        assert!(graph.edges().any(|edge| edge.is_boundary_edge()));
+        assert_eq!(6, graph.weld(1e-6).unwrap());
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+        assert_eq!(Ok(()), graph.validate());
     }
 
     #[test]
-    fn isolate_disjoint_subgraphs() {
-        // Construct a graph from a quadrilateral.
-        let graph = MeshGraph::<E2>::from_raw_buffers(
-            vec![NGon([0u32, 1, 2, 3])],
-            vec![(1.0, 0.0), (2.0, 0.0), (2.0, 1.0), (1.0, 1.0)],
+    fn weld_with_report() {
+        // Construct three triangles that each share an edge when welded. The
+        // edge cannot have three faces, so one of its groups is not merged.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 4, 5]), NGon([6, 7, 8])],
+            vec![
+                (0.0, 0.0, 1.0),
+                (0.0, 0.0, -1.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 0.0, 1.0),
+                (0.0, 0.0, -1.0),
+                (0.0, 1.0, 0.0),
+                (0.0, 0.0, 1.0),
+                (0.0, 0.0, -1.0),
+                (1.0, 1.0, 0.0),
+            ],
         )
         .unwrap();
+        let report = graph.weld_with_report(1e-6);
 
-        assert_eq!(1, graph.disjoint_subgraph_vertices().count());
+        assert_eq!(2, report.merged());
+        assert_eq!(1, report.rejected().len());
+        assert_eq!(7, graph.vertex_count());
+        assert_eq!(3, graph.face_count());
+        let rejected = &report.rejected()[0];
+        assert_eq!(3, rejected.len());
+        assert!(rejected.iter().all(|key| {
+            let vertex = graph.vertex(*key).unwrap();
+            vertex.position().z == -1.0 && vertex.adjacent_faces().count() == 1
+        }));
+        assert_eq!(Ok(()), graph.validate());
 
-        // Construct a graph with two disjoint quadrilaterals.
-        let graph = MeshGraph::<E2>::from_raw_buffers(
-            vec![NGon([0u32, 1, 2, 3]), NGon([4, 5, 6, 7])],
+        // Welding again rejects the same group.
+        let report = graph.weld_with_report(1e-6);
+        assert_eq!(0, report.merged());
+        assert_eq!(1, report.rejected().len());
+        assert_eq!(7, graph.vertex_count());
+    }
+
+    #[test]
+    fn quantize_and_weld() {
+        // Construct unindexed triangles that share an edge when quantized.
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0usize, 1, 2]), NGon([3, 4, 5])],
             vec![
-                (-2.0, 0.0),
-                (-1.0, 0.0),
-                (-1.0, 1.0),
-                (-2.0, 1.0),
-                (1.0, 0.0),
-                (2.0, 0.0),
-                (2.0, 1.0),
-                (1.0, 1.0),
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (1.01, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 1.02, 0.0),
             ],
         )
         .unwrap();
 
-        assert_eq!(2, graph.disjoint_subgraph_vertices().count());
+        assert_eq!(2, graph.quantize_and_weld(0.1).unwrap());
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert!(graph
+            .vertices()
+            .any(|vertex| *vertex.position() == Point3::new(1.0, 0.0, 0.0)));
+        assert_eq!(Ok(()), graph.validate());
+
+        // Quantizing again has no effect.
+        assert_eq!(0, graph.quantize_and_weld(0.1).unwrap());
+        assert_eq!(4, graph.vertex_count());
+    }
+
+    #[test]
+    fn flip_orientation() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let volume = graph.volume();
+        let normals = graph
+            .faces()
+            .map(|face| (face.key(), face.normal().unwrap()))
+            .collect::<Vec<_>>();
+
+        graph.flip_orientation();
+
+        assert_eq!(Ok(()), graph.validate());
+        assert!(abs_diff_eq!(-volume, graph.volume(), epsilon = 1e-9));
+        for (key, normal) in normals {
+            let flipped = graph.face(key).unwrap().normal().unwrap();
+            assert!(abs_diff_eq!(-1.0, normal.dot(&flipped), epsilon = 1e-9));
+        }
+    }
+
+    #[test]
+    fn bridge() {
+        // Construct a graph with two disjoint quadrilaterals facing each other.
+        let graph = || {
+            MeshGraph::<Point3<f64>>::from_raw_buffers(
+                vec![NGon([0usize, 1, 2, 3]), NGon([4, 7, 6, 5])],
+                vec![
+                    (0.0, 0.0, 0.0),
+                    (1.0, 0.0, 0.0),
+                    (1.0, 1.0, 0.0),
+                    (0.0, 1.0, 0.0),
+                    (0.0, 0.0, 1.0),
+                    (1.0, 0.0, 1.0),
+                    (1.0, 1.0, 1.0),
+                    (0.0, 1.0, 1.0),
+                ],
+            )
+            .unwrap()
+        };
+        // Gets the vertex in the destination face that corresponds to the
+        // vertex at the origin in the source face.
+        let partner = |graph: &MeshGraph<Point3<f64>>| {
+            let origin = graph
+                .vertices()
+                .find(|vertex| *vertex.position() == Point3::origin())
+                .unwrap();
+            let partners = origin
+                .adjacent_vertices()
+                .filter(|vertex| vertex.position().z == 1.0)
+                .map(|vertex| *vertex.position())
+                .collect::<Vec<_>>();
+            assert_eq!(1, partners.len());
+            partners[0]
+        };
+        // The perimeter of the destination face, in order.
+        let perimeter = [
+            Point3::new(0.0, 0.0, 1.0),
+            Point3::new(0.0, 1.0, 1.0),
+            Point3::new(1.0, 1.0, 1.0),
+            Point3::new(1.0, 0.0, 1.0),
+        ];
+        let mut partners = Vec::new();
+        for offset in 0..4 {
+            let mut graph = graph();
+            let (source, destination) = graph
+                .faces()
+                .map(|face| face.key())
+                .partition::<Vec<_>, _>(|key| {
+                    graph
+                        .face(*key)
+                        .unwrap()
+                        .adjacent_vertices()
+                        .all(|vertex| vertex.position().z == 0.0)
+                });
+            graph.bridge(source[0], destination[0], offset).unwrap();
+
+            assert_eq!(8, graph.vertex_count());
+            assert_eq!(12, graph.edge_count());
+            assert_eq!(4, graph.face_count());
+            assert!(graph.faces().all(|face| face.arity() == 4));
+            partners.push(partner(&graph));
+        }
+        // Each increment of the offset advances the corresponding vertex by
+        // one step along the perimeter of the destination face.
+        let start = perimeter
+            .iter()
+            .position(|position| *position == partners[0])
+            .unwrap();
+        for (offset, partner) in partners.into_iter().enumerate() {
+            assert_eq!(perimeter[(start + offset) % 4], partner);
+        }
+    }
+
+    #[test]
+    fn bridge_arity_conflict() {
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![
+                UnboundedPolygon::trigon(0usize, 1, 2),
+                UnboundedPolygon::tetragon(3, 6, 5, 4),
+            ],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (0.0, 0.0, 1.0),
+                (1.0, 0.0, 1.0),
+                (1.0, 1.0, 1.0),
+                (0.0, 1.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let keys = graph.faces().map(|face| face.key()).collect::<Vec<_>>();
+
+        assert!(matches!(
+            graph.bridge(keys[0], keys[1], 0),
+            Err(GraphError::ArityConflict { .. })
+        ));
+        assert!(matches!(
+            graph.face_mut(keys[0]).unwrap().bridge(keys[1]),
+            Err(GraphError::ArityNonUniform)
+        ));
+        assert_eq!(2, graph.face_count());
     }
 
     #[test]
@@ -1788,6 +6336,457 @@ mod tests {
         let _: MeshBuffer3<usize, E3> = graph.to_mesh_by_face().unwrap();
     }
 
+    #[test]
+    fn from_indexed_buffers() {
+        #[derive(Clone, Copy)]
+        struct Vertex {
+            position: Point3<f64>,
+            normal: Vector3<f64>,
+        }
+
+        impl GraphData for Vertex {
+            type Vertex = Self;
+            type Arc = ();
+            type Edge = ();
+            type Face = ();
+
+            fn vertex_normal(vertex: &Self::Vertex) -> Option<Vector3<f64>> {
+                Some(vertex.normal)
+            }
+        }
+
+        impl AsPosition for Vertex {
+            type Position = Point3<f64>;
+
+            fn as_position(&self) -> &Self::Position {
+                &self.position
+            }
+        }
+
+        impl FromGeometry<(Point3<f64>, Vector3<f64>)> for Vertex {
+            fn from_geometry((position, normal): (Point3<f64>, Vector3<f64>)) -> Self {
+                Vertex { position, normal }
+            }
+        }
+
+        // The normals differ from the normals of the faces, so that they are
+        // distinguishable from computed normals.
+        let positions = vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ];
+        let normals = vec![
+            Vector3::x(),
+            Vector3::y(),
+            Vector3::z(),
+            Vector3::new(1.0, 1.0, 1.0),
+        ];
+        let graph = MeshGraph::<Vertex>::from_indexed_buffers(
+            positions.clone(),
+            normals.clone(),
+            vec![0u32, 1, 2, 0, 2, 3],
+            3,
+        )
+        .unwrap();
+
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+        for vertex in graph.vertices() {
+            let index = positions
+                .iter()
+                .position(|position| position == vertex.position())
+                .unwrap();
+            // Normals in vertex data are normalized.
+            assert!(abs_diff_eq!(
+                normals[index].normalize(),
+                vertex.normal().unwrap(),
+                epsilon = 1e-9
+            ));
+        }
+
+        // Buffers of different lengths are rejected.
+        assert_eq!(
+            GraphError::BufferLengthConflict {
+                expected: 4,
+                actual: 1
+            },
+            MeshGraph::<Vertex>::from_indexed_buffers(
+                positions,
+                vec![Vector3::z()],
+                vec![0u32, 1, 2],
+                3,
+            )
+            .err()
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn to_flat_buffers_shortest_diagonal() {
+        // A skewed quadrilateral with a short diagonal between its second and
+        // fourth vertices.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Tetragon::new(0usize, 1, 2, 3)],
+            vec![(0.0, 0.0, 0.0), (4.0, 0.0, 0.0), (5.0, 1.0, 0.0), (1.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        let (vertices, indices) = graph.to_flat_buffers(Triangulation::ShortestDiagonal);
+
+        assert_eq!(4, vertices.len());
+        assert_eq!(6, indices.len());
+        // Both triangles share the short diagonal.
+        let b = Point3::new(4.0, 0.0, 0.0);
+        let d = Point3::new(1.0, 1.0, 0.0);
+        for triangle in indices.chunks(3) {
+            let triangle = triangle
+                .iter()
+                .map(|index| vertices[*index])
+                .collect::<Vec<_>>();
+            assert!(triangle.contains(&b));
+            assert!(triangle.contains(&d));
+        }
+
+        let (_, indices) = graph.to_flat_buffers(Triangulation::Fan);
+        assert_eq!(6, indices.len());
+    }
+
+    #[test]
+    fn push_triangle() {
+        let point = |x: f64, y: f64| Point2::new(R64::from_inner(x), R64::from_inner(y));
+
+        let mut graph = MeshGraph::<Point2<R64>>::default();
+        graph
+            .push_triangle(point(0.0, 0.0), point(1.0, 0.0), point(1.0, 1.0))
+            .unwrap();
+        graph
+            .push_triangle(point(0.0, 0.0), point(1.0, 1.0), point(0.0, 1.0))
+            .unwrap();
+
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(5, graph.edge_count());
+        assert_eq!(2, graph.face_count());
+        assert_eq!(
+            1,
+            graph
+                .edges()
+                .filter(|edge| !edge.is_boundary_edge())
+                .count()
+        );
+
+        // A triangle that occupies an arc of another triangle is rejected and
+        // does not modify the graph.
+        assert_eq!(
+            Err(GraphError::TopologyConflict),
+            graph.push_triangle(point(0.0, 0.0), point(1.0, 0.0), point(0.5, -1.0))
+        );
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+
+        // Positions need only be comparable, not hashable.
+        let mut graph = MeshGraph::<Point2<f64>>::default();
+        graph
+            .push_triangle(
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 0.0),
+                Point2::new(1.0, 1.0),
+            )
+            .unwrap();
+        graph
+            .push_triangle(
+                Point2::new(0.0, 0.0),
+                Point2::new(1.0, 1.0),
+                Point2::new(0.0, 1.0),
+            )
+            .unwrap();
+
+        assert_eq!(4, graph.vertex_count());
+        assert_eq!(2, graph.face_count());
+    }
+
+    #[test]
+    fn triangle_indices() {
+        let mut graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.triangulate();
+        let triangles = graph.triangle_indices();
+
+        assert_eq!(12, triangles.len());
+        assert!(triangles.iter().flatten().all(|index| *index < 8));
+        // Indices are the same as those of the flat buffers.
+        let (_, indices) = graph.to_flat_buffers(Triangulation::Fan);
+        assert_eq!(indices, triangles.into_iter().flatten().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn triangulate_with_parent_map() {
+        let graph: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let (triangles, parents) = graph.triangulate_with_parent_map();
+
+        assert_eq!(12, triangles.len());
+        assert_eq!(12, parents.len());
+        assert_eq!(triangles, graph.triangle_indices());
+        assert!(graph.faces().all(|face| {
+            parents.iter().filter(|key| **key == face.key()).count() == 2
+        }));
+        // Each triangle is formed from the vertices of its parent face.
+        let vertices = graph.vertices().map(|vertex| vertex.key()).collect::<Vec<_>>();
+        for (triangle, key) in triangles.iter().zip(parents) {
+            let face = graph.face(key).unwrap();
+            assert!(triangle.iter().all(|index| face
+                .adjacent_vertices()
+                .any(|vertex| vertex.key() == vertices[*index])));
+        }
+    }
+
+    #[test]
+    fn to_interleaved_f32() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(8, 8)
+            .polygons::<Position<E3>>()
+            .collect();
+        let (vertices, indices) = graph.to_interleaved_f32(&[VertexBufferAttribute::Position]);
+
+        assert_eq!(3 * graph.vertex_count(), vertices.len());
+        assert_eq!(3 * graph.triangle_indices().len(), indices.len());
+        assert!(indices
+            .iter()
+            .all(|index| (*index as usize) < graph.vertex_count()));
+
+        // Normals of a sphere are unit vectors that follow positions.
+        let (vertices, _) = graph.to_interleaved_f32(&[
+            VertexBufferAttribute::Position,
+            VertexBufferAttribute::Normal,
+        ]);
+        assert_eq!(6 * graph.vertex_count(), vertices.len());
+        for vertex in vertices.chunks(6) {
+            let norm = vertex[3..].iter().map(|x| x * x).sum::<f32>().sqrt();
+            assert!((1.0 - norm).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn map_positions() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.map_positions(|position| Point3::new(position.x * 2.0, position.y, position.z));
+
+        assert_eq!(
+            Some((Point3::new(-1.0, -0.5, -0.5), Point3::new(1.0, 0.5, 0.5))),
+            graph.bounds()
+        );
+
+        let offset = Vector3::new(1.0, 2.0, 3.0);
+        graph.transform(&|position: Point3<f64>| position + offset);
+
+        assert_eq!(Point3::new(1.0, 2.0, 3.0), graph.centroid().unwrap());
+        assert_eq!(8, graph.vertex_count());
+    }
+
+    #[test]
+    fn transform() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        graph.transform(&Matrix4::new_scaling(2.0));
+
+        assert_eq!(
+            Some((Point3::new(-1.0, -1.0, -1.0), Point3::new(1.0, 1.0, 1.0))),
+            graph.bounds()
+        );
+
+        graph.transform(&Isometry3::translation(1.0, 2.0, 3.0));
+
+        assert!(abs_diff_eq!(
+            Point3::new(1.0, 2.0, 3.0),
+            graph.centroid().unwrap(),
+            epsilon = 1e-9
+        ));
+        assert_eq!(8, graph.vertex_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+    }
+
+    #[test]
+    fn transform_with_user_transform() {
+        struct Reflect;
+
+        impl Transform<Point3<f64>> for Reflect {
+            fn transform(&self, position: Point3<f64>) -> Point3<f64> {
+                Point3::new(position.y, position.x, position.z)
+            }
+        }
+
+        let mut graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(1.0, 0.0, 0.0), (2.0, 0.0, 0.0), (2.0, 3.0, 0.0)],
+        )
+        .unwrap();
+        graph.transform(&Reflect);
+
+        let mut positions = graph
+            .vertices()
+            .map(|vertex| (vertex.position().x, vertex.position().y))
+            .collect::<Vec<_>>();
+        positions.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(vec![(0.0, 1.0), (0.0, 2.0), (3.0, 2.0)], positions);
+    }
+
+    #[test]
+    fn faces_in_frustum() {
+        let graph: MeshGraph<Point3<f64>> = UvSphere::new(16, 16)
+            .polygons::<Position<E3>>()
+            .collect();
+        let plane = |x, y, z, normal| Plane {
+            origin: Point3::new(x, y, z),
+            normal,
+        };
+        let mut planes = [
+            plane(-2.0, 0.0, 0.0, Unit::<Vector3<f64>>::x()),
+            plane(2.0, 0.0, 0.0, -Unit::<Vector3<f64>>::x()),
+            plane(0.0, -2.0, 0.0, Unit::<Vector3<f64>>::y()),
+            plane(0.0, 2.0, 0.0, -Unit::<Vector3<f64>>::y()),
+            plane(0.0, 0.0, -2.0, Unit::<Vector3<f64>>::z()),
+            plane(0.0, 0.0, 2.0, -Unit::<Vector3<f64>>::z()),
+        ];
+
+        assert_eq!(graph.face_count(), graph.faces_in_frustum(&planes).len());
+
+        // Cull the half of the sphere behind the $yz$-plane.
+        planes[0].origin = Point3::origin();
+        let faces = graph.faces_in_frustum(&planes);
+        let ratio = faces.len() as f64 / graph.face_count() as f64;
+
+        assert!(ratio > 0.4 && ratio < 0.6);
+        assert!(faces.iter().all(|key| graph
+            .face(*key)
+            .unwrap()
+            .adjacent_vertices()
+            .any(|vertex| vertex.position().x > -1e-9)));
+    }
+
+    #[test]
+    fn exact_size_iterators() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(8, graph.vertices().len());
+        assert_eq!(24, graph.arcs().len());
+        assert_eq!(12, graph.edges().len());
+        assert_eq!(6, graph.faces().len());
+        assert_eq!((6, Some(6)), graph.faces().size_hint());
+        let mut faces = graph.faces();
+        faces.next();
+        assert_eq!(5, faces.len());
+    }
+
+    #[test]
+    fn try_from_mesh_buffer() {
+        let buffer: MeshBuffer4<usize, E3> = Cube::new().polygons::<Position<E3>>().collect();
+        let graph = MeshGraph::<E3>::try_from(buffer).unwrap();
+        let expected: MeshGraph<E3> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(expected.vertex_count(), graph.vertex_count());
+        assert_eq!(expected.arc_count(), graph.arc_count());
+        assert_eq!(expected.face_count(), graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 4));
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+        assert!(graph.vertices().all(|vertex| vertex.valence() == 3));
+    }
+
+    #[test]
+    fn empty_aggregates() {
+        let graph = MeshGraph::<Point3<f64>>::default();
+
+        assert!(graph.aabb().is_none());
+        assert!(graph.bounds().is_none());
+        assert!(graph.centroid().is_none());
+        assert!(graph.bounding_sphere().is_none());
+        assert_eq!(0.0, graph.total_edge_length());
+        assert_eq!(0.0, graph.average_edge_length());
+        assert_eq!(Ok(0.0), graph.surface_area());
+        assert_eq!(0.0, graph.volume());
+        assert_eq!(0, graph.vertices().count());
+        assert_eq!(0, graph.edges().count());
+        assert_eq!(0, graph.faces().count());
+        assert_eq!(0, graph.vertex_normals().count());
+    }
+
+    #[test]
+    fn aggregates() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert!(graph.aabb().is_some());
+        assert_eq!(
+            Some((Point3::new(-0.5, -0.5, -0.5), Point3::new(0.5, 0.5, 0.5))),
+            graph.bounds()
+        );
+        assert_eq!(graph.bounds(), Cube::new().bounds::<Point3<f64>>());
+        assert_eq!(Point3::origin(), graph.centroid().unwrap());
+        let (_, radius) = graph.bounding_sphere().unwrap();
+        assert!(abs_diff_eq!(0.75f64.sqrt(), radius, epsilon = 1e-9));
+        assert!(abs_diff_eq!(12.0, graph.total_edge_length(), epsilon = 1e-9));
+        assert!(abs_diff_eq!(1.0, graph.average_edge_length(), epsilon = 1e-9));
+        assert!(abs_diff_eq!(6.0, graph.surface_area().unwrap(), epsilon = 1e-9));
+        assert!(abs_diff_eq!(1.0, graph.volume().abs(), epsilon = 1e-9));
+    }
+
+    #[test]
+    fn faces_with_area() {
+        let graph: MeshGraph<Point3<f64>> =
+            UvSphere::new(8, 6).polygons::<Position<E3>>().collect();
+        let areas = graph.faces_with_area().collect::<Vec<_>>();
+
+        assert_eq!(graph.face_count(), areas.len());
+        assert!(areas.iter().all(|(_, area)| *area > 0.0));
+        assert!(abs_diff_eq!(
+            graph.surface_area().unwrap(),
+            areas.iter().map(|(_, area)| area).sum::<f64>(),
+            epsilon = 1e-9
+        ));
+    }
+
+    #[test]
+    fn validate() {
+        let mut graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        assert_eq!(Ok(()), graph.validate());
+
+        // Mutate the graph and validate it again.
+        let key = graph.faces().nth(0).unwrap().key();
+        graph.face_mut(key).unwrap().extrude_with_offset(1.0).unwrap();
+        graph.triangulate();
+        let key = graph.edges().nth(0).unwrap().key();
+        graph.edge_mut(key).unwrap().split().unwrap();
+        assert_eq!(Ok(()), graph.validate());
+
+        // Corrupt the graph by removing an edge.
+        let key = graph.edges().nth(0).unwrap().key();
+        graph.core.edges.remove(&key);
+        assert_eq!(Err(GraphError::TopologyNotFound), graph.validate());
+    }
+
+    #[test]
+    fn is_manifold() {
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        assert!(graph.is_manifold());
+
+        // Open surfaces are manifolds.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2]), NGon([0, 2, 3])],
+            vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0), (0.0, 1.0, 0.0)],
+        )
+        .unwrap();
+        assert!(graph.is_manifold());
+
+        // Construct a "bowtie" of two triangles joined only at a vertex.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![NGon([0u32, 1, 2]), NGon([0, 3, 4])],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, -1.0, 0.0),
+                (1.0, 1.0, 0.0),
+                (-1.0, 1.0, 0.0),
+                (-1.0, -1.0, 0.0),
+            ],
+        )
+        .unwrap();
+        assert!(!graph.is_manifold());
+    }
+
     #[test]
     fn error_on_non_manifold() {
         // Construct a graph with a "fan" of three triangles sharing the same
@@ -1801,6 +6800,54 @@ mod tests {
         assert_eq!(graph.err().unwrap(), GraphError::TopologyConflict);
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let graph: MeshGraph<(f64, f64, f64)> = Cube::new().polygons::<Position<E3>>().collect();
+        let json = serde_json::to_string(&graph).unwrap();
+        let graph: MeshGraph<(f64, f64, f64)> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(12, graph.edge_count());
+        assert_eq!(6, graph.face_count());
+        assert_eq!(Ok(()), graph.validate());
+
+        // Face data is preserved.
+        struct Data;
+
+        impl GraphData for Data {
+            type Vertex = (f64, f64, f64);
+            type Arc = ();
+            type Edge = ();
+            type Face = u32;
+        }
+
+        let mut graph: MeshGraph<Data> = Cube::new().polygons::<Position<E3>>().collect();
+        let key = graph.faces().nth(0).unwrap().key();
+        *graph.face_mut(key).unwrap().get_mut() = 7;
+        let json = serde_json::to_string(&graph).unwrap();
+        let graph: MeshGraph<Data> = serde_json::from_str(&json).unwrap();
+        assert_eq!(1, graph.faces().filter(|face| *face.get() == 7).count());
+
+        // Faces that refer to missing vertices are rejected.
+        let json = r#"{"vertices":[[0.0,0.0,0.0],[1.0,0.0,0.0]],"faces":[[[0,1,2],null]]}"#;
+        assert!(serde_json::from_str::<MeshGraph<(f64, f64, f64)>>(json).is_err());
+    }
+
+    #[test]
+    fn send_sync() {
+        fn assert_send<T: Send>() {}
+        fn assert_sync<T: Sync>() {}
+
+        assert_send::<MeshGraph<Point3<f32>>>();
+        assert_sync::<MeshGraph<Point3<f32>>>();
+
+        let graph: MeshGraph<Point3<f64>> = Cube::new().polygons::<Position<E3>>().collect();
+        let count = std::thread::spawn(move || graph.face_count()).join().unwrap();
+
+        assert_eq!(6, count);
+    }
+
     // This test is a sanity check for circulators over orphan views and the
     // unsafe transmutations used to coerce lifetimes. It is a good target for
     // Miri, which can detect certain memory safety issues.
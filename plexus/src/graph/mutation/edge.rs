@@ -1,3 +1,6 @@
+use arrayvec::ArrayVec;
+use smallvec::SmallVec;
+use std::collections::HashSet;
 use std::ops::{Deref, DerefMut};
 
 use crate::entity::borrow::Reborrow;
@@ -14,7 +17,7 @@ use crate::graph::mutation::{Consistent, Immediate, Mode, Mutable, Mutation};
 use crate::graph::vertex::{Vertex, VertexKey, VertexView};
 use crate::graph::GraphError;
 use crate::transact::{Bypass, Transact};
-use crate::IteratorExt as _;
+use crate::{DynamicArity, IteratorExt as _};
 
 pub type CompositeEdge<G> = (Edge<G>, (Arc<G>, Arc<G>));
 pub type CompositeEdgeData<G> = (
@@ -316,6 +319,222 @@ impl EdgeSplitCache {
     }
 }
 
+pub struct EdgeSplitFacesCache {
+    cache: EdgeSplitCache,
+    faces: ArrayVec<(ArcKey, VertexKey, FaceRemoveCache), 2>,
+}
+
+impl EdgeSplitFacesCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let mut faces = ArrayVec::new();
+        for arc in [arc.to_ref(), arc.opposite_arc()] {
+            if let Some(face) = arc.face() {
+                let arity = face.arity();
+                if arity != 3 {
+                    return Err(GraphError::ArityConflict {
+                        expected: 3,
+                        actual: arity,
+                    });
+                }
+                // The vertex opposite the arc within its triangular face.
+                let c = arc.next_arc().destination_vertex().key();
+                faces.push((arc.key(), c, FaceRemoveCache::from_face(face)?));
+            }
+        }
+        Ok(EdgeSplitFacesCache {
+            cache: EdgeSplitCache::from_arc(arc)?,
+            faces,
+        })
+    }
+}
+
+pub struct EdgeFlipCache {
+    ab: ArcKey,
+    ba: ArcKey,
+    ab_ba: EdgeKey,
+    c: VertexKey,
+    d: VertexKey,
+    cache: (FaceRemoveCache, FaceRemoveCache),
+}
+
+impl EdgeFlipCache {
+    pub fn from_arc<B>(arc: ArcView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        let opposite = arc.opposite_arc();
+        let (abc, bad) = arc
+            .face()
+            .zip(opposite.face())
+            .ok_or(GraphError::TopologyNotFound)?;
+        if abc.arity() != 3 || bad.arity() != 3 {
+            return Err(GraphError::TopologyNotTriangular);
+        }
+        let c = arc.next_arc().destination_vertex().key();
+        let d = opposite.next_arc().destination_vertex().key();
+        // The flipped edge must not already exist. This also rejects the
+        // faces of two-sided triangles, where C and D are the same vertex.
+        if c == d || Rebind::<_, ArcView<_>>::rebind(arc.to_ref(), (c, d).into()).is_some() {
+            return Err(GraphError::TopologyConflict);
+        }
+        Ok(EdgeFlipCache {
+            ab: arc.key(),
+            ba: opposite.key(),
+            ab_ba: arc.edge().key(),
+            c,
+            d,
+            cache: (
+                FaceRemoveCache::from_face(abc)?,
+                FaceRemoveCache::from_face(bad)?,
+            ),
+        })
+    }
+}
+
+pub struct EdgeCollapseCache {
+    a: VertexKey,
+    b: VertexKey,
+    arcs: Vec<ArcKey>,
+    edges: Vec<EdgeKey>,
+    degenerate: ArrayVec<FaceRemoveCache, 2>,
+    faces: Vec<(SmallVec<[VertexKey; 4]>, FaceRemoveCache)>,
+    leading: Vec<(VertexKey, ArcKey)>,
+}
+
+impl EdgeCollapseCache {
+    pub fn from_arc<B>(arc: ArcView<B>, max_valence: Option<usize>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Edge<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        // The destination vertex B is removed and its faces are reinserted
+        // about the source vertex A. This cannot rebuild the connectivity of
+        // boundary arcs, so B must be an interior vertex.
+        let arc = if is_boundary_vertex(arc.destination_vertex()) {
+            arc.into_opposite_arc()
+        }
+        else {
+            arc
+        };
+        let a = arc.source_vertex();
+        let b = arc.destination_vertex();
+        if is_boundary_vertex(b.to_ref()) {
+            return Err(GraphError::TopologyConflict);
+        }
+        let mut opposites = HashSet::new();
+        let mut degenerate = ArrayVec::new();
+        for arc in [arc.to_ref(), arc.opposite_arc()] {
+            let face = arc.face().ok_or(GraphError::TopologyMalformed)?;
+            let arity = face.arity();
+            if arity != 3 {
+                return Err(GraphError::ArityConflict {
+                    expected: 3,
+                    actual: arity,
+                });
+            }
+            opposites.insert(arc.next_arc().destination_vertex().key());
+            degenerate.push(FaceRemoveCache::from_face(face)?);
+        }
+        // The only vertices adjacent to both A and B must be the vertices
+        // opposite the edge in its triangles. Otherwise, the collapse pinches
+        // the surface into a non-manifold configuration.
+        let adjacent = a.adjacent_vertices().keys().collect::<HashSet<_>>();
+        let common = b
+            .adjacent_vertices()
+            .keys()
+            .filter(|key| adjacent.contains(key))
+            .collect::<HashSet<_>>();
+        if common != opposites {
+            return Err(GraphError::TopologyConflict);
+        }
+        // The remaining vertex A is adjacent to the union of the vertices
+        // adjacent to A and B, excluding A and B themselves.
+        let valence = adjacent.len() + b.valence() - common.len() - 2;
+        if max_valence.map_or(false, |max| valence > max) {
+            return Err(GraphError::TopologyConflict);
+        }
+        // Each opposite vertex loses an edge. An interior vertex with only two
+        // remaining edges is non-manifold, as in a collapsed tetrahedron.
+        for key in opposites.iter().cloned() {
+            let vertex: VertexView<_> = arc
+                .to_ref()
+                .rebind(key)
+                .ok_or(GraphError::TopologyNotFound)?;
+            if vertex.valence() <= 3 && !is_boundary_vertex(vertex) {
+                return Err(GraphError::TopologyConflict);
+            }
+        }
+        let excluded = [arc.face(), arc.opposite_arc().face()]
+            .into_iter()
+            .flatten()
+            .keys()
+            .collect::<ArrayVec<_, 2>>();
+        let faces = b
+            .adjacent_faces()
+            .filter(|face| !excluded.contains(&face.key()))
+            .map(|face| {
+                let perimeter = face
+                    .adjacent_vertices()
+                    .keys()
+                    .map(|key| if key == b.key() { a.key() } else { key })
+                    .collect();
+                FaceRemoveCache::from_face(face).map(|cache| (perimeter, cache))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut arcs = Vec::new();
+        let mut edges = Vec::new();
+        for arc in b.outgoing_arcs() {
+            arcs.push(arc.key());
+            arcs.push(arc.opposite_arc().key());
+            edges.push(arc.edge().key());
+        }
+        // Vertices that lead with an arc into B must be connected to another
+        // outgoing arc, which cannot be incident to B.
+        let mut leading = Vec::new();
+        for vertex in b.adjacent_vertices() {
+            if vertex.arc.map_or(false, |xb| {
+                let (_, y) = xb.into();
+                y == b.key()
+            }) {
+                let xy = vertex
+                    .outgoing_arcs()
+                    .find(|arc| arc.destination_vertex().key() != b.key())
+                    .ok_or(GraphError::TopologyMalformed)?;
+                leading.push((vertex.key(), xy.key()));
+            }
+        }
+        Ok(EdgeCollapseCache {
+            a: a.key(),
+            b: b.key(),
+            arcs,
+            edges,
+            degenerate,
+            faces,
+            leading,
+        })
+    }
+}
+
 pub struct ArcBridgeCache {
     a: VertexKey,
     b: VertexKey,
@@ -468,6 +687,72 @@ where
     }
 }
 
+// Inserts an edge that is not adjacent to any faces. Unlike
+// `get_or_insert_with`, the arcs of the edge are connected to the boundaries
+// at its vertices, so this can be used to insert loose edges that are never
+// bridged or otherwise used by a face.
+pub fn get_or_insert_disjoint_with<N, P, F>(
+    mut mutation: N,
+    endpoints: (VertexKey, VertexKey),
+    f: F,
+) -> Result<CompositeEdgeKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    F: FnOnce() -> CompositeEdgeData<Data<P::Graph>>,
+{
+    // Gets a boundary arc incoming to the vertex and the next arc of that
+    // boundary arc. A vertex without arcs has no such arcs, but a vertex with
+    // arcs must have a boundary to which the edge can be connected.
+    fn boundary<N, P>(
+        mut mutation: N,
+        a: VertexKey,
+    ) -> Result<Option<(ArcKey, ArcKey)>, GraphError>
+    where
+        N: AsMut<Mutation<P>>,
+        P: Mode,
+        P::Graph: Mutable,
+    {
+        let vertex = VertexView::bind(mutation.as_mut(), a).ok_or(GraphError::TopologyNotFound)?;
+        if vertex.arc.is_none() {
+            return Ok(None);
+        }
+        vertex
+            .reachable_incoming_arcs()
+            .find(|arc| arc.face.is_none())
+            .and_then(|arc| arc.next.map(|next| (arc.key(), next)))
+            .map(Some)
+            .ok_or(GraphError::TopologyConflict)
+    }
+
+    let (a, b) = endpoints;
+    let ab = endpoints.into();
+    if mutation.as_mut().storage.0.as_storage().get(&ab).is_some() {
+        return get_or_insert_with(mutation, endpoints, f);
+    }
+    let xa = boundary(mutation.as_mut(), a)?;
+    let xb = boundary(mutation.as_mut(), b)?;
+    let (ab_ba, (ab, ba)) = get_or_insert_with(mutation.as_mut(), endpoints, f)?;
+    // Splice the arcs into the boundaries at each vertex. If a vertex has no
+    // other arcs, then the arcs of the edge are adjacent to each other.
+    if let Some((xa, ay)) = xa {
+        mutation.as_mut().connect_adjacent_arcs(xa, ab)?;
+        mutation.as_mut().connect_adjacent_arcs(ba, ay)?;
+    }
+    else {
+        mutation.as_mut().connect_adjacent_arcs(ba, ab)?;
+    }
+    if let Some((xb, bx)) = xb {
+        mutation.as_mut().connect_adjacent_arcs(xb, ba)?;
+        mutation.as_mut().connect_adjacent_arcs(ab, bx)?;
+    }
+    else {
+        mutation.as_mut().connect_adjacent_arcs(ab, ba)?;
+    }
+    Ok((ab_ba, (ab, ba)))
+}
+
 // TODO: Removing arcs must also remove disjoint vertices. More importantly, the
 //       leading arc of vertices may be invalidated by this operation and must
 //       be healed. This code does not handle these cases, and so can become
@@ -645,6 +930,154 @@ where
     Ok(m)
 }
 
+pub fn split_faces_with<N, P, F>(
+    mut mutation: N,
+    cache: EdgeSplitFacesCache,
+    f: F,
+) -> Result<VertexKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    F: FnOnce() -> <Data<P::Graph> as GraphData>::Vertex,
+{
+    let EdgeSplitFacesCache { cache, faces } = cache;
+    // Remove the incident faces before splitting the edge. Pair the topology
+    // with the face data so that it can be copied into the inserted faces.
+    let faces = faces
+        .into_iter()
+        .map(|(ab, c, cache)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (ab, c, face.data))
+        })
+        .collect::<Result<ArrayVec<_, 2>, _>>()?;
+    let m = split_with(mutation.as_mut(), cache, f)?;
+    // Connect the inserted vertex to the opposite vertex of each removed face.
+    for (ab, c, data) in faces {
+        let (a, b) = ab.into();
+        for perimeter in [[a, m, c], [m, b, c]] {
+            let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+            face::insert_with(mutation.as_mut(), cache, || {
+                (Default::default(), data.clone())
+            })?;
+        }
+    }
+    Ok(m)
+}
+
+pub fn flip<N, P>(mut mutation: N, cache: EdgeFlipCache) -> Result<ArcKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let EdgeFlipCache {
+        ab,
+        ba,
+        ab_ba,
+        c,
+        d,
+        cache,
+    } = cache;
+    let (a, b) = ab.into();
+    let abc = face::remove(mutation.as_mut(), cache.0)?;
+    let bad = face::remove(mutation.as_mut(), cache.1)?;
+    // Connect each vertex to an outgoing arc that remains after the edge is
+    // removed.
+    mutation.as_mut().connect_outgoing_arc(a, (a, d).into())?;
+    mutation.as_mut().connect_outgoing_arc(b, (b, c).into())?;
+    for ab in [ab, ba] {
+        mutation
+            .as_mut()
+            .storage
+            .0
+            .as_storage_mut()
+            .remove(&ab)
+            .ok_or(GraphError::TopologyMalformed)?;
+    }
+    mutation
+        .as_mut()
+        .storage
+        .1
+        .as_storage_mut()
+        .remove(&ab_ba)
+        .ok_or(GraphError::TopologyMalformed)?;
+    // Insert triangles that share the flipped edge between C and D.
+    for (perimeter, data) in [([c, a, d], abc.data), ([d, b, c], bad.data)] {
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+    }
+    Ok((d, c).into())
+}
+
+pub fn collapse_with<N, P, F>(
+    mut mutation: N,
+    cache: EdgeCollapseCache,
+    f: F,
+) -> Result<VertexKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+    F: FnOnce(<Data<P::Graph> as GraphData>::Vertex) -> <Data<P::Graph> as GraphData>::Vertex,
+{
+    let EdgeCollapseCache {
+        a,
+        b,
+        arcs,
+        edges,
+        degenerate,
+        faces,
+        leading,
+    } = cache;
+    // Remove the triangles incident to the edge, which degenerate when the
+    // edge is collapsed, and then the remaining faces incident to B. Pair
+    // the topology of the remaining faces with their data so that they can
+    // be reinserted.
+    for cache in degenerate {
+        face::remove(mutation.as_mut(), cache)?;
+    }
+    let faces = faces
+        .into_iter()
+        .map(|(perimeter, cache)| {
+            face::remove(mutation.as_mut(), cache).map(|face| (perimeter, face.data))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    // Remove the arcs and edges incident to B and then B itself.
+    for ab in arcs {
+        mutation
+            .as_mut()
+            .storage
+            .0
+            .as_storage_mut()
+            .remove(&ab)
+            .ok_or(GraphError::TopologyMalformed)?;
+    }
+    for ab_ba in edges {
+        mutation
+            .as_mut()
+            .storage
+            .1
+            .as_storage_mut()
+            .remove(&ab_ba)
+            .ok_or(GraphError::TopologyMalformed)?;
+    }
+    vertex::remove_disjoint(mutation.as_mut(), b)?;
+    for (x, xy) in leading {
+        mutation.as_mut().connect_outgoing_arc(x, xy)?;
+    }
+    let data = VertexView::bind(mutation.as_mut(), a)
+        .ok_or(GraphError::TopologyNotFound)?
+        .get()
+        .clone();
+    vertex::replace(mutation.as_mut(), a, f(data))?;
+    // Reinsert the faces about A.
+    for (perimeter, data) in faces {
+        let cache = FaceInsertCache::from_storage(mutation.as_mut(), &perimeter)?;
+        face::insert_with(mutation.as_mut(), cache, || (Default::default(), data))?;
+    }
+    Ok(a)
+}
+
 pub fn bridge<N, P>(mut mutation: N, cache: ArcBridgeCache) -> Result<FaceKey, GraphError>
 where
     N: AsMut<Mutation<P>>,
@@ -691,3 +1124,13 @@ where
     let cache = ArcBridgeCache::from_storage(mutation.as_mut(), ab, cd)?;
     bridge(mutation, cache).map(|_| cd)
 }
+
+fn is_boundary_vertex<B>(vertex: VertexView<B>) -> bool
+where
+    B: Reborrow,
+    B::Target: AsStorage<Arc<Data<B>>> + AsStorage<Vertex<Data<B>>> + Consistent + Parametric,
+{
+    vertex
+        .outgoing_arcs()
+        .any(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+}
@@ -440,6 +440,37 @@ impl FacePokeCache {
     }
 }
 
+pub struct FaceFlipCache {
+    vertices: Vec<VertexKey>,
+    cache: FaceRemoveCache,
+}
+
+impl FaceFlipCache {
+    pub fn from_face<B>(face: FaceView<B>) -> Result<Self, GraphError>
+    where
+        B: Reborrow,
+        B::Target: AsStorage<Arc<Data<B>>>
+            + AsStorage<Face<Data<B>>>
+            + AsStorage<Vertex<Data<B>>>
+            + Consistent
+            + Parametric,
+    {
+        // Reversing the face requires that its opposite arcs are unoccupied.
+        if face
+            .adjacent_arcs()
+            .any(|arc| arc.into_opposite_arc().face.is_some())
+        {
+            return Err(GraphError::TopologyConflict);
+        }
+        let mut vertices = face.adjacent_vertices().keys().collect::<Vec<_>>();
+        vertices.reverse();
+        Ok(FaceFlipCache {
+            vertices,
+            cache: FaceRemoveCache::from_face(face)?,
+        })
+    }
+}
+
 pub struct FaceBridgeCache {
     source: SmallVec<[ArcKey; 4]>,
     destination: SmallVec<[ArcKey; 4]>,
@@ -447,7 +478,11 @@ pub struct FaceBridgeCache {
 }
 
 impl FaceBridgeCache {
-    pub fn from_face<B>(face: FaceView<B>, destination: FaceKey) -> Result<Self, GraphError>
+    pub fn from_face<B>(
+        face: FaceView<B>,
+        destination: FaceKey,
+        offset: usize,
+    ) -> Result<Self, GraphError>
     where
         B: Reborrow,
         B::Target: AsStorage<Arc<Data<B>>>
@@ -456,6 +491,9 @@ impl FaceBridgeCache {
             + Consistent
             + Parametric,
     {
+        if face.key() == destination {
+            return Err(GraphError::TopologyConflict);
+        }
         let destination: FaceView<_> = face
             .to_ref()
             .rebind(destination)
@@ -465,12 +503,17 @@ impl FaceBridgeCache {
             FaceRemoveCache::from_face(destination.to_ref())?,
         );
         // Ensure that the opposite face exists and has the same arity.
-        if face.arity() != destination.arity() {
+        let arity = face.arity();
+        if arity != destination.arity() {
             return Err(GraphError::ArityNonUniform);
         }
+        // Rotate the arcs of the destination face to offset the
+        // correspondence of vertices between the faces.
+        let mut arcs = destination.adjacent_arcs().keys().collect::<SmallVec<[_; 4]>>();
+        arcs.rotate_left(offset % arity);
         Ok(FaceBridgeCache {
             source: face.adjacent_arcs().keys().collect(),
-            destination: destination.adjacent_arcs().keys().collect(),
+            destination: arcs,
             cache,
         })
     }
@@ -604,6 +647,18 @@ where
     Ok(c)
 }
 
+pub fn flip<N, P>(mut mutation: N, cache: FaceFlipCache) -> Result<FaceKey, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    let FaceFlipCache { vertices, cache } = cache;
+    let face = remove(mutation.as_mut(), cache)?;
+    let cache = FaceInsertCache::from_storage(mutation.as_mut(), &vertices)?;
+    insert_with(mutation.as_mut(), cache, || (Default::default(), face.data))
+}
+
 pub fn bridge<N, P>(mut mutation: N, cache: FaceBridgeCache) -> Result<(), GraphError>
 where
     N: AsMut<Mutation<P>>,
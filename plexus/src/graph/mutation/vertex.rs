@@ -1,3 +1,5 @@
+use std::mem;
+
 use crate::entity::borrow::Reborrow;
 use crate::entity::storage::prelude::*;
 use crate::entity::storage::{AsStorage, AsStorageMut, Fuse, StorageTarget};
@@ -134,6 +136,49 @@ where
         .insert(Vertex::new(data))
 }
 
+/// Replaces the data of a vertex.
+///
+/// Returns the replaced data.
+pub fn replace<N, P>(
+    mut mutation: N,
+    a: VertexKey,
+    data: <Data<P::Graph> as GraphData>::Vertex,
+) -> Result<<Data<P::Graph> as GraphData>::Vertex, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    mutation
+        .as_mut()
+        .storage
+        .as_storage_mut()
+        .get_mut(&a)
+        .map(|vertex| mem::replace(&mut vertex.data, data))
+        .ok_or(GraphError::TopologyNotFound)
+}
+
+/// Removes a vertex that has no incident arcs.
+///
+/// The leading arc of the vertex is not examined, so the caller must ensure
+/// that all arcs incident to the vertex have already been removed.
+pub fn remove_disjoint<N, P>(
+    mut mutation: N,
+    a: VertexKey,
+) -> Result<Vertex<Data<P::Graph>>, GraphError>
+where
+    N: AsMut<Mutation<P>>,
+    P: Mode,
+    P::Graph: Mutable,
+{
+    mutation
+        .as_mut()
+        .storage
+        .as_storage_mut()
+        .remove(&a)
+        .ok_or(GraphError::TopologyNotFound)
+}
+
 pub fn remove<N, P>(
     mut mutation: N,
     cache: VertexRemoveCache,
@@ -0,0 +1,235 @@
+//! Subdivision schemes.
+
+use num::{NumCast, One};
+use smallvec::SmallVec;
+use std::collections::HashMap;
+use theon::space::{EuclideanSpace, Scalar};
+use theon::AsPosition;
+
+use crate::graph::data::GraphData;
+use crate::graph::edge::EdgeView;
+use crate::graph::face::{FaceKey, FaceView};
+use crate::graph::geometry::{FaceCentroid, VertexPosition};
+use crate::graph::vertex::VertexView;
+use crate::graph::{GraphError, MeshGraph, OptionExt as _};
+
+/// Face points of a graph keyed by face.
+pub type FacePoints<G> = HashMap<FaceKey, VertexPosition<G>>;
+
+/// Subdivision scheme that determines the positions of vertices in a
+/// subdivided [`MeshGraph`].
+///
+/// Subdivision replaces each face with faces formed from the _vertex points_
+/// of its vertices, the _edge points_ of its edges, and optionally its _face
+/// point_. If a face has a face point, then it is replaced by quadrilaterals
+/// that each join a vertex point, the edge points of its adjacent edges, and
+/// the face point (as in Catmull-Clark subdivision). Otherwise, it is replaced
+/// by triangles that each join a vertex point and the edge points of its
+/// adjacent edges and a face formed from its edge points (as in Loop
+/// subdivision).
+///
+/// Face points are computed first and are given to the computation of edge
+/// and vertex points. Schemes are applied via [`MeshGraph::subdivide_with`].
+///
+/// [`MeshGraph`]: crate::graph::MeshGraph
+/// [`MeshGraph::subdivide_with`]: crate::graph::MeshGraph::subdivide_with
+pub trait SubdivisionScheme<G>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    /// Determines whether or not the scheme can be applied to a graph.
+    ///
+    /// Returns an error if the scheme cannot be applied. If an error is
+    /// returned, then the graph is not modified.
+    fn validate(graph: &MeshGraph<G>) -> Result<(), GraphError> {
+        let _ = graph;
+        Ok(())
+    }
+
+    /// Gets the face point of a face, if any.
+    fn face_point(face: FaceView<&MeshGraph<G>>) -> Option<VertexPosition<G>>;
+
+    /// Gets the edge point of an edge.
+    fn edge_point(edge: EdgeView<&MeshGraph<G>>, faces: &FacePoints<G>) -> VertexPosition<G>;
+
+    /// Gets the vertex point of a vertex.
+    ///
+    /// This is the position of the vertex in the subdivided graph.
+    fn vertex_point(
+        vertex: VertexView<&MeshGraph<G>>,
+        faces: &FacePoints<G>,
+    ) -> VertexPosition<G>;
+}
+
+/// Catmull-Clark subdivision scheme.
+///
+/// See [`MeshGraph::subdivide_catmull_clark`].
+///
+/// [`MeshGraph::subdivide_catmull_clark`]: crate::graph::MeshGraph::subdivide_catmull_clark
+pub enum CatmullClark {}
+
+impl<G> SubdivisionScheme<G> for CatmullClark
+where
+    G: FaceCentroid,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    fn validate(graph: &MeshGraph<G>) -> Result<(), GraphError> {
+        if graph
+            .vertices()
+            .any(|vertex| vertex.adjacent_faces().next().is_none())
+        {
+            Err(GraphError::TopologyMalformed)
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    fn face_point(face: FaceView<&MeshGraph<G>>) -> Option<VertexPosition<G>> {
+        Some(face.centroid())
+    }
+
+    fn edge_point(edge: EdgeView<&MeshGraph<G>>, faces: &FacePoints<G>) -> VertexPosition<G> {
+        let arc = edge.arc();
+        let a = *arc.source_vertex().position();
+        let b = *arc.destination_vertex().position();
+        match (arc.face(), arc.opposite_arc().face()) {
+            (Some(left), Some(right)) => centroid(&[a, b, faces[&left.key()], faces[&right.key()]]),
+            _ => centroid(&[a, b]),
+        }
+    }
+
+    fn vertex_point(
+        vertex: VertexView<&MeshGraph<G>>,
+        faces: &FacePoints<G>,
+    ) -> VertexPosition<G> {
+        let reciprocal = |n: usize| {
+            Scalar::<VertexPosition<G>>::one()
+                / <Scalar<VertexPosition<G>> as NumCast>::from(n).expect_consistent()
+        };
+        let position = *vertex.position();
+        let boundary = boundary_positions(vertex);
+        match boundary.len() {
+            0 => {
+                // Given a valence $n$, the mean $Q$ of adjacent face points,
+                // and the mean $R$ of adjacent edge midpoints, the position
+                // becomes $\frac{Q+2R+(n-3)P}{n}$.
+                let q = centroid(
+                    &vertex
+                        .adjacent_faces()
+                        .map(|face| faces[&face.key()])
+                        .collect::<Vec<_>>(),
+                );
+                let r = centroid(
+                    &vertex
+                        .adjacent_vertices()
+                        .map(|vertex| *vertex.position())
+                        .collect::<Vec<_>>(),
+                );
+                position + (((q - position) + (r - position)) * reciprocal(vertex.valence()))
+            }
+            2 => position + ((centroid(&boundary) - position) * reciprocal(4)),
+            // Vertices joining more than one boundary are not moved.
+            _ => position,
+        }
+    }
+}
+
+/// Loop subdivision scheme.
+///
+/// See [`MeshGraph::subdivide_loop`].
+///
+/// [`MeshGraph::subdivide_loop`]: crate::graph::MeshGraph::subdivide_loop
+pub enum Loop {}
+
+impl<G> SubdivisionScheme<G> for Loop
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    fn validate(graph: &MeshGraph<G>) -> Result<(), GraphError> {
+        if graph.faces().any(|face| face.arity() != 3) {
+            Err(GraphError::TopologyNotTriangular)
+        }
+        else {
+            Ok(())
+        }
+    }
+
+    fn face_point(_: FaceView<&MeshGraph<G>>) -> Option<VertexPosition<G>> {
+        None
+    }
+
+    fn edge_point(edge: EdgeView<&MeshGraph<G>>, _: &FacePoints<G>) -> VertexPosition<G> {
+        let arc = edge.arc();
+        let a = *arc.source_vertex().position();
+        let b = *arc.destination_vertex().position();
+        let m = centroid(&[a, b]);
+        if arc.face().is_some() && arc.opposite_arc().face().is_some() {
+            let c = *arc.next_arc().destination_vertex().position();
+            let d = *arc.opposite_arc().next_arc().destination_vertex().position();
+            m + ((centroid(&[c, d]) - m) * into_scalar::<G>(0.25))
+        }
+        else {
+            m
+        }
+    }
+
+    fn vertex_point(vertex: VertexView<&MeshGraph<G>>, _: &FacePoints<G>) -> VertexPosition<G> {
+        let position = *vertex.position();
+        let boundary = boundary_positions(vertex);
+        match boundary.len() {
+            0 => {
+                // Expressed as a translation toward the mean $Q$ of adjacent
+                // vertices, the position becomes $P+n\beta(Q-P)$.
+                let n = vertex.valence() as f64;
+                let beta = (0.625
+                    - (0.375 + (0.25 * (2.0 * std::f64::consts::PI / n).cos())).powi(2))
+                    / n;
+                let q = centroid(
+                    &vertex
+                        .adjacent_vertices()
+                        .map(|vertex| *vertex.position())
+                        .collect::<Vec<_>>(),
+                );
+                position + ((q - position) * into_scalar::<G>(n * beta))
+            }
+            2 => position + ((centroid(&boundary) - position) * into_scalar::<G>(0.25)),
+            // Vertices joining more than one boundary are not moved.
+            _ => position,
+        }
+    }
+}
+
+// Gets the positions of the vertices adjacent to a vertex along boundaries.
+fn boundary_positions<G>(vertex: VertexView<&MeshGraph<G>>) -> SmallVec<[VertexPosition<G>; 2]>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+{
+    vertex
+        .outgoing_arcs()
+        .filter(|arc| arc.is_boundary_arc() || arc.opposite_arc().is_boundary_arc())
+        .map(|arc| *arc.destination_vertex().position())
+        .collect()
+}
+
+fn centroid<S>(positions: &[S]) -> S
+where
+    S: EuclideanSpace,
+{
+    S::centroid(positions.iter().cloned()).expect_consistent()
+}
+
+fn into_scalar<G>(x: f64) -> Scalar<VertexPosition<G>>
+where
+    G: GraphData,
+    G::Vertex: AsPosition,
+    VertexPosition<G>: EuclideanSpace,
+{
+    <Scalar<VertexPosition<G>> as NumCast>::from(x).expect_consistent()
+}
@@ -2,6 +2,8 @@ use derivative::Derivative;
 use smallvec::SmallVec;
 use std::borrow::Borrow;
 use std::hash::{Hash, Hasher};
+use std::iter;
+use std::mem;
 use std::ops::{Deref, DerefMut};
 use theon::space::Vector;
 use theon::AsPosition;
@@ -17,7 +19,7 @@ use crate::entity::view::{ClosedView, Orphan, Rebind, Unbind, View};
 use crate::entity::{Entity, Payload};
 use crate::geometry::Metric;
 use crate::graph::data::{Data, GraphData, Parametric};
-use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge};
+use crate::graph::edge::{Arc, ArcKey, ArcOrphan, ArcView, Edge, EdgeView};
 use crate::graph::face::{Face, FaceOrphan, FaceView};
 use crate::graph::geometry::{VertexCentroid, VertexNormal, VertexPosition};
 use crate::graph::mutation::vertex::{self, VertexRemoveCache};
@@ -201,6 +203,11 @@ where
     {
         self.inner.get_mut()
     }
+
+    /// Replaces the data of the vertex and returns the previous data.
+    pub fn set_geometry(&mut self, data: G::Vertex) -> G::Vertex {
+        mem::replace(self.get_mut(), data)
+    }
 }
 
 /// Reachable API.
@@ -302,6 +309,22 @@ where
         + Parametric<Data = G>,
     G: GraphData,
 {
+    /// Gets the normal of the vertex.
+    ///
+    /// If the vertex data has a normal (see [`GraphData::vertex_normal`]),
+    /// then that normal is normalized and returned. Such normals are not
+    /// updated as the graph is modified. Otherwise, the normal is the mean of
+    /// the normals of the faces adjacent to the vertex weighted by the areas
+    /// of those faces and is normalized. Degenerate faces, which have no
+    /// normal, are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the normal in the vertex data has zero length or if
+    /// the normal is computed and the vertex has no non-degenerate adjacent
+    /// faces or the normal cannot be computed.
+    ///
+    /// [`GraphData::vertex_normal`]: crate::graph::GraphData::vertex_normal
     pub fn normal(&self) -> Result<Vector<VertexPosition<G>>, GraphError>
     where
         G: VertexNormal,
@@ -453,6 +476,65 @@ where
     }
 }
 
+impl<'a, B, M, G> VertexView<B>
+where
+    B: ReborrowInto<'a, Target = M>,
+    M: 'a
+        + AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Converts the vertex into an iterator of views over the edges of its
+    /// link.
+    ///
+    /// See [`VertexView::link`].
+    ///
+    /// [`VertexView::link`]: crate::graph::VertexView::link
+    pub fn into_link(self) -> impl Clone + Iterator<Item = EdgeView<&'a M>> {
+        let key = self.key();
+        self.into_outgoing_arcs()
+            .filter(|arc| !arc.is_boundary_arc())
+            .flat_map(move |arc| {
+                // Walk the ring of the face from the destination of the
+                // outgoing arc until the vertex is reached again.
+                iter::successors(Some(arc.into_next_arc()), |arc| Some(arc.into_next_arc()))
+                    .take_while(move |arc| arc.destination_vertex().key() != key)
+                    .map(|arc| arc.into_edge())
+            })
+    }
+}
+
+impl<B, G> VertexView<B>
+where
+    B: Reborrow,
+    B::Target: AsStorage<Arc<G>>
+        + AsStorage<Edge<G>>
+        + AsStorage<Face<G>>
+        + AsStorage<Vertex<G>>
+        + Consistent
+        + Parametric<Data = G>,
+    G: GraphData,
+{
+    /// Gets an iterator of views over the edges of the link of the vertex.
+    ///
+    /// The link is the boundary of the one-ring of the vertex: for each
+    /// adjacent face, these are the edges of the face that are not incident to
+    /// the vertex. For a triangle, this is the single edge opposite the vertex.
+    /// For a face with arity $n$, $n-2$ edges are yielded. The link of an
+    /// interior vertex of a manifold forms a closed loop while the link of a
+    /// boundary vertex forms an open path.
+    ///
+    /// The ordering of edges is deterministic and is based on the leading arc
+    /// of the vertex.
+    pub fn link(&self) -> impl Clone + Iterator<Item = EdgeView<&B::Target>> {
+        self.to_ref().into_link()
+    }
+}
+
 impl<'a, M> VertexView<&'a mut M>
 where
     M: AsStorage<Arc<M::Data>> + AsStorageMut<Vertex<M::Data>> + Consistent + Parametric,
@@ -887,6 +969,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.bind_next_view()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.lower_bound(), None)
+    }
 }
 
 impl<'a, M> Iterator for VertexCirculator<TraceAny<ArcKey>, &'a mut M>
@@ -898,6 +984,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.lower_bound(), None)
+    }
 }
 
 impl<'a, M> Iterator for VertexCirculator<TraceFirst<ArcKey>, &'a mut M>
@@ -909,6 +999,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.inner.lower_bound(), None)
+    }
 }
 
 impl<'a, P, M> OrphanCirculator<'a, M> for VertexCirculator<P, &'a mut M>
@@ -940,6 +1034,22 @@ where
     storage: B,
     outgoing: Option<ArcKey>,
     trace: P,
+    visited: bool,
+}
+
+impl<P, B, M, G> ArcCirculator<P, B>
+where
+    P: Trace<ArcKey>,
+    B: Reborrow<Target = M>,
+    M: AsStorage<Arc<G>> + Parametric<Data = G>,
+    G: GraphData,
+{
+    // A vertex with a leading arc in consistent storage has at least one
+    // incoming arc. The degree of the vertex is not known without walking
+    // about it, so no upper bound is given.
+    fn lower_bound(&self) -> usize {
+        usize::from(!self.visited && self.outgoing.is_some())
+    }
 }
 
 impl<P, B, M, G> Circulator<B> for ArcCirculator<P, B>
@@ -964,6 +1074,7 @@ where
                     .map(|outgoing| (incoming, outgoing))
             })
             .map(|(incoming, outgoing)| {
+                self.visited = true;
                 self.outgoing = outgoing;
                 incoming
             })
@@ -982,6 +1093,7 @@ where
             storage: self.storage.clone(),
             outgoing: self.outgoing,
             trace: self.trace.clone(),
+            visited: self.visited,
         }
     }
 }
@@ -1000,6 +1112,7 @@ where
             storage,
             outgoing: key,
             trace: Default::default(),
+            visited: false,
         }
     }
 }
@@ -1014,6 +1127,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.bind_next_view()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.lower_bound(), None)
+    }
 }
 
 impl<'a, M> Iterator for ArcCirculator<TraceAny<ArcKey>, &'a mut M>
@@ -1025,6 +1142,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.lower_bound(), None)
+    }
 }
 
 impl<'a, M> Iterator for ArcCirculator<TraceFirst<ArcKey>, &'a mut M>
@@ -1036,6 +1157,10 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         unsafe { self.bind_next_orphan() }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.lower_bound(), None)
+    }
 }
 
 impl<'a, P, M> OrphanCirculator<'a, M> for ArcCirculator<P, &'a mut M>
@@ -1181,7 +1306,8 @@ where
 #[cfg(test)]
 mod tests {
     use decorum::R64;
-    use nalgebra::{Point2, Point3};
+    use nalgebra::{Point2, Point3, Vector3};
+    use std::collections::HashMap;
 
     use crate::graph::{GraphError, MeshGraph};
     use crate::prelude::*;
@@ -1192,6 +1318,103 @@ mod tests {
 
     type E3 = Point3<R64>;
 
+    #[test]
+    fn area_weighted_normal() {
+        // Construct a graph with a large triangle in the $xy$-plane and a
+        // smaller triangle inclined toward $x$.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (2.0, 0.0, 0.0),
+                (0.0, 2.0, 0.0),
+                (-1.0, 0.0, 1.0),
+            ],
+        )
+        .unwrap();
+        let vertex = graph
+            .vertices()
+            .find(|vertex| *vertex.position() == Point3::origin())
+            .unwrap();
+        let normal = vertex.normal().unwrap();
+
+        // The normals $(0,0,1)$ and $\frac{1}{\sqrt{2}}(1,0,1)$ are weighted
+        // by the areas $2$ and $\sqrt{2}$, respectively.
+        let n = 10.0f64.sqrt();
+        assert!((normal.x - (1.0 / n)).abs() < 1e-9);
+        assert!(normal.y.abs() < 1e-9);
+        assert!((normal.z - (3.0 / n)).abs() < 1e-9);
+        assert_eq!(4, graph.vertex_normals().count());
+    }
+
+    #[test]
+    fn normal_skip_degenerate_faces() {
+        // Construct a graph with a triangle in the $xy$-plane and a degenerate
+        // triangle with collinear vertices.
+        let graph = MeshGraph::<Point3<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2), Trigon::new(0, 2, 3)],
+            vec![
+                (0.0, 0.0, 0.0),
+                (1.0, 0.0, 0.0),
+                (0.0, 1.0, 0.0),
+                (0.0, 2.0, 0.0),
+            ],
+        )
+        .unwrap();
+        let normals = graph.compute_vertex_normals();
+
+        // The normals of the vertices shared with the degenerate triangle are
+        // not affected by it.
+        assert_eq!(3, normals.len());
+        for (key, normal) in normals {
+            assert_eq!(Vector3::z(), normal);
+            assert!(graph.vertex(key).unwrap().position().y < 2.0);
+        }
+    }
+
+    #[test]
+    fn link() {
+        let graph: MeshGraph<E3> = UvSphere::new(8, 8).polygons::<Position<E3>>().collect();
+        // Interior (non-polar) vertices of the sphere are adjacent to four
+        // quadrilaterals, each of which contributes two edges to the link.
+        let vertex = graph
+            .vertices()
+            .find(|vertex| vertex.adjacent_faces().all(|face| face.arity() == 4))
+            .unwrap();
+        let edges = vertex.link().collect::<Vec<_>>();
+        assert_eq!(8, edges.len());
+
+        // The link forms a single closed loop: each vertex of the link is an
+        // endpoint of exactly two of its edges, no edge is incident to the
+        // vertex, and walking the loop visits every vertex of the link before
+        // returning to where it began.
+        let mut adjacency = HashMap::<_, Vec<_>>::new();
+        for edge in edges.iter() {
+            let arc = edge.arc();
+            let (a, b) = (arc.source_vertex().key(), arc.destination_vertex().key());
+            assert_ne!(vertex.key(), a);
+            assert_ne!(vertex.key(), b);
+            adjacency.entry(a).or_default().push(b);
+            adjacency.entry(b).or_default().push(a);
+        }
+        assert_eq!(8, adjacency.len());
+        assert!(adjacency.values().all(|keys| keys.len() == 2));
+        let start = *adjacency.keys().next().unwrap();
+        let (mut previous, mut current) = (start, adjacency[&start][0]);
+        let mut n = 1;
+        while current != start {
+            let next = adjacency[&current]
+                .iter()
+                .cloned()
+                .find(|key| *key != previous)
+                .unwrap();
+            previous = current;
+            current = next;
+            n += 1;
+        }
+        assert_eq!(8, n);
+    }
+
     #[test]
     fn circulate_over_arcs() {
         let graph: MeshGraph<E3> = UvSphere::new(4, 2)
@@ -1202,6 +1425,9 @@ mod tests {
         // (incoming) arcs.
         for vertex in graph.vertices() {
             assert_eq!(4, vertex.incoming_arcs().count());
+            assert_eq!((1, None), vertex.incoming_arcs().size_hint());
+            assert_eq!((1, None), vertex.adjacent_vertices().size_hint());
+            assert_eq!((0, None), vertex.adjacent_faces().size_hint());
         }
     }
 
@@ -1265,4 +1491,20 @@ mod tests {
         let vertex = graph.vertices().nth(0).unwrap();
         assert_eq!(graph.vertex_count(), vertex.traverse_by_depth().count());
     }
+
+    #[test]
+    fn set_vertex_data() {
+        let mut graph = MeshGraph::<Point2<f64>>::from_raw_buffers(
+            vec![Trigon::new(0usize, 1, 2)],
+            vec![(-1.0, 0.0), (0.0, 1.0), (1.0, 0.0)],
+        )
+        .unwrap();
+        let key = graph.vertices().nth(0).unwrap().key();
+        let mut vertex = graph.vertex_mut(key).unwrap();
+        let previous = *vertex.get();
+
+        assert_eq!(previous, vertex.set_geometry(Point2::new(0.0, -1.0)));
+        assert_eq!(Point2::new(0.0, -1.0), *vertex.get());
+        assert_eq!(Point2::new(0.0, -1.0), graph.vertex(key).unwrap().data);
+    }
 }
@@ -77,14 +77,17 @@
 //! [`UnboundedPolygon`]: crate::primitive::UnboundedPolygon
 //! [`primitive`]: crate::primitive
 
-use num::{Integer, NumCast, Unsigned};
+use num::{Integer, NumCast, ToPrimitive, Unsigned};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
 use std::cmp;
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::fmt::Debug;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use theon::adjunct::Map;
-use typenum::NonZero;
+use theon::space::{EuclideanSpace, FiniteDimensional, InnerSpace, Scalar};
+use typenum::{NonZero, U3};
 
 use crate::constant::{Constant, ToType, TypeOf};
 use crate::primitive::decompose::IntoVertices;
@@ -301,6 +304,21 @@ where
         F: Fn(&T::Vertex) -> &K;
 }
 
+// This allows an indexer to be inspected after it has been used, such as with
+// `LruIndexer::overflow_count`.
+impl<'a, T, K, N> Indexer<T, K> for &'a mut N
+where
+    T: Topological,
+    N: Indexer<T, K>,
+{
+    fn index<F>(&mut self, vertex: T::Vertex, f: F) -> (usize, Option<T::Vertex>)
+    where
+        F: Fn(&T::Vertex) -> &K,
+    {
+        (**self).index(vertex, f)
+    }
+}
+
 /// Hashing vertex indexer.
 ///
 /// This indexer hashes key data for vertices to form an index. This is fast,
@@ -429,6 +447,8 @@ where
     lru: Vec<(K, usize)>,
     capacity: usize,
     n: usize,
+    evicted: Option<Vec<K>>,
+    overflow: usize,
     phantom: PhantomData<fn() -> T>,
 }
 
@@ -445,17 +465,74 @@ where
     /// Creates a new `LruIndexer` with the specified capacity.
     ///
     /// The capacity of the cache must be sufficient in order to generate a
-    /// unique set of index and vertex data.
+    /// unique set of index and vertex data. A vertex is found in the cache if
+    /// and only if fewer than `capacity` distinct other vertices have been
+    /// indexed since it was last indexed. Otherwise, its entry has been
+    /// evicted and the vertex is emitted again with a new index. See
+    /// [`LruIndexer::with_overflow_warning`] to detect an inadequate capacity.
+    ///
+    /// [`LruIndexer::with_overflow_warning`]: crate::index::LruIndexer::with_overflow_warning
     pub fn with_capacity(capacity: usize) -> Self {
         let capacity = cmp::max(1, capacity);
         LruIndexer {
             lru: Vec::with_capacity(capacity),
             capacity,
             n: 0,
+            evicted: None,
+            overflow: 0,
             phantom: PhantomData,
         }
     }
 
+    /// Creates a new `LruIndexer` with the specified capacity that counts
+    /// overflows.
+    ///
+    /// An overflow occurs when a vertex is emitted again because its entry was
+    /// evicted from the cache, producing redundant vertex data. The number of
+    /// overflows can be queried with [`LruIndexer::overflow_count`]. Evicted
+    /// keys are retained to detect overflows, so this should only be used to
+    /// diagnose an inadequate capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::index::{Flat3, LruIndexer};
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::generate::Position;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// let mut indexer = LruIndexer::with_overflow_warning(64);
+    /// let (indices, positions) = UvSphere::new(8, 8)
+    ///     .polygons::<Position<Point3<f64>>>()
+    ///     .triangulate()
+    ///     .index_vertices::<Flat3, _>(&mut indexer);
+    ///
+    /// assert_eq!(0, indexer.overflow_count());
+    /// ```
+    ///
+    /// [`LruIndexer::overflow_count`]: crate::index::LruIndexer::overflow_count
+    pub fn with_overflow_warning(capacity: usize) -> Self {
+        LruIndexer {
+            evicted: Some(Vec::new()),
+            ..LruIndexer::with_capacity(capacity)
+        }
+    }
+
+    /// Gets the number of vertices that have been emitted again because their
+    /// entries were evicted from the cache.
+    ///
+    /// This is always zero unless the indexer was created with
+    /// [`LruIndexer::with_overflow_warning`].
+    ///
+    /// [`LruIndexer::with_overflow_warning`]: crate::index::LruIndexer::with_overflow_warning
+    pub fn overflow_count(&self) -> usize {
+        self.overflow
+    }
+
     fn find(&self, key: &K) -> Option<(usize, usize)> {
         self.lru
             .iter()
@@ -495,8 +572,17 @@ where
             vertex = Some(input);
             let m = self.n;
             self.n += 1;
+            if let Some(evicted) = self.evicted.as_mut() {
+                if let Some(index) = evicted.iter().position(|evicted| *evicted == key) {
+                    evicted.swap_remove(index);
+                    self.overflow += 1;
+                }
+            }
             if self.lru.len() >= self.capacity {
-                self.lru.remove(0);
+                let (evicted, _) = self.lru.remove(0);
+                if let Some(keys) = self.evicted.as_mut() {
+                    keys.push(evicted);
+                }
             }
             self.lru.push((key, m));
             m
@@ -505,6 +591,314 @@ where
     }
 }
 
+/// Hashing LRU caching vertex indexer.
+///
+/// This indexer behaves like [`LruIndexer`], but also hashes key data to find
+/// entries in its cache. Finding an entry takes constant time regardless of
+/// the capacity of the cache, so this indexer is much faster than
+/// [`LruIndexer`] when a large capacity is needed. Entries are evicted in the
+/// same order as [`LruIndexer`].
+///
+/// The vertex key data must implement [`Hash`]. If the key data cannot be
+/// hashed, use [`LruIndexer`] instead.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::index::{Flat3, HashLruIndexer};
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// let (indices, positions) = UvSphere::new(64, 64)
+///     .polygons::<Position<Point3<R64>>>()
+///     .triangulate()
+///     .index_vertices::<Flat3, _>(HashLruIndexer::with_capacity(1024));
+/// ```
+///
+/// [`Hash`]: std::hash::Hash
+/// [`LruIndexer`]: crate::index::LruIndexer
+pub struct HashLruIndexer<T, K>
+where
+    T: Topological,
+    K: Clone + Eq + Hash,
+{
+    // Maps keys to their indices and the time at which they were last used.
+    hash: HashMap<K, (usize, u64)>,
+    // Keys in the order that they were used. Entries are stale if their time
+    // differs from the time in `hash` and are removed lazily.
+    queue: VecDeque<(K, u64)>,
+    capacity: usize,
+    time: u64,
+    n: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, K> HashLruIndexer<T, K>
+where
+    T: Topological,
+    K: Clone + Eq + Hash,
+{
+    /// Creates a new `HashLruIndexer` with a default capacity.
+    pub fn new() -> Self {
+        HashLruIndexer::with_capacity(16)
+    }
+
+    /// Creates a new `HashLruIndexer` with the specified capacity.
+    ///
+    /// The capacity of the cache must be sufficient in order to generate a
+    /// unique set of index and vertex data.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = cmp::max(1, capacity);
+        HashLruIndexer {
+            hash: HashMap::with_capacity(capacity),
+            queue: VecDeque::with_capacity(capacity),
+            capacity,
+            time: 0,
+            n: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    fn is_current(hash: &HashMap<K, (usize, u64)>, entry: &(K, u64)) -> bool {
+        matches!(hash.get(&entry.0), Some(&(_, time)) if time == entry.1)
+    }
+
+    fn evict(&mut self) {
+        while let Some(entry) = self.queue.pop_front() {
+            if Self::is_current(&self.hash, &entry) {
+                self.hash.remove(&entry.0);
+                break;
+            }
+        }
+    }
+
+    fn compact(&mut self) {
+        // Stale entries accumulate as keys are used, so remove them once the
+        // queue is much larger than the cache.
+        if self.queue.len() > self.capacity * 4 {
+            let hash = &self.hash;
+            self.queue.retain(|entry| Self::is_current(hash, entry));
+        }
+    }
+}
+
+impl<T, K> Default for HashLruIndexer<T, K>
+where
+    T: Topological,
+    K: Clone + Eq + Hash,
+{
+    fn default() -> Self {
+        HashLruIndexer::new()
+    }
+}
+
+impl<T, K> Indexer<T, K> for HashLruIndexer<T, K>
+where
+    T: Topological,
+    K: Clone + Eq + Hash,
+{
+    fn index<F>(&mut self, input: T::Vertex, f: F) -> (usize, Option<T::Vertex>)
+    where
+        F: Fn(&T::Vertex) -> &K,
+    {
+        let mut vertex = None;
+        let key = f(&input).clone();
+        self.time += 1;
+        let index = if let Some(entry) = self.hash.get_mut(&key) {
+            entry.1 = self.time;
+            entry.0
+        }
+        else {
+            vertex = Some(input);
+            let m = self.n;
+            self.n += 1;
+            if self.hash.len() >= self.capacity {
+                self.evict();
+            }
+            self.hash.insert(key.clone(), (m, self.time));
+            m
+        };
+        self.queue.push_back((key, self.time));
+        self.compact();
+        (index, vertex)
+    }
+}
+
+/// Spatial vertex indexer.
+///
+/// This indexer merges vertices with positions that are within a given
+/// distance of each other, which is useful for welding near-coincident
+/// vertices in noisy data. Positions are bucketed into a grid with cells as
+/// wide as the tolerance, so only neighboring cells are examined when
+/// indexing a vertex. If more than one previously indexed position is within
+/// the tolerance, then the nearest is used.
+///
+/// The vertex key data must be a three-dimensional Euclidean position. Unlike
+/// [`HashIndexer`], the key data need not implement [`Hash`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use nalgebra::Point3;
+/// use plexus::index::{Flat3, SpatialIndexer};
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// let (indices, positions) = UvSphere::new(8, 8)
+///     .polygons::<Position<Point3<f64>>>()
+///     .triangulate()
+///     .index_vertices::<Flat3, _>(SpatialIndexer::with_tolerance(1e-6));
+/// ```
+///
+/// [`Hash`]: std::hash::Hash
+/// [`HashIndexer`]: crate::index::HashIndexer
+pub struct SpatialIndexer<T, K>
+where
+    T: Topological,
+    K: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    grid: HashMap<(i64, i64, i64), Vec<(K, usize)>>,
+    epsilon: f64,
+    n: usize,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T, K> SpatialIndexer<T, K>
+where
+    T: Topological,
+    K: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    /// Creates a new `SpatialIndexer` that merges positions within the given
+    /// distance of each other.
+    ///
+    /// The tolerance is clamped to a small positive value.
+    pub fn with_tolerance(epsilon: f64) -> Self {
+        SpatialIndexer {
+            grid: HashMap::new(),
+            epsilon: epsilon.max(f64::EPSILON),
+            n: 0,
+            phantom: PhantomData,
+        }
+    }
+
+    fn cell(&self, key: &K) -> (i64, i64, i64) {
+        let (x, y, z) = key.into_xyz();
+        let cell = |x: Scalar<K>| (x.to_f64().unwrap_or(0.0) / self.epsilon).floor() as i64;
+        (cell(x), cell(y), cell(z))
+    }
+
+    fn find(&self, key: &K) -> Option<usize> {
+        let (x, y, z) = self.cell(key);
+        (-1..=1)
+            .flat_map(|i| (-1..=1).flat_map(move |j| (-1..=1).map(move |k| (i, j, k))))
+            .flat_map(|(i, j, k)| self.grid.get(&(x + i, y + j, z + k)).into_iter().flatten())
+            .map(|(position, index)| {
+                let distance = (*position - *key).magnitude().to_f64().unwrap_or(f64::MAX);
+                (distance, *index)
+            })
+            .filter(|(distance, _)| *distance <= self.epsilon)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, index)| index)
+    }
+}
+
+impl<T, K> Indexer<T, K> for SpatialIndexer<T, K>
+where
+    T: Topological,
+    K: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    fn index<F>(&mut self, input: T::Vertex, f: F) -> (usize, Option<T::Vertex>)
+    where
+        F: Fn(&T::Vertex) -> &K,
+    {
+        let key = *f(&input);
+        if let Some(index) = self.find(&key) {
+            (index, None)
+        }
+        else {
+            let m = self.n;
+            self.n += 1;
+            let cell = self.cell(&key);
+            self.grid.entry(cell).or_default().push((key, m));
+            (m, Some(input))
+        }
+    }
+}
+
+/// Statistics describing the vertices indexed by an [`Indexer`].
+///
+/// See [`CollectWithIndexer::collect_with_stats`].
+///
+/// [`CollectWithIndexer::collect_with_stats`]: crate::index::CollectWithIndexer::collect_with_stats
+/// [`Indexer`]: crate::index::Indexer
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct IndexStats {
+    input: usize,
+    unique: usize,
+}
+
+impl IndexStats {
+    /// Gets the number of input vertices given to the indexer.
+    pub fn input(&self) -> usize {
+        self.input
+    }
+
+    /// Gets the number of unique vertices emitted by the indexer.
+    pub fn unique(&self) -> usize {
+        self.unique
+    }
+
+    /// Gets the number of input vertices that were merged into a previously
+    /// emitted vertex.
+    pub fn merged(&self) -> usize {
+        self.input - self.unique
+    }
+
+    /// Gets the ratio of input vertices to unique vertices.
+    ///
+    /// For example, a ratio of `3.0` indicates that each unique vertex was
+    /// shared by three input vertices on average. Returns `None` if no
+    /// vertices have been indexed.
+    pub fn ratio(&self) -> Option<f64> {
+        (self.unique > 0).then(|| self.input as f64 / self.unique as f64)
+    }
+}
+
+/// Indexer that records [`IndexStats`] for another indexer.
+struct Tally<'a, N> {
+    indexer: N,
+    stats: &'a mut IndexStats,
+}
+
+impl<'a, T, K, N> Indexer<T, K> for Tally<'a, N>
+where
+    T: Topological,
+    N: Indexer<T, K>,
+{
+    fn index<F>(&mut self, input: T::Vertex, f: F) -> (usize, Option<T::Vertex>)
+    where
+        F: Fn(&T::Vertex) -> &K,
+    {
+        let (index, vertex) = self.indexer.index(input, f);
+        self.stats.input += 1;
+        if vertex.is_some() {
+            self.stats.unique += 1;
+        }
+        (index, vertex)
+    }
+}
+
 /// Functions for collecting an iterator of $n$-gons into raw index and vertex
 /// buffers.
 ///
@@ -657,6 +1051,142 @@ where
 {
 }
 
+/// Parallel functions for collecting an iterator of $n$-gons into raw index and
+/// vertex buffers.
+///
+/// This trait is only available when the `rayon` feature is enabled.
+///
+/// The $n$-gons are partitioned and each partition is indexed independently
+/// on a thread pool with a [`HashIndexer`]. The partial results are then
+/// merged and indices are remapped in a final pass. The output is identical
+/// to that of [`IndexVertices::index_vertices`] with a [`HashIndexer`]: vertices
+/// are ordered by their first occurrence in the input.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::Point3;
+/// use plexus::index::{Flat3, ParallelIndexVertices};
+/// use plexus::prelude::*;
+/// use plexus::primitive::generate::Position;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// let (indices, positions) = UvSphere::new(64, 64)
+///     .polygons::<Position<Point3<R64>>>()
+///     .triangulate()
+///     .par_index_vertices::<Flat3>();
+/// ```
+///
+/// [`HashIndexer`]: crate::index::HashIndexer
+/// [`IndexVertices::index_vertices`]: crate::index::IndexVertices::index_vertices
+#[cfg(feature = "rayon")]
+pub trait ParallelIndexVertices<P>
+where
+    P: Topological,
+{
+    /// Indexes an iterator of $n$-gons into raw index and vertex buffers using
+    /// the given grouping.
+    fn par_index_vertices<R>(self) -> (Vec<R::Group>, Vec<P::Vertex>)
+    where
+        R: Grouping,
+        R::Group: Send,
+        P: Map<IndexOf<R>>,
+        P::Output: Topological<Vertex = IndexOf<R>>,
+        BufferOf<R>: Push<R, P::Output>,
+        IndexOf<R>: NumCast;
+}
+
+#[cfg(feature = "rayon")]
+impl<P, I> ParallelIndexVertices<P> for I
+where
+    I: Iterator<Item = P>,
+    P: Clone + Send + Topological,
+    P::Vertex: Clone + Eq + Hash + Send,
+{
+    fn par_index_vertices<R>(self) -> (Vec<R::Group>, Vec<P::Vertex>)
+    where
+        R: Grouping,
+        R::Group: Send,
+        P: Map<IndexOf<R>>,
+        P::Output: Topological<Vertex = IndexOf<R>>,
+        BufferOf<R>: Push<R, P::Output>,
+        IndexOf<R>: NumCast,
+    {
+        // Partition the topology into contiguous chunks, one per thread.
+        let mut topology = self.collect::<Vec<_>>();
+        let size = cmp::max(1, Integer::div_ceil(&topology.len(), &rayon::current_num_threads()));
+        let mut partitions = Vec::new();
+        while topology.len() > size {
+            partitions.push(topology.split_off(topology.len() - size));
+        }
+        partitions.push(topology);
+        partitions.reverse();
+        // Index each partition independently. This yields the local index of
+        // each vertex in the partition and the partition's unique vertices.
+        let partitions = partitions
+            .into_par_iter()
+            .map(|partition| {
+                let mut indexer = HashIndexer::<P, P::Vertex>::default();
+                let mut indices = Vec::new();
+                let mut vertices = Vec::new();
+                for vertex in partition.iter().cloned().flat_map(IntoVertices::into_vertices) {
+                    let (index, vertex) = indexer.index(vertex, |vertex| vertex);
+                    if let Some(vertex) = vertex {
+                        vertices.push(vertex);
+                    }
+                    indices.push(index);
+                }
+                (partition, indices, vertices)
+            })
+            .collect::<Vec<_>>();
+        // Merge the unique vertices of each partition in order. Because the
+        // unique vertices of a partition are ordered by first occurrence, so
+        // are the merged vertices.
+        let mut indexer = HashIndexer::<P, P::Vertex>::default();
+        let mut vertices = Vec::new();
+        let partitions = partitions
+            .into_iter()
+            .map(|(partition, indices, local)| {
+                let map = local
+                    .into_iter()
+                    .map(|vertex| {
+                        let (index, vertex) = indexer.index(vertex, |vertex| vertex);
+                        if let Some(vertex) = vertex {
+                            vertices.push(vertex);
+                        }
+                        index
+                    })
+                    .collect::<Vec<_>>();
+                (partition, indices, map)
+            })
+            .collect::<Vec<_>>();
+        // Remap the local indices of each partition into the merged vertices.
+        let indices = partitions
+            .into_par_iter()
+            .map(|(partition, indices, map)| {
+                let mut buffer = Vec::new();
+                let mut indices = indices.into_iter();
+                for topology in partition {
+                    Push::push(
+                        &mut buffer,
+                        topology.map(|_| NumCast::from(map[indices.next().unwrap()]).unwrap()),
+                    );
+                }
+                buffer
+            })
+            .collect::<Vec<BufferOf<R>>>()
+            .into_iter()
+            .flatten()
+            .collect();
+        (indices, vertices)
+    }
+}
+
 pub trait FromIndexer<P, Q>: Sized
 where
     P: Topological,
@@ -719,6 +1249,48 @@ where
     where
         T: FromIndexer<P, Q>,
         N: Indexer<Q, P::Vertex>;
+
+    /// Collects an iterator of $n$-gons into a mesh data structure using the
+    /// given indexer and reports statistics about how vertices were indexed.
+    ///
+    /// This behaves like [`collect_with_indexer`], but also returns an
+    /// [`IndexStats`] that describes how many input vertices were emitted or
+    /// merged by the indexer. This can be used to detect over- or
+    /// under-merging and to tune the choice of indexer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error defined by the implementer if the target type cannot be
+    /// constructed from the indexed vertex data.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::graph::MeshGraph;
+    /// use plexus::index::HashIndexer;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::cube::Cube;
+    /// use plexus::primitive::generate::Position;
+    ///
+    /// let (graph, stats): (MeshGraph<Point3<f64>>, _) = Cube::new()
+    ///     .polygons::<Position<Point3<R64>>>()
+    ///     .collect_with_stats(HashIndexer::default())
+    ///     .unwrap();
+    /// assert_eq!(8, stats.unique());
+    /// ```
+    ///
+    /// [`collect_with_indexer`]: crate::index::CollectWithIndexer::collect_with_indexer
+    /// [`IndexStats`]: crate::index::IndexStats
+    fn collect_with_stats<T, N>(self, indexer: N) -> Result<(T, IndexStats), T::Error>
+    where
+        T: FromIndexer<P, Q>,
+        N: Indexer<Q, P::Vertex>;
 }
 
 impl<P, Q, I> CollectWithIndexer<P, Q> for I
@@ -734,4 +1306,157 @@ where
     {
         T::from_indexer(self, indexer)
     }
+
+    fn collect_with_stats<T, N>(self, indexer: N) -> Result<(T, IndexStats), T::Error>
+    where
+        T: FromIndexer<P, Q>,
+        N: Indexer<Q, P::Vertex>,
+    {
+        let mut stats = IndexStats::default();
+        let output = T::from_indexer(
+            self,
+            Tally {
+                indexer,
+                stats: &mut stats,
+            },
+        )?;
+        Ok((output, stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+
+    use crate::graph::MeshGraph;
+    use crate::index::{Flat3, HashIndexer, HashLruIndexer, LruIndexer, SpatialIndexer};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+    use crate::primitive::sphere::UvSphere;
+    use crate::primitive::Trigon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn collect_with_stats() {
+        let (graph, stats): (MeshGraph<E3>, _) = Cube::new()
+            .polygons::<Position<E3>>() // 6 quadrilaterals, 24 vertices.
+            .collect_with_stats(HashIndexer::default())
+            .unwrap();
+
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(24, stats.input());
+        assert_eq!(8, stats.unique());
+        assert_eq!(16, stats.merged());
+        assert_eq!(Some(3.0), stats.ratio());
+    }
+
+    #[test]
+    fn spatial_indexer() {
+        // Shared vertices of the triangles differ slightly.
+        let (indices, positions) = vec![
+            Trigon::new(
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 0.0),
+            ),
+            Trigon::new(
+                Point3::new(1e-4, 0.0, 0.0),
+                Point3::new(1.0, 1.0, 1e-4),
+                Point3::new(0.0, 1.0, 0.0),
+            ),
+        ]
+        .into_iter()
+        .index_vertices::<Flat3, _>(SpatialIndexer::with_tolerance(1e-3));
+
+        assert_eq!(vec![0, 1, 2, 0, 2, 3], indices);
+        assert_eq!(4, positions.len());
+        assert_eq!(Point3::new(1.0, 1.0, 0.0), positions[2]);
+    }
+
+    #[test]
+    fn spatial_indexer_cell_boundary() {
+        // Shared vertices straddle the boundaries of grid cells at the origin
+        // and are indexed by position only.
+        let (indices, vertices) = vec![
+            Trigon::new(
+                (Point3::new(-1e-4, -1e-4, 0.0), 0usize),
+                (Point3::new(1.0, 0.0, 0.0), 1),
+                (Point3::new(0.0, 1.0, 0.0), 2),
+            ),
+            Trigon::new(
+                (Point3::new(1e-4, 1e-4, 1e-4), 3),
+                (Point3::new(0.0, 1.0, 0.0), 4),
+                (Point3::new(-1.0, 0.0, 0.0), 5),
+            ),
+        ]
+        .into_iter()
+        .index_vertices_with::<Flat3, _, _, _>(SpatialIndexer::with_tolerance(1e-3), |vertex| {
+            &vertex.0
+        });
+
+        assert_eq!(vec![0, 1, 2, 0, 2, 3], indices);
+        assert_eq!(
+            vec![0, 1, 2, 5],
+            vertices.iter().map(|vertex| vertex.1).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn hash_lru_indexer() {
+        let polygons = || UvSphere::new(16, 8).polygons::<Position<E3>>().triangulate();
+        let (indices, positions) =
+            polygons().index_vertices::<Flat3, _>(LruIndexer::with_capacity(64));
+        let (hash_indices, hash_positions) =
+            polygons().index_vertices::<Flat3, _>(HashLruIndexer::with_capacity(64));
+
+        assert_eq!(indices, hash_indices);
+        assert_eq!(positions, hash_positions);
+
+        // An insufficient capacity evicts entries in the same order.
+        let (indices, positions) =
+            polygons().index_vertices::<Flat3, _>(LruIndexer::with_capacity(4));
+        let (hash_indices, hash_positions) =
+            polygons().index_vertices::<Flat3, _>(HashLruIndexer::with_capacity(4));
+
+        assert_eq!(indices, hash_indices);
+        assert_eq!(positions, hash_positions);
+    }
+
+    #[test]
+    fn lru_indexer_overflow() {
+        let polygons = || UvSphere::new(16, 8).polygons::<Position<E3>>().triangulate();
+        let (_, expected) = polygons().index_vertices::<Flat3, _>(HashIndexer::default());
+
+        let mut indexer = LruIndexer::with_overflow_warning(64);
+        let (_, positions) = polygons().index_vertices::<Flat3, _>(&mut indexer);
+        assert_eq!(0, indexer.overflow_count());
+        assert_eq!(expected.len(), positions.len());
+
+        // An insufficient capacity emits redundant vertices.
+        let mut indexer = LruIndexer::with_overflow_warning(4);
+        let (_, positions) = polygons().index_vertices::<Flat3, _>(&mut indexer);
+        assert!(indexer.overflow_count() > 0);
+        assert_eq!(expected.len() + indexer.overflow_count(), positions.len());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_index_vertices() {
+        use crate::index::ParallelIndexVertices;
+
+        let polygons = || UvSphere::new(32, 16).polygons::<Position<E3>>().triangulate();
+
+        // The output must be identical to serial indexing.
+        assert_eq!(
+            polygons().index_vertices::<Flat3, _>(HashIndexer::default()),
+            polygons().par_index_vertices::<Flat3>()
+        );
+        assert_eq!(
+            polygons().index_vertices::<Trigon<usize>, _>(HashIndexer::default()),
+            polygons().par_index_vertices::<Trigon<usize>>()
+        );
+    }
 }
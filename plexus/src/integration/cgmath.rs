@@ -164,3 +164,32 @@ impl_from_geometry_ordered!(geometry => Point2, proxy => Total);
 impl_from_geometry_ordered!(geometry => Point3, proxy => Finite);
 impl_from_geometry_ordered!(geometry => Point3, proxy => NotNan);
 impl_from_geometry_ordered!(geometry => Point3, proxy => Total);
+
+#[cfg(test)]
+mod tests {
+    use decorum::R32;
+
+    use crate::graph::MeshGraph;
+    use crate::integration::cgmath::{InnerSpace, Point3, Vector3};
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R32>;
+
+    #[test]
+    fn collect_graph() {
+        let graph: MeshGraph<Point3<f32>> = Cube::new().polygons::<Position<E3>>().collect();
+
+        assert_eq!(8, graph.vertex_count());
+        assert_eq!(6, graph.face_count());
+        for face in graph.faces() {
+            let centroid = face.centroid();
+            let normal = face.normal().unwrap();
+            // The normal of each face of the cube is parallel to the vector
+            // from the origin to its centroid.
+            let direction = Vector3::new(centroid.x, centroid.y, centroid.z).normalize();
+            assert!((normal.dot(direction) - 1.0).abs() < 1e-6);
+        }
+    }
+}
@@ -243,3 +243,34 @@ macro_rules! impl_from_geometry_ordered {
 impl_from_geometry_ordered!(proxy => Finite);
 impl_from_geometry_ordered!(proxy => NotNan);
 impl_from_geometry_ordered!(proxy => Total);
+
+#[cfg(test)]
+mod tests {
+    use crate::buffer::MeshBuffer;
+    use crate::graph::MeshGraph;
+    use crate::index::Flat3;
+    use crate::integration::mint::Point3;
+    use crate::prelude::*;
+    use crate::primitive::Trigon;
+
+    #[test]
+    fn raw_buffers() {
+        let positions = vec![(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)];
+        let buffer =
+            MeshBuffer::<Flat3, Point3<f32>>::from_raw_buffers(0..3usize, positions.clone())
+                .unwrap();
+        // Vertices can be consumed by any crate that supports `mint`.
+        let vertices = buffer
+            .as_vertex_slice()
+            .iter()
+            .map(|vertex| (*vertex).into())
+            .collect::<Vec<[f32; 3]>>();
+        assert_eq!(vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]], vertices);
+
+        let graph =
+            MeshGraph::<Point3<f32>>::from_raw_buffers(vec![Trigon::new(0usize, 1, 2)], positions)
+                .unwrap();
+        assert_eq!(3, graph.vertex_count());
+        assert_eq!(1, graph.face_count());
+    }
+}
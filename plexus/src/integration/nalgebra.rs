@@ -8,7 +8,7 @@ use self::nalgebra::base::dimension::DimName;
 use decorum::{Finite, Float, NotNan, Primitive, Total};
 use num::{NumCast, ToPrimitive};
 
-use crate::geometry::{FromGeometry, UnitGeometry};
+use crate::geometry::{FromGeometry, Transform, UnitGeometry};
 use crate::graph::GraphData;
 
 #[doc(hidden)]
@@ -114,6 +114,60 @@ where
     }
 }
 
+impl<T> Transform<Point2<T>> for Isometry2<T>
+where
+    T: RealField,
+{
+    fn transform(&self, position: Point2<T>) -> Point2<T> {
+        self.transform_point(&position)
+    }
+}
+
+impl<T> Transform<Point3<T>> for Isometry3<T>
+where
+    T: RealField,
+{
+    fn transform(&self, position: Point3<T>) -> Point3<T> {
+        self.transform_point(&position)
+    }
+}
+
+impl<T> Transform<Point2<T>> for Similarity2<T>
+where
+    T: RealField,
+{
+    fn transform(&self, position: Point2<T>) -> Point2<T> {
+        self.transform_point(&position)
+    }
+}
+
+impl<T> Transform<Point3<T>> for Similarity3<T>
+where
+    T: RealField,
+{
+    fn transform(&self, position: Point3<T>) -> Point3<T> {
+        self.transform_point(&position)
+    }
+}
+
+impl<T> Transform<Point2<T>> for Matrix3<T>
+where
+    T: RealField,
+{
+    fn transform(&self, position: Point2<T>) -> Point2<T> {
+        self.transform_point(&position)
+    }
+}
+
+impl<T> Transform<Point3<T>> for Matrix4<T>
+where
+    T: RealField,
+{
+    fn transform(&self, position: Point3<T>) -> Point3<T> {
+        self.transform_point(&position)
+    }
+}
+
 impl<T, D> GraphData for OPoint<T, D>
 where
     T: Scalar,
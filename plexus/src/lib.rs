@@ -72,6 +72,8 @@ pub mod prelude {
     pub use crate::geometry::{FromGeometry as _, IntoGeometry as _};
     pub use crate::graph::{ClosedView as _, Rebind as _, Selector};
     pub use crate::index::{CollectWithIndexer as _, IndexVertices as _};
+    #[cfg(feature = "rayon")]
+    pub use crate::index::ParallelIndexVertices as _;
     pub use crate::primitive::decompose::{
         Edges as _, IntoEdges as _, IntoSubdivisions as _, IntoTetrahedrons as _, IntoTrigons as _,
         IntoVertices as _, Subdivide as _, Tetrahedrons as _, Triangulate as _, Vertices as _,
@@ -0,0 +1,315 @@
+//! Cone primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a cone.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::index::HashIndexer;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cone::{Bounds, Cone};
+//! use plexus::primitive::generate::Position;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let mut graph = Cone::new(16)
+//!     .polygons_from::<Position<E3>>(Bounds::new(N64::from_inner(1.0), N64::from_inner(3.0)))
+//!     .collect_with_indexer::<MeshGraph<E3>, _>(HashIndexer::default())
+//!     .unwrap();
+//! ```
+
+use decorum::Real;
+use num::{NumCast, One, ToPrimitive, Zero};
+use std::cmp;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position,
+};
+use crate::primitive::Trigon;
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    radius: Scalar<S>,
+    height: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn new(radius: Scalar<S>, height: Scalar<S>) -> Self {
+        Bounds { radius, height }
+    }
+
+    /// Gets bounds with the given radius and a height of twice the radius.
+    pub fn with_radius(radius: Scalar<S>) -> Self {
+        Self::new(radius, radius + radius)
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::with_radius(One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+/// Cone about the $z$-axis.
+///
+/// The base of the cone has the radius of its [`Bounds`] and the cone is
+/// centered at the origin, so its base lies half of the height of its
+/// [`Bounds`] below the origin and its apex lies the same distance above.
+/// With the default bounds, the cone fits in the same bounds as a
+/// [`UvSphere`]. The sides of the cone are a fan of triangles about the apex
+/// and its base is closed by a fan of triangles about the center of the base.
+///
+/// The apex and the center of the base are each generated exactly once and
+/// are indexed by every triangle of their fans, so indexing yields a closed
+/// surface without any welding.
+///
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+#[derive(Clone, Copy)]
+pub struct Cone {
+    nu: usize, // Radial divisions.
+}
+
+impl Cone {
+    pub fn new(nu: usize) -> Self {
+        Cone { nu: cmp::max(3, nu) }
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        u: usize,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let one = Scalar::<S>::one();
+        let pi = Real::PI;
+        // Wrap `u` so that the first and last vertices of the base are
+        // identical.
+        let u = into_scalar::<_, S>(u % self.nu) / into_scalar::<_, S>(self.nu);
+        let u = u * pi * (one + one);
+        S::from_xyz(
+            state.radius * u.cos(),
+            state.radius * u.sin(),
+            -state.height / (one + one),
+        )
+    }
+
+    fn vertex_with_position_from_apex<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        apex: bool,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let one = Scalar::<S>::one();
+        let z = state.height / (one + one);
+        S::from_xyz(
+            Scalar::<S>::zero(),
+            Scalar::<S>::zero(),
+            if apex { z } else { -z },
+        )
+    }
+
+    fn index_for_position(&self, u: usize) -> usize {
+        u % self.nu
+    }
+
+    fn index_for_apex(&self, apex: bool) -> usize {
+        if apex {
+            self.nu
+        }
+        else {
+            self.nu + 1
+        }
+    }
+
+    fn map_polygon_index(&self, index: usize) -> Polygon {
+        if index < self.nu {
+            Polygon::Side(index)
+        }
+        else {
+            Polygon::Base(index - self.nu)
+        }
+    }
+}
+
+impl Default for Cone {
+    fn default() -> Self {
+        Cone::new(16)
+    }
+}
+
+impl PolygonGenerator for Cone {
+    fn polygon_count(&self) -> usize {
+        2 * self.nu
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.nu + 2
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        if index < self.nu {
+            self.vertex_with_position_from(state, index)
+        }
+        else {
+            self.vertex_with_position_from_apex(state, index == self.nu)
+        }
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Cone
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Trigon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // Triangles are wound counter-clockwise when viewed from outside of
+        // the cone.
+        match self.map_polygon_index(index) {
+            Polygon::Side(u) => Trigon::new(
+                self.vertex_with_position_from(state, u),
+                self.vertex_with_position_from(state, u + 1),
+                self.vertex_with_position_from_apex(state, true),
+            ),
+            Polygon::Base(u) => Trigon::new(
+                self.vertex_with_position_from_apex(state, false),
+                self.vertex_with_position_from(state, u + 1),
+                self.vertex_with_position_from(state, u),
+            ),
+        }
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Cone {
+    type Output = Trigon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        match self.map_polygon_index(index) {
+            Polygon::Side(u) => Trigon::new(
+                self.index_for_position(u),
+                self.index_for_position(u + 1),
+                self.index_for_apex(true),
+            ),
+            Polygon::Base(u) => Trigon::new(
+                self.index_for_apex(false),
+                self.index_for_position(u + 1),
+                self.index_for_position(u),
+            ),
+        }
+    }
+}
+
+impl Generator for Cone {}
+
+enum Polygon {
+    Side(usize),
+    Base(usize),
+}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::collections::BTreeSet;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::cone::{Bounds, Cone};
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_count() {
+        assert_eq!(
+            10,
+            Cone::new(8)
+                .vertices::<Position<E3>>() // 10 conjoint vertices.
+                .count()
+        );
+    }
+
+    #[test]
+    fn position_index_to_vertex_mapping() {
+        assert_eq!(
+            10,
+            Cone::new(8)
+                .indexing_polygons::<Position>() // 16 triangles, 10 indices.
+                .vertices()
+                .collect::<BTreeSet<_>>()
+                .len()
+        )
+    }
+
+    #[test]
+    fn apex() {
+        let graph: MeshGraph<Point3<f64>> = Cone::new(8)
+            .polygons_from::<Position<E3>>(Bounds::new(R64::from_inner(1.0), R64::from_inner(3.0)))
+            .collect();
+
+        assert_eq!(10, graph.vertex_count());
+        assert_eq!(16, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+        // The apex is shared by every triangle of the sides.
+        let apex = graph
+            .vertices()
+            .find(|vertex| vertex.position().z == 1.5)
+            .unwrap();
+        assert_eq!(8, apex.valence());
+        assert!(graph
+            .vertices()
+            .filter(|vertex| vertex.key() != apex.key())
+            .all(|vertex| vertex.position().z == -1.5));
+    }
+}
@@ -19,15 +19,15 @@
 //!     .collect::<MeshGraph<Point3<N64>>>();
 //! ```
 
-use num::One;
+use num::{One, Zero};
 use theon::adjunct::{Converged, Map};
 use theon::query::Unit;
 use theon::space::{Basis, EuclideanSpace, FiniteDimensional, InnerSpace, Scalar, Vector};
-use typenum::U3;
+use typenum::{U2, U3};
 
 use crate::primitive::generate::{
     Attribute, AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
-    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, UvMap,
 };
 use crate::primitive::Tetragon;
 
@@ -285,4 +285,105 @@ impl IndexingPolygonGenerator<Plane> for Cube {
     }
 }
 
+impl<S> AttributeGenerator<UvMap<S>> for Cube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for Cube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        4
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let (zero, one) = (Scalar::<S>::zero(), Scalar::<S>::one());
+        match index {
+            0 => S::from_xy(zero, zero),
+            1 => S::from_xy(one, zero),
+            2 => S::from_xy(one, one),
+            3 => S::from_xy(zero, one),
+            _ => panic!(),
+        }
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for Cube
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<UvMap<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<UvMap<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for Cube {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        // Each face is mapped onto the entire unit square.
+        assert!(index < self.polygon_count());
+        Tetragon::new(0, 1, 2, 3)
+    }
+}
+
 impl Generator for Cube {}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
+
+    use crate::index::HashIndexer;
+    use crate::prelude::*;
+    use crate::primitive::cube::Cube;
+    use crate::primitive::{NGon, Tetragon};
+
+    type E3 = Point3<f64>;
+
+    #[test]
+    fn position_and_normal() {
+        for NGon(vertices) in Cube::new().polygons_with_position_and_normal::<E3>() {
+            let (origin, normal) = (vertices[0].0, *vertices[0].1.get());
+            // Normals are constant across faces and point away from the origin.
+            for (position, other) in vertices.iter() {
+                assert_eq!(normal, *other.get());
+                assert!(normal.dot(&(*position - origin)).abs() < 1e-9);
+                assert!(normal.dot(&position.coords) > 0.0);
+            }
+        }
+    }
+
+    #[test]
+    fn position_and_uv() {
+        let (indices, vertices) = Cube::new()
+            .polygons_with_position_and_uv::<Point3<R64>, Point2<R64>>()
+            .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+
+        // Each face is mapped onto the entire unit square, so a corner is
+        // shared between faces only where it has the same texture coordinates
+        // in each face. Of the 24 corners of the faces, 17 are unique.
+        assert_eq!(6, indices.len());
+        assert_eq!(17, vertices.len());
+        for NGon(uvs) in Cube::new().polygons_with_uv::<Point2<f64>>() {
+            assert_eq!(
+                [
+                    Point2::new(0.0, 0.0),
+                    Point2::new(1.0, 0.0),
+                    Point2::new(1.0, 1.0),
+                    Point2::new(0.0, 1.0),
+                ],
+                uvs
+            );
+        }
+    }
+}
@@ -0,0 +1,376 @@
+//! Cylinder primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of an open cylinder (a tube).
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::index::HashIndexer;
+//! use plexus::prelude::*;
+//! use plexus::primitive::cylinder::Cylinder;
+//! use plexus::primitive::generate::Position;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let mut graph = Cylinder::new(16, 4)
+//!     .with_caps(false)
+//!     .polygons::<Position<E3>>()
+//!     .collect_with_indexer::<MeshGraph<E3>, _>(HashIndexer::default())
+//!     .unwrap();
+//! ```
+
+use decorum::Real;
+use num::{NumCast, One, ToPrimitive, Zero};
+use std::cmp;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position,
+};
+use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    radius: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn with_radius(radius: Scalar<S>) -> Self {
+        Bounds { radius }
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::with_radius(One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_radius()
+    }
+}
+
+/// Cylinder about the $z$-axis.
+///
+/// The cylinder has the radius of its [`Bounds`] and extends that same
+/// distance above and below the origin, so it fits in the same bounds as a
+/// [`UvSphere`]. The sides of the cylinder are quadrilaterals and its ends are
+/// closed by caps formed from fans of triangles about the $z$-axis. If caps
+/// are disabled, then the ends of the cylinder are open and form boundaries.
+///
+/// The vertices of each cap are the center of the cap and the first or last
+/// ring of the sides, which are indexed in the same way by both the sides and
+/// the caps. Only the two centers are generated for the caps, so indexing
+/// (such as with `indexing_polygons` or `index_vertices`) yields a closed
+/// surface without any welding.
+///
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+#[derive(Clone, Copy)]
+pub struct Cylinder {
+    nu: usize, // Radial divisions.
+    ny: usize, // Height divisions.
+    caps: bool,
+}
+
+impl Cylinder {
+    pub fn new(nu: usize, ny: usize) -> Self {
+        Cylinder {
+            nu: cmp::max(3, nu),
+            ny: cmp::max(1, ny),
+            caps: true,
+        }
+    }
+
+    /// Sets whether or not the ends of the cylinder are closed by caps.
+    pub fn with_caps(mut self, caps: bool) -> Self {
+        self.caps = caps;
+        self
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        u: usize,
+        v: usize,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let one = Scalar::<S>::one();
+        let pi = Real::PI;
+        // Wrap `u` so that the first and last meridians are identical.
+        let u = into_scalar::<_, S>(u % self.nu) / into_scalar::<_, S>(self.nu);
+        let u = u * pi * (one + one);
+        let z = ((into_scalar::<_, S>(v) / into_scalar::<_, S>(self.ny)) * (one + one)) - one;
+        S::from_xyz(state.radius * u.cos(), state.radius * u.sin(), state.radius * z)
+    }
+
+    fn vertex_with_position_from_cap<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        v: usize,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let one = Scalar::<S>::one();
+        let z = if v == 0 { -one } else { one };
+        S::from_xyz(Scalar::<S>::zero(), Scalar::<S>::zero(), state.radius * z)
+    }
+
+    fn index_for_position(&self, u: usize, v: usize) -> usize {
+        (v * self.nu) + (u % self.nu)
+    }
+
+    fn index_for_cap(&self, v: usize) -> usize {
+        let base = (self.ny + 1) * self.nu;
+        if v == 0 {
+            base
+        }
+        else {
+            base + 1
+        }
+    }
+
+    fn map_polygon_index(&self, index: usize) -> Polygon {
+        let sides = self.nu * self.ny;
+        if index < sides {
+            Polygon::Side(index % self.nu, index / self.nu)
+        }
+        else if index < sides + self.nu {
+            Polygon::Bottom(index - sides)
+        }
+        else {
+            Polygon::Top(index - sides - self.nu)
+        }
+    }
+}
+
+impl Default for Cylinder {
+    fn default() -> Self {
+        Cylinder::new(16, 1)
+    }
+}
+
+impl PolygonGenerator for Cylinder {
+    fn polygon_count(&self) -> usize {
+        let caps = if self.caps { 2 * self.nu } else { 0 };
+        (self.nu * self.ny) + caps
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        let caps = if self.caps { 2 } else { 0 };
+        ((self.ny + 1) * self.nu) + caps
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let count = (self.ny + 1) * self.nu;
+        if index < count {
+            self.vertex_with_position_from(state, index % self.nu, index / self.nu)
+        }
+        else if index == count {
+            self.vertex_with_position_from_cap(state, 0)
+        }
+        else {
+            self.vertex_with_position_from_cap(state, self.ny)
+        }
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Cylinder
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        // Polygons are wound counter-clockwise when viewed from outside of the
+        // cylinder.
+        match self.map_polygon_index(index) {
+            Polygon::Side(u, v) => {
+                let (p, q) = (u + 1, v + 1);
+                Tetragon::new(
+                    self.vertex_with_position_from(state, u, v),
+                    self.vertex_with_position_from(state, p, v),
+                    self.vertex_with_position_from(state, p, q),
+                    self.vertex_with_position_from(state, u, q),
+                )
+                .into()
+            }
+            Polygon::Bottom(u) => Trigon::new(
+                self.vertex_with_position_from_cap(state, 0),
+                self.vertex_with_position_from(state, u + 1, 0),
+                self.vertex_with_position_from(state, u, 0),
+            )
+            .into(),
+            Polygon::Top(u) => Trigon::new(
+                self.vertex_with_position_from_cap(state, self.ny),
+                self.vertex_with_position_from(state, u, self.ny),
+                self.vertex_with_position_from(state, u + 1, self.ny),
+            )
+            .into(),
+        }
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Cylinder {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        match self.map_polygon_index(index) {
+            Polygon::Side(u, v) => {
+                let (p, q) = (u + 1, v + 1);
+                Tetragon::new(
+                    self.index_for_position(u, v),
+                    self.index_for_position(p, v),
+                    self.index_for_position(p, q),
+                    self.index_for_position(u, q),
+                )
+                .into()
+            }
+            Polygon::Bottom(u) => Trigon::new(
+                self.index_for_cap(0),
+                self.index_for_position(u + 1, 0),
+                self.index_for_position(u, 0),
+            )
+            .into(),
+            Polygon::Top(u) => Trigon::new(
+                self.index_for_cap(self.ny),
+                self.index_for_position(u, self.ny),
+                self.index_for_position(u + 1, self.ny),
+            )
+            .into(),
+        }
+    }
+}
+
+impl Generator for Cylinder {}
+
+enum Polygon {
+    Side(usize, usize),
+    Bottom(usize),
+    Top(usize),
+}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::collections::BTreeSet;
+
+    use crate::graph::MeshGraph;
+    use crate::index::{Flat3, HashIndexer};
+    use crate::prelude::*;
+    use crate::primitive::cylinder::Cylinder;
+    use crate::primitive::generate::Position;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_count() {
+        assert_eq!(
+            26,
+            Cylinder::new(8, 2)
+                .vertices::<Position<E3>>() // 26 conjoint vertices.
+                .count()
+        );
+        assert_eq!(
+            24,
+            Cylinder::new(8, 2)
+                .with_caps(false)
+                .vertices::<Position<E3>>() // 24 conjoint vertices.
+                .count()
+        );
+    }
+
+    #[test]
+    fn position_index_to_vertex_mapping() {
+        assert_eq!(
+            26,
+            Cylinder::new(8, 2)
+                .indexing_polygons::<Position>() // 32 polygons, 26 indices.
+                .vertices()
+                .collect::<BTreeSet<_>>()
+                .len()
+        )
+    }
+
+    #[test]
+    fn caps() {
+        let graph: MeshGraph<E3> = Cylinder::new(8, 2).polygons::<Position<E3>>().collect();
+
+        assert_eq!(26, graph.vertex_count());
+        assert_eq!(32, graph.face_count());
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+
+        // Without caps, the ends of the cylinder are boundaries.
+        let graph: MeshGraph<E3> = Cylinder::new(8, 2)
+            .with_caps(false)
+            .polygons::<Position<E3>>()
+            .collect();
+
+        assert_eq!(24, graph.vertex_count());
+        assert_eq!(16, graph.face_count());
+        assert_eq!(
+            16,
+            graph
+                .edges()
+                .filter(|edge| edge.is_boundary_edge())
+                .count()
+        );
+    }
+
+    #[test]
+    fn triangulate_and_index_vertices() {
+        let (indices, vertices) = Cylinder::new(8, 2)
+            .polygons::<Position<E3>>()
+            .triangulate()
+            .index_vertices::<Flat3, _>(HashIndexer::default());
+
+        // Each quadrilateral of the sides is split into two triangles.
+        assert_eq!(3 * (32 + 16), indices.len());
+        assert_eq!(26, vertices.len());
+    }
+}
@@ -44,10 +44,19 @@
 //! [`Generator`]: crate::primitive::generate::Generator
 //! [`UvSphere`]: crate::primitive::sphere::UvSphere
 
+use decorum::cmp::IntrinsicOrd;
 use std::marker::PhantomData;
 use std::ops::Range;
+use theon::adjunct::Map;
+use theon::query::Aabb;
+use theon::space::{EuclideanSpace, Scalar};
 
-use crate::primitive::Polygonal;
+use crate::primitive::{Polygonal, Topological};
+
+type StateOf<G, A> = <G as AttributeGenerator<A>>::State;
+type PolygonOf<G, A> = <G as AttributePolygonGenerator<A>>::Output;
+type VertexOf<G, A> = <PolygonOf<G, A> as Topological>::Vertex;
+type ZipOf<G, A, B> = <PolygonOf<G, A> as Map<(VertexOf<G, A>, VertexOf<G, B>)>>::Output;
 
 /// Geometric attribute.
 ///
@@ -135,6 +144,48 @@ pub struct Position<S = ()> {
 
 impl<S> Attribute for Position<S> {}
 
+/// Meta-attribute for texture coordinates.
+///
+/// Describes the texture coordinates of vertices in a polytope. The generated
+/// data is derived from the type parameter `S`, which typically requires
+/// [`EuclideanSpace`] with two dimensions. Coordinates are in the unit square.
+///
+/// Unlike positions, texture coordinates may be discontinuous across a
+/// polytope. For example, the texture coordinates of a [$uv$-sphere][`UvSphere`]
+/// wrap around at a seam, so positions along the seam have two distinct
+/// texture coordinates. Indexing vertices with both attributes yields
+/// distinct vertices along such seams.
+///
+/// # Examples
+///
+/// Generating raw buffers with positional and texture data of a
+/// [$uv$-sphere][`UvSphere`]:
+///
+/// ```rust
+/// # extern crate decorum;
+/// # extern crate nalgebra;
+/// # extern crate plexus;
+/// #
+/// use decorum::R64;
+/// use nalgebra::{Point2, Point3};
+/// use plexus::index::{Flat3, HashIndexer};
+/// use plexus::prelude::*;
+/// use plexus::primitive::sphere::UvSphere;
+///
+/// let (indices, vertices) = UvSphere::new(16, 8)
+///     .polygons_with_position_and_uv::<Point3<R64>, Point2<R64>>()
+///     .triangulate()
+///     .index_vertices::<Flat3, _>(HashIndexer::default());
+/// ```
+///
+/// [`EuclideanSpace`]: theon::space::EuclideanSpace
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+pub struct UvMap<S = ()> {
+    phantom: PhantomData<fn() -> S>,
+}
+
+impl<S> Attribute for UvMap<S> {}
+
 /// Iterator that generates topology and geometric attributes.
 pub struct Generate<'a, G, S, P>
 where
@@ -318,6 +369,125 @@ pub trait Generator: Sized {
         Generate::new(self, state, self.polygon_count(), Self::polygon_from)
     }
 
+    /// Gets an iterator over the set of polygons with positional data.
+    ///
+    /// This is equivalent to `polygons::<Position<S>>()`.
+    fn polygons_with_position<S>(
+        &self,
+    ) -> Generate<Self, StateOf<Self, Position<S>>, PolygonOf<Self, Position<S>>>
+    where
+        Self: AttributePolygonGenerator<Position<S>>,
+    {
+        self.polygons::<Position<S>>()
+    }
+
+    /// Gets an iterator over the set of polygons with surface normal data.
+    ///
+    /// This is equivalent to `polygons::<Normal<S>>()`. Normals are generated
+    /// directly by the polytope and need not be computed from positions. For
+    /// example, [`UvSphere`] generates normalized positions and [`Cube`]
+    /// generates a constant normal for each face.
+    ///
+    /// [`Cube`]: crate::primitive::cube::Cube
+    /// [`UvSphere`]: crate::primitive::sphere::UvSphere
+    fn polygons_with_normal<S>(
+        &self,
+    ) -> Generate<Self, StateOf<Self, Normal<S>>, PolygonOf<Self, Normal<S>>>
+    where
+        Self: AttributePolygonGenerator<Normal<S>>,
+    {
+        self.polygons::<Normal<S>>()
+    }
+
+    /// Gets an iterator over the set of polygons with both positional and
+    /// surface normal data.
+    ///
+    /// Each vertex is a tuple of its position and its surface normal. Data is
+    /// generated with default state, such as unit bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate decorum;
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use decorum::R64;
+    /// use nalgebra::Point3;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// let vertices = UvSphere::new(16, 8)
+    ///     .polygons_with_position_and_normal::<Point3<R64>>()
+    ///     .triangulate()
+    ///     .vertices()
+    ///     .map(|(position, normal)| (position, normal.into_inner()))
+    ///     .collect::<Vec<_>>();
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn polygons_with_position_and_normal<S>(
+        &self,
+    ) -> Generate<
+        Self,
+        (StateOf<Self, Position<S>>, StateOf<Self, Normal<S>>),
+        ZipOf<Self, Position<S>, Normal<S>>,
+    >
+    where
+        Self: AttributePolygonGenerator<Position<S>> + AttributePolygonGenerator<Normal<S>>,
+        PolygonOf<Self, Position<S>>:
+            Map<(VertexOf<Self, Position<S>>, VertexOf<Self, Normal<S>>)>,
+    {
+        Generate::new(
+            self,
+            Default::default(),
+            self.polygon_count(),
+            zip_polygon_from::<Self, Position<S>, Normal<S>>,
+        )
+    }
+
+    /// Gets an iterator over the set of polygons with texture coordinates.
+    ///
+    /// This is equivalent to `polygons::<UvMap<T>>()`. See [`UvMap`].
+    ///
+    /// [`UvMap`]: crate::primitive::generate::UvMap
+    fn polygons_with_uv<T>(
+        &self,
+    ) -> Generate<Self, StateOf<Self, UvMap<T>>, PolygonOf<Self, UvMap<T>>>
+    where
+        Self: AttributePolygonGenerator<UvMap<T>>,
+    {
+        self.polygons::<UvMap<T>>()
+    }
+
+    /// Gets an iterator over the set of polygons with both positional data and
+    /// texture coordinates.
+    ///
+    /// Each vertex is a tuple of its position and its texture coordinates.
+    /// Data is generated with default state, such as unit bounds. See
+    /// [`UvMap`].
+    ///
+    /// [`UvMap`]: crate::primitive::generate::UvMap
+    #[allow(clippy::type_complexity)]
+    fn polygons_with_position_and_uv<S, T>(
+        &self,
+    ) -> Generate<
+        Self,
+        (StateOf<Self, Position<S>>, StateOf<Self, UvMap<T>>),
+        ZipOf<Self, Position<S>, UvMap<T>>,
+    >
+    where
+        Self: AttributePolygonGenerator<Position<S>> + AttributePolygonGenerator<UvMap<T>>,
+        PolygonOf<Self, Position<S>>:
+            Map<(VertexOf<Self, Position<S>>, VertexOf<Self, UvMap<T>>)>,
+    {
+        Generate::new(
+            self,
+            Default::default(),
+            self.polygon_count(),
+            zip_polygon_from::<Self, Position<S>, UvMap<T>>,
+        )
+    }
+
     /// Gets an iterator over a set of polygons that index the unique set of
     /// vertices with the given attribute.
     ///
@@ -363,4 +533,59 @@ pub trait Generator: Sized {
             generator.indexing_polygon(index)
         })
     }
+
+    /// Gets the minimum and maximum corners of the axis-aligned bounding box
+    /// of the polytope's positions.
+    ///
+    /// Positions are generated with default state, such as unit bounds. This
+    /// does not require collecting the polytope into a mesh data structure.
+    ///
+    /// Returns `None` if the polytope has no vertices.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate nalgebra;
+    /// # extern crate plexus;
+    /// #
+    /// use nalgebra::Point3;
+    /// use plexus::prelude::*;
+    /// use plexus::primitive::sphere::UvSphere;
+    ///
+    /// let (min, max) = UvSphere::new(16, 8).bounds::<Point3<f64>>().unwrap();
+    /// ```
+    fn bounds<S>(&self) -> Option<(S, S)>
+    where
+        Self: AttributeVertexGenerator<Position<S>, Output = S>,
+        S: EuclideanSpace,
+        Scalar<S>: IntrinsicOrd,
+    {
+        if AttributeVertexGenerator::<Position<S>>::vertex_count(self) == 0 {
+            None
+        }
+        else {
+            let aabb = Aabb::from_points(self.vertices::<Position<S>>());
+            Some((aabb.origin, aabb.endpoint()))
+        }
+    }
+}
+
+// Generates a polygon with vertices that pair the data of two attributes.
+fn zip_polygon_from<G, A, B>(
+    generator: &G,
+    (a, b): &(StateOf<G, A>, StateOf<G, B>),
+    index: usize,
+) -> ZipOf<G, A, B>
+where
+    G: AttributePolygonGenerator<A> + AttributePolygonGenerator<B>,
+    A: Attribute,
+    B: Attribute,
+    PolygonOf<G, A>: Map<(VertexOf<G, A>, VertexOf<G, B>)>,
+{
+    let mut vertices =
+        AttributePolygonGenerator::<B>::polygon_from(generator, b, index).into_iter();
+    AttributePolygonGenerator::<A>::polygon_from(generator, a, index).map(|vertex| {
+        let other = vertices.next().expect("inconsistent polygon arity");
+        (vertex, other)
+    })
 }
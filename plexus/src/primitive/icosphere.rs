@@ -0,0 +1,293 @@
+//! Geodesic sphere primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of an icosphere.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::N64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::index::HashIndexer;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::icosphere::IcoSphere;
+//!
+//! type E3 = Point3<N64>;
+//!
+//! let mut graph = IcoSphere::new(2)
+//!     .polygons::<Position<E3>>()
+//!     .collect_with_indexer::<MeshGraph<E3>, _>(HashIndexer::default())
+//!     .unwrap();
+//! ```
+
+use num::{NumCast, ToPrimitive};
+use std::collections::HashMap;
+use theon::query::Unit;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+};
+use crate::primitive::platonic::{normalize, ICOSAHEDRON_FACES, ICOSAHEDRON_POSITIONS};
+use crate::primitive::sphere::Bounds;
+use crate::primitive::Trigon;
+
+/// Sphere formed from a subdivided icosahedron.
+///
+/// Unlike a [`UvSphere`], the triangles of an icosphere are distributed
+/// nearly uniformly and there is no pinching at poles. Each subdivision
+/// splits every triangle into four triangles by inserting vertices at the
+/// midpoints of edges and projecting them onto the sphere. Midpoints are
+/// shared by the triangles of an edge, so an icosphere with $n$ subdivisions
+/// has exactly $10 \cdot 4^n + 2$ vertices.
+///
+/// [`UvSphere`]: crate::primitive::sphere::UvSphere
+#[derive(Clone)]
+pub struct IcoSphere {
+    positions: Vec<[f64; 3]>,
+    triangles: Vec<[usize; 3]>,
+}
+
+impl IcoSphere {
+    pub fn new(subdivisions: usize) -> Self {
+        let mut positions = ICOSAHEDRON_POSITIONS
+            .into_iter()
+            .map(normalize)
+            .collect::<Vec<_>>();
+        let mut triangles = ICOSAHEDRON_FACES.to_vec();
+        for _ in 0..subdivisions {
+            // Midpoints are keyed by the indices of their edge so that they
+            // are inserted only once.
+            let mut midpoints = HashMap::with_capacity(triangles.len() * 3 / 2);
+            let mut midpoint = |a: usize, b: usize| {
+                *midpoints
+                    .entry(if a < b { (a, b) } else { (b, a) })
+                    .or_insert_with(|| {
+                        let (p, q) = (positions[a], positions[b]);
+                        positions.push(normalize([p[0] + q[0], p[1] + q[1], p[2] + q[2]]));
+                        positions.len() - 1
+                    })
+            };
+            triangles = triangles
+                .into_iter()
+                .flat_map(|[a, b, c]| {
+                    let (ab, bc, ca) = (midpoint(a, b), midpoint(b, c), midpoint(c, a));
+                    [[a, ab, ca], [b, bc, ab], [c, ca, bc], [ab, bc, ca]]
+                })
+                .collect();
+        }
+        IcoSphere {
+            positions,
+            triangles,
+        }
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        index: usize,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let [x, y, z] = self.positions[index];
+        S::from_xyz(
+            state.radius * into_scalar::<_, S>(x),
+            state.radius * into_scalar::<_, S>(y),
+            state.radius * into_scalar::<_, S>(z),
+        )
+    }
+}
+
+impl Default for IcoSphere {
+    fn default() -> Self {
+        IcoSphere::new(2)
+    }
+}
+
+impl PolygonGenerator for IcoSphere {
+    fn polygon_count(&self) -> usize {
+        self.triangles.len()
+    }
+}
+
+impl<S> AttributeGenerator<Normal<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<Normal<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Unit<Vector<S>>;
+
+    fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let position = self.vertex_with_position_from::<S>(&Default::default(), index);
+        Unit::try_from_inner(position.into_coordinates()).expect("non-zero vector")
+    }
+}
+
+impl<S> AttributePolygonGenerator<Normal<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Trigon<Unit<Vector<S>>>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let [a, b, c] = self.triangles[index];
+        Trigon::new(
+            AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, a),
+            AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, b),
+            AttributeVertexGenerator::<Normal<S>>::vertex_from(self, state, c),
+        )
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Normal<S>> for IcoSphere {
+    type Output = Trigon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        self.vertex_with_position_from(state, index)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for IcoSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Trigon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let [a, b, c] = self.triangles[index];
+        Trigon::new(
+            self.vertex_with_position_from(state, a),
+            self.vertex_with_position_from(state, b),
+            self.vertex_with_position_from(state, c),
+        )
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for IcoSphere {
+    type Output = Trigon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let [a, b, c] = self.triangles[index];
+        Trigon::new(a, b, c)
+    }
+}
+
+impl Generator for IcoSphere {}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::collections::BTreeSet;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::icosphere::IcoSphere;
+    use crate::primitive::sphere::UvSphere;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_count() {
+        for n in 0..4 {
+            assert_eq!(
+                (10 * 4usize.pow(n)) + 2,
+                IcoSphere::new(n as usize)
+                    .vertices::<Position<E3>>()
+                    .count()
+            );
+        }
+    }
+
+    #[test]
+    fn position_index_to_vertex_mapping() {
+        assert_eq!(
+            42,
+            IcoSphere::new(1)
+                .indexing_polygons::<Position>() // 80 triangles, 42 indices.
+                .vertices()
+                .collect::<BTreeSet<_>>()
+                .len()
+        )
+    }
+
+    #[test]
+    fn watertight() {
+        let graph: MeshGraph<E3> = IcoSphere::new(2).polygons::<Position<E3>>().collect();
+
+        assert_eq!(162, graph.vertex_count());
+        assert_eq!(320, graph.face_count());
+        assert!(graph.faces().all(|face| face.arity() == 3));
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+    }
+
+    #[test]
+    fn uniform_area() {
+        fn ratio(graph: MeshGraph<Point3<f64>>) -> f64 {
+            let areas = graph
+                .faces()
+                .map(|face| face.area().unwrap())
+                .collect::<Vec<_>>();
+            let max = areas.iter().cloned().fold(f64::MIN, f64::max);
+            let min = areas.iter().cloned().fold(f64::MAX, f64::min);
+            max / min
+        }
+
+        let ico = ratio(IcoSphere::new(2).polygons::<Position<E3>>().collect());
+        let uv = ratio(UvSphere::new(16, 8).polygons::<Position<E3>>().collect());
+
+        // Unlike a $uv$-sphere, there is no pinching at poles.
+        assert!(ico < 2.0);
+        assert!(ico < uv);
+    }
+}
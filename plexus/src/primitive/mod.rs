@@ -80,9 +80,14 @@
 //! [`DynamicArity`]: crate::DynamicArity
 //! [`StaticArity`]: crate::StaticArity
 
+pub mod cone;
 pub mod cube;
+pub mod cylinder;
 pub mod decompose;
 pub mod generate;
+pub mod icosphere;
+pub mod plane;
+pub mod platonic;
 pub mod sphere;
 
 use arrayvec::ArrayVec;
@@ -0,0 +1,337 @@
+//! Plane primitives.
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a subdivided plane and
+//! displacing it to form a heightfield.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::plane::Plane;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let mut graph: MeshGraph<Point3<f64>> = Plane::new(16, 16)
+//!     .polygons_with_position::<E3>()
+//!     .collect();
+//! graph.map_positions(|position| {
+//!     Point3::new(position.x, position.y, (position.x * 8.0).sin() * 0.1)
+//! });
+//! ```
+
+use num::{NumCast, One, ToPrimitive, Zero};
+use std::cmp;
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::{U2, U3};
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position, UvMap,
+};
+use crate::primitive::Tetragon;
+
+#[derive(Clone, Copy)]
+pub struct Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    lower: Scalar<S>,
+    upper: Scalar<S>,
+}
+
+impl<S> Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    pub fn with_radius(radius: Scalar<S>) -> Self {
+        Bounds {
+            lower: -radius,
+            upper: radius,
+        }
+    }
+
+    pub fn with_width(width: Scalar<S>) -> Self {
+        Self::with_radius(width / (Scalar::<S>::one() + One::one()))
+    }
+
+    pub fn unit_radius() -> Self {
+        Self::with_radius(One::one())
+    }
+
+    pub fn unit_width() -> Self {
+        Self::with_width(One::one())
+    }
+}
+
+impl<S> Default for Bounds<S>
+where
+    S: EuclideanSpace,
+{
+    fn default() -> Self {
+        Self::unit_width()
+    }
+}
+
+/// Subdivided plane in the $xy$-plane.
+///
+/// The plane is a grid of quadrilaterals centered at the origin with the
+/// width of its [`Bounds`] along both the $x$- and $y$-axes, so it fits in the
+/// same bounds as a [`Cube`]. Quadrilaterals are wound counter-clockwise when
+/// viewed from the positive $z$-axis.
+///
+/// [`Cube`]: crate::primitive::cube::Cube
+#[derive(Clone, Copy)]
+pub struct Plane {
+    nx: usize, // Divisions along the x-axis.
+    ny: usize, // Divisions along the y-axis.
+}
+
+impl Plane {
+    pub fn new(nx: usize, ny: usize) -> Self {
+        Plane {
+            nx: cmp::max(1, nx),
+            ny: cmp::max(1, ny),
+        }
+    }
+
+    fn vertex_with_position_from<S>(
+        &self,
+        state: &<Self as AttributeGenerator<Position<S>>>::State,
+        x: usize,
+        y: usize,
+    ) -> S
+    where
+        Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+        S: EuclideanSpace + FiniteDimensional<N = U3>,
+    {
+        let width = state.upper - state.lower;
+        let x = into_scalar::<_, S>(x) / into_scalar::<_, S>(self.nx);
+        let y = into_scalar::<_, S>(y) / into_scalar::<_, S>(self.ny);
+        S::from_xyz(
+            state.lower + (x * width),
+            state.lower + (y * width),
+            Zero::zero(),
+        )
+    }
+
+    fn vertex_with_uv_from<S>(&self, x: usize, y: usize) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        S::from_xy(
+            into_scalar::<_, S>(x) / into_scalar::<_, S>(self.nx),
+            into_scalar::<_, S>(y) / into_scalar::<_, S>(self.ny),
+        )
+    }
+
+    fn index_for_position(&self, x: usize, y: usize) -> usize {
+        (y * (self.nx + 1)) + x
+    }
+}
+
+impl Default for Plane {
+    fn default() -> Self {
+        Plane::new(1, 1)
+    }
+}
+
+impl PolygonGenerator for Plane {
+    fn polygon_count(&self) -> usize {
+        self.nx * self.ny
+    }
+}
+
+impl<S> AttributeGenerator<Position<S>> for Plane
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type State = Bounds<S>;
+}
+
+impl<S> AttributeVertexGenerator<Position<S>> for Plane
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        (self.nx + 1) * (self.ny + 1)
+    }
+
+    fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let n = self.nx + 1;
+        self.vertex_with_position_from(state, index % n, index / n)
+    }
+}
+
+impl<S> AttributePolygonGenerator<Position<S>> for Plane
+where
+    S: EuclideanSpace + FiniteDimensional<N = U3>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        let (x, y) = (index % self.nx, index / self.nx);
+        Tetragon::new(
+            self.vertex_with_position_from(state, x, y),
+            self.vertex_with_position_from(state, x + 1, y),
+            self.vertex_with_position_from(state, x + 1, y + 1),
+            self.vertex_with_position_from(state, x, y + 1),
+        )
+    }
+}
+
+impl<S> IndexingPolygonGenerator<Position<S>> for Plane {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        let (x, y) = (index % self.nx, index / self.nx);
+        Tetragon::new(
+            self.index_for_position(x, y),
+            self.index_for_position(x + 1, y),
+            self.index_for_position(x + 1, y + 1),
+            self.index_for_position(x, y + 1),
+        )
+    }
+}
+
+impl<S> AttributeGenerator<UvMap<S>> for Plane
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for Plane
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        (self.nx + 1) * (self.ny + 1)
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let n = self.nx + 1;
+        self.vertex_with_uv_from(index % n, index / n)
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for Plane
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = Tetragon<S>;
+
+    fn polygon_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let (x, y) = (index % self.nx, index / self.nx);
+        Tetragon::new(
+            self.vertex_with_uv_from(x, y),
+            self.vertex_with_uv_from(x + 1, y),
+            self.vertex_with_uv_from(x + 1, y + 1),
+            self.vertex_with_uv_from(x, y + 1),
+        )
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for Plane {
+    type Output = Tetragon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<Position<S>>::indexing_polygon(self, index)
+    }
+}
+
+impl Generator for Plane {}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
+    use std::collections::BTreeSet;
+
+    use crate::graph::MeshGraph;
+    use crate::index::HashIndexer;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::plane::Plane;
+    use crate::primitive::Tetragon;
+
+    type E3 = Point3<R64>;
+
+    #[test]
+    fn vertex_count() {
+        assert_eq!(
+            20,
+            Plane::new(4, 3)
+                .vertices::<Position<E3>>() // 20 conjoint vertices.
+                .count()
+        );
+    }
+
+    #[test]
+    fn position_index_to_vertex_mapping() {
+        assert_eq!(
+            20,
+            Plane::new(4, 3)
+                .indexing_polygons::<Position>() // 12 quadrilaterals, 20 indices.
+                .vertices()
+                .collect::<BTreeSet<_>>()
+                .len()
+        )
+    }
+
+    #[test]
+    fn index_vertices() {
+        let (indices, positions) = Plane::new(4, 3)
+            .polygons_with_position::<E3>()
+            .index_vertices::<Tetragon<usize>, _>(HashIndexer::default());
+
+        assert_eq!(4 * 3, indices.len());
+        assert_eq!((4 + 1) * (3 + 1), positions.len());
+        assert!(positions.iter().all(|position| position.z.into_inner() == 0.0));
+    }
+
+    #[test]
+    fn position_and_uv() {
+        // Texture coordinates are the normalized positions in the $xy$-plane.
+        for (position, uv) in Plane::new(4, 3)
+            .polygons_with_position_and_uv::<Point3<f64>, Point2<f64>>()
+            .vertices()
+        {
+            assert!((position.x + 0.5 - uv.x).abs() < 1e-9);
+            assert!((position.y + 0.5 - uv.y).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn boundary() {
+        let graph: MeshGraph<E3> = Plane::new(4, 3).polygons::<Position<E3>>().collect();
+
+        assert_eq!(20, graph.vertex_count());
+        assert_eq!(12, graph.face_count());
+        assert_eq!(
+            2 * (4 + 3),
+            graph
+                .edges()
+                .filter(|edge| edge.is_boundary_edge())
+                .count()
+        );
+    }
+}
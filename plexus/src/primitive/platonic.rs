@@ -0,0 +1,373 @@
+//! Platonic solid primitives.
+//!
+//! This module provides the regular convex polyhedra other than the cube,
+//! which is provided by the [`cube`] module. The vertices of each solid lie on
+//! a sphere with the radius of its [`Bounds`] and each face is a regular
+//! polygon wound counter-clockwise when viewed from outside of the solid.
+//!
+//! | Solid            | Vertices | Edges | Faces | Polygon      |
+//! |------------------|----------|-------|-------|--------------|
+//! | [`Tetrahedron`]  | 4        | 6     | 4     | `Trigon`     |
+//! | [`Octahedron`]   | 6        | 12    | 8     | `Trigon`     |
+//! | [`Dodecahedron`] | 20       | 30    | 12    | `NGon<_, 5>` |
+//! | [`Icosahedron`]  | 12       | 30    | 20    | `Trigon`     |
+//!
+//! # Examples
+//!
+//! Generating a graph from the positional data of a dodecahedron.
+//!
+//! ```rust
+//! # extern crate decorum;
+//! # extern crate nalgebra;
+//! # extern crate plexus;
+//! #
+//! use decorum::R64;
+//! use nalgebra::Point3;
+//! use plexus::graph::MeshGraph;
+//! use plexus::prelude::*;
+//! use plexus::primitive::generate::Position;
+//! use plexus::primitive::platonic::Dodecahedron;
+//!
+//! type E3 = Point3<R64>;
+//!
+//! let graph: MeshGraph<E3> = Dodecahedron::new().polygons::<Position<E3>>().collect();
+//!
+//! assert_eq!(20, graph.vertex_count());
+//! assert_eq!(30, graph.edge_count());
+//! assert_eq!(12, graph.face_count());
+//! ```
+//!
+//! [`cube`]: crate::primitive::cube
+//! [`Bounds`]: crate::primitive::sphere::Bounds
+
+use num::{NumCast, ToPrimitive};
+use theon::space::{EuclideanSpace, FiniteDimensional, Scalar};
+use typenum::U3;
+
+use crate::primitive::generate::{
+    AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
+    IndexingPolygonGenerator, PolygonGenerator, Position,
+};
+use crate::primitive::sphere::Bounds;
+use crate::primitive::NGon;
+
+const PHI: f64 = 1.618_033_988_749_895; // Golden ratio.
+const INV_PHI: f64 = PHI - 1.0;
+
+const TETRAHEDRON_POSITIONS: [[f64; 3]; 4] = [
+    [1.0, 1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+];
+const TETRAHEDRON_FACES: [[usize; 3]; 4] = [[1, 3, 2], [0, 2, 3], [0, 3, 1], [0, 1, 2]];
+
+const OCTAHEDRON_POSITIONS: [[f64; 3]; 6] = [
+    [1.0, 0.0, 0.0],
+    [-1.0, 0.0, 0.0],
+    [0.0, 1.0, 0.0],
+    [0.0, -1.0, 0.0],
+    [0.0, 0.0, 1.0],
+    [0.0, 0.0, -1.0],
+];
+const OCTAHEDRON_FACES: [[usize; 3]; 8] = [
+    [0, 2, 4],
+    [0, 5, 2],
+    [0, 4, 3],
+    [0, 3, 5],
+    [1, 4, 2],
+    [1, 2, 5],
+    [1, 3, 4],
+    [1, 5, 3],
+];
+
+const DODECAHEDRON_POSITIONS: [[f64; 3]; 20] = [
+    [1.0, 1.0, 1.0],
+    [1.0, 1.0, -1.0],
+    [1.0, -1.0, 1.0],
+    [1.0, -1.0, -1.0],
+    [-1.0, 1.0, 1.0],
+    [-1.0, 1.0, -1.0],
+    [-1.0, -1.0, 1.0],
+    [-1.0, -1.0, -1.0],
+    [0.0, INV_PHI, PHI],
+    [0.0, INV_PHI, -PHI],
+    [0.0, -INV_PHI, PHI],
+    [0.0, -INV_PHI, -PHI],
+    [INV_PHI, PHI, 0.0],
+    [INV_PHI, -PHI, 0.0],
+    [-INV_PHI, PHI, 0.0],
+    [-INV_PHI, -PHI, 0.0],
+    [PHI, 0.0, INV_PHI],
+    [PHI, 0.0, -INV_PHI],
+    [-PHI, 0.0, INV_PHI],
+    [-PHI, 0.0, -INV_PHI],
+];
+const DODECAHEDRON_FACES: [[usize; 5]; 12] = [
+    [0, 16, 17, 1, 12],
+    [4, 14, 5, 19, 18],
+    [2, 13, 3, 17, 16],
+    [6, 18, 19, 7, 15],
+    [0, 12, 14, 4, 8],
+    [2, 10, 6, 15, 13],
+    [1, 9, 5, 14, 12],
+    [3, 13, 15, 7, 11],
+    [0, 8, 10, 2, 16],
+    [1, 17, 3, 11, 9],
+    [4, 18, 6, 10, 8],
+    [5, 9, 11, 7, 19],
+];
+
+pub(crate) const ICOSAHEDRON_POSITIONS: [[f64; 3]; 12] = [
+    [-1.0, PHI, 0.0],
+    [1.0, PHI, 0.0],
+    [-1.0, -PHI, 0.0],
+    [1.0, -PHI, 0.0],
+    [0.0, -1.0, PHI],
+    [0.0, 1.0, PHI],
+    [0.0, -1.0, -PHI],
+    [0.0, 1.0, -PHI],
+    [PHI, 0.0, -1.0],
+    [PHI, 0.0, 1.0],
+    [-PHI, 0.0, -1.0],
+    [-PHI, 0.0, 1.0],
+];
+pub(crate) const ICOSAHEDRON_FACES: [[usize; 3]; 20] = [
+    [0, 11, 5],
+    [0, 5, 1],
+    [0, 1, 7],
+    [0, 7, 10],
+    [0, 10, 11],
+    [1, 5, 9],
+    [5, 11, 4],
+    [11, 10, 2],
+    [10, 7, 6],
+    [7, 1, 8],
+    [3, 9, 4],
+    [3, 4, 2],
+    [3, 2, 6],
+    [3, 6, 8],
+    [3, 8, 9],
+    [4, 9, 5],
+    [2, 4, 11],
+    [6, 2, 10],
+    [8, 6, 7],
+    [9, 8, 1],
+];
+
+/// Regular tetrahedron formed from four triangles.
+#[derive(Clone, Copy)]
+pub struct Tetrahedron;
+
+/// Regular octahedron formed from eight triangles.
+#[derive(Clone, Copy)]
+pub struct Octahedron;
+
+/// Regular dodecahedron formed from twelve pentagons.
+///
+/// Unlike the other platonic solids, the faces of a dodecahedron are not
+/// triangles. Its polygons can be triangulated via [`Triangulate`].
+///
+/// [`Triangulate`]: crate::primitive::decompose::Triangulate
+#[derive(Clone, Copy)]
+pub struct Dodecahedron;
+
+/// Regular icosahedron formed from twenty triangles.
+///
+/// This is the unsubdivided form of an [`IcoSphere`].
+///
+/// [`IcoSphere`]: crate::primitive::icosphere::IcoSphere
+#[derive(Clone, Copy)]
+pub struct Icosahedron;
+
+macro_rules! impl_platonic_solid {
+    (solid => $s:ident, arity => $n:literal, positions => $p:ident, faces => $f:ident) => {
+        impl $s {
+            pub fn new() -> Self {
+                $s
+            }
+
+            fn vertex_with_position_from<S>(
+                &self,
+                state: &<Self as AttributeGenerator<Position<S>>>::State,
+                index: usize,
+            ) -> S
+            where
+                Self: AttributeGenerator<Position<S>, State = Bounds<S>>,
+                S: EuclideanSpace + FiniteDimensional<N = U3>,
+            {
+                let [x, y, z] = normalize($p[index]);
+                S::from_xyz(
+                    state.radius * into_scalar::<_, S>(x),
+                    state.radius * into_scalar::<_, S>(y),
+                    state.radius * into_scalar::<_, S>(z),
+                )
+            }
+        }
+
+        impl Default for $s {
+            fn default() -> Self {
+                $s::new()
+            }
+        }
+
+        impl PolygonGenerator for $s {
+            fn polygon_count(&self) -> usize {
+                $f.len()
+            }
+        }
+
+        impl<S> AttributeGenerator<Position<S>> for $s
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type State = Bounds<S>;
+        }
+
+        impl<S> AttributeVertexGenerator<Position<S>> for $s
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = S;
+
+            fn vertex_count(&self) -> usize {
+                $p.len()
+            }
+
+            fn vertex_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                self.vertex_with_position_from(state, index)
+            }
+        }
+
+        impl<S> AttributePolygonGenerator<Position<S>> for $s
+        where
+            S: EuclideanSpace + FiniteDimensional<N = U3>,
+        {
+            type Output = NGon<S, $n>;
+
+            fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+                NGon($f[index].map(|index| self.vertex_with_position_from(state, index)))
+            }
+        }
+
+        impl<S> IndexingPolygonGenerator<Position<S>> for $s {
+            type Output = NGon<usize, $n>;
+
+            fn indexing_polygon(&self, index: usize) -> Self::Output {
+                NGon($f[index])
+            }
+        }
+
+        impl Generator for $s {}
+    };
+}
+impl_platonic_solid!(
+    solid => Tetrahedron,
+    arity => 3,
+    positions => TETRAHEDRON_POSITIONS,
+    faces => TETRAHEDRON_FACES
+);
+impl_platonic_solid!(
+    solid => Octahedron,
+    arity => 3,
+    positions => OCTAHEDRON_POSITIONS,
+    faces => OCTAHEDRON_FACES
+);
+impl_platonic_solid!(
+    solid => Dodecahedron,
+    arity => 5,
+    positions => DODECAHEDRON_POSITIONS,
+    faces => DODECAHEDRON_FACES
+);
+impl_platonic_solid!(
+    solid => Icosahedron,
+    arity => 3,
+    positions => ICOSAHEDRON_POSITIONS,
+    faces => ICOSAHEDRON_FACES
+);
+
+pub(crate) fn normalize([x, y, z]: [f64; 3]) -> [f64; 3] {
+    let magnitude = ((x * x) + (y * y) + (z * z)).sqrt();
+    [x / magnitude, y / magnitude, z / magnitude]
+}
+
+fn into_scalar<T, S>(value: T) -> Scalar<S>
+where
+    T: ToPrimitive,
+    S: EuclideanSpace,
+{
+    <Scalar<S> as NumCast>::from(value).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use decorum::R64;
+    use nalgebra::Point3;
+    use std::collections::BTreeSet;
+
+    use crate::graph::MeshGraph;
+    use crate::prelude::*;
+    use crate::primitive::generate::Position;
+    use crate::primitive::platonic::{Dodecahedron, Icosahedron, Octahedron, Tetrahedron};
+
+    type E3 = Point3<R64>;
+
+    fn assert_regular(graph: MeshGraph<Point3<f64>>, counts: (usize, usize, usize), arity: usize) {
+        assert_eq!(counts, (graph.vertex_count(), graph.edge_count(), graph.face_count()));
+        assert!(graph.faces().all(|face| face.arity() == arity));
+        assert!(graph.edges().all(|edge| !edge.is_boundary_edge()));
+        // Vertices lie on the unit sphere.
+        assert!(graph
+            .vertices()
+            .all(|vertex| (1.0 - vertex.position().coords.norm()).abs() < 1e-9));
+        // Faces are wound counter-clockwise when viewed from outside.
+        assert!(graph.faces().all(|face| {
+            let centroid = face.centroid();
+            face.normal().unwrap().dot(&centroid.coords) > 0.0
+        }));
+    }
+
+    #[test]
+    fn vertex_count() {
+        assert_eq!(4, Tetrahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(6, Octahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(20, Dodecahedron::new().vertices::<Position<E3>>().count());
+        assert_eq!(12, Icosahedron::new().vertices::<Position<E3>>().count());
+    }
+
+    #[test]
+    fn position_index_to_vertex_mapping() {
+        assert_eq!(
+            20,
+            Dodecahedron::new()
+                .indexing_polygons::<Position>() // 12 pentagons, 20 indices.
+                .vertices()
+                .collect::<BTreeSet<_>>()
+                .len()
+        )
+    }
+
+    #[test]
+    fn regular_solids() {
+        assert_regular(
+            Tetrahedron::new().polygons::<Position<E3>>().collect(),
+            (4, 6, 4),
+            3,
+        );
+        assert_regular(
+            Octahedron::new().polygons::<Position<E3>>().collect(),
+            (6, 12, 8),
+            3,
+        );
+        assert_regular(
+            Dodecahedron::new().polygons::<Position<E3>>().collect(),
+            (20, 30, 12),
+            5,
+        );
+        assert_regular(
+            Icosahedron::new().polygons::<Position<E3>>().collect(),
+            (12, 30, 20),
+            3,
+        );
+    }
+}
@@ -25,17 +25,17 @@
 //!     .unwrap();
 //! ```
 
-use decorum::Real;
+use decorum::{Real, R64};
 use num::{NumCast, One, ToPrimitive};
 use std::cmp;
 use theon::adjunct::Map;
 use theon::query::Unit;
 use theon::space::{EuclideanSpace, FiniteDimensional, Scalar, Vector};
-use typenum::U3;
+use typenum::{U2, U3};
 
 use crate::primitive::generate::{
     AttributeGenerator, AttributePolygonGenerator, AttributeVertexGenerator, Generator,
-    IndexingPolygonGenerator, Normal, PolygonGenerator, Position,
+    IndexingPolygonGenerator, Normal, PolygonGenerator, Position, UvMap,
 };
 use crate::primitive::{BoundedPolygon, Tetragon, Trigon};
 
@@ -44,7 +44,7 @@ pub struct Bounds<S>
 where
     S: EuclideanSpace,
 {
-    radius: Scalar<S>,
+    pub(in crate::primitive) radius: Scalar<S>,
 }
 
 impl<S> Bounds<S>
@@ -81,13 +81,25 @@ where
 pub struct UvSphere {
     nu: usize, // Meridians.
     nv: usize, // Parallels.
+    radius: R64,
 }
 
 impl UvSphere {
     pub fn new(nu: usize, nv: usize) -> Self {
+        Self::with_radius(nu, nv, R64::one())
+    }
+
+    /// Creates a $uv$-sphere with the given radius.
+    ///
+    /// Positions are scaled by the radius, so there is no need to map over
+    /// generated positions. The radius is relative to the radius of the
+    /// [`Bounds`] used to generate positions, which is one by default.
+    /// Normals are unaffected.
+    pub fn with_radius(nu: usize, nv: usize, radius: R64) -> Self {
         UvSphere {
             nu: cmp::max(3, nu),
             nv: cmp::max(2, nv),
+            radius,
         }
     }
 
@@ -103,15 +115,30 @@ impl UvSphere {
     {
         let one = Scalar::<S>::one();
         let pi = Real::PI;
+        let radius = state.radius * into_scalar::<_, S>(self.radius.into_inner());
         let u = (into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu)) * pi * (one + one);
         let v = (into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nv)) * pi;
         S::from_xyz(
-            state.radius * u.cos() * v.sin(),
-            state.radius * u.sin() * v.sin(),
-            state.radius * v.cos(),
+            radius * u.cos() * v.sin(),
+            radius * u.sin() * v.sin(),
+            radius * v.cos(),
+        )
+    }
+
+    fn vertex_with_uv_from<S>(&self, u: usize, v: usize) -> S
+    where
+        S: EuclideanSpace + FiniteDimensional<N = U2>,
+    {
+        S::from_xy(
+            into_scalar::<_, S>(u) / into_scalar::<_, S>(self.nu),
+            into_scalar::<_, S>(v) / into_scalar::<_, S>(self.nv),
         )
     }
 
+    fn index_for_uv(&self, u: usize, v: usize) -> usize {
+        (v * (self.nu + 1)) + u
+    }
+
     fn index_for_position(&self, u: usize, v: usize) -> usize {
         if v == 0 {
             0
@@ -304,6 +331,65 @@ impl<S> IndexingPolygonGenerator<Position<S>> for UvSphere {
     }
 }
 
+impl<S> AttributeGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type State = ();
+}
+
+impl<S> AttributeVertexGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = S;
+
+    fn vertex_count(&self) -> usize {
+        (self.nu + 1) * (self.nv + 1)
+    }
+
+    fn vertex_from(&self, _: &Self::State, index: usize) -> Self::Output {
+        let n = self.nu + 1;
+        self.vertex_with_uv_from(index % n, index / n)
+    }
+}
+
+impl<S> AttributePolygonGenerator<UvMap<S>> for UvSphere
+where
+    S: EuclideanSpace + FiniteDimensional<N = U2>,
+{
+    type Output = BoundedPolygon<S>;
+
+    fn polygon_from(&self, state: &Self::State, index: usize) -> Self::Output {
+        IndexingPolygonGenerator::<UvMap<S>>::indexing_polygon(self, index)
+            .map(|index| AttributeVertexGenerator::<UvMap<S>>::vertex_from(self, state, index))
+    }
+}
+
+impl<S> IndexingPolygonGenerator<UvMap<S>> for UvSphere {
+    type Output = BoundedPolygon<usize>;
+
+    fn indexing_polygon(&self, index: usize) -> Self::Output {
+        // Unlike positions, texture coordinates do not wrap at the seam where
+        // `u` is zero and one, and the poles have a distinct texture
+        // coordinate for each meridian.
+        let (u, v) = self.map_polygon_index(index);
+        let (p, q) = (u + 1, v + 1);
+
+        let low = self.index_for_uv(u, v);
+        let high = self.index_for_uv(p, q);
+        if v == 0 {
+            Trigon::new(low, self.index_for_uv(u, q), high).into()
+        }
+        else if v == self.nv - 1 {
+            Trigon::new(self.index_for_uv(u, q), self.index_for_uv(p, v), low).into()
+        }
+        else {
+            Tetragon::new(low, self.index_for_uv(u, q), high, self.index_for_uv(p, v)).into()
+        }
+    }
+}
+
 impl Generator for UvSphere {}
 
 fn into_scalar<T, S>(value: T) -> Scalar<S>
@@ -316,12 +402,15 @@ where
 
 #[cfg(test)]
 mod tests {
-    use nalgebra::Point3;
+    use decorum::R64;
+    use nalgebra::{Point2, Point3};
     use std::collections::BTreeSet;
 
+    use crate::index::HashIndexer;
     use crate::prelude::*;
     use crate::primitive::generate::Position;
     use crate::primitive::sphere::UvSphere;
+    use crate::primitive::BoundedPolygon;
 
     type E3 = Point3<f64>;
 
@@ -346,6 +435,14 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_radius() {
+        let sphere = UvSphere::with_radius(8, 4, R64::from_inner(2.0));
+        for position in sphere.vertices::<Position<E3>>() {
+            assert!((2.0 - position.coords.norm()).abs() < 1e-9);
+        }
+    }
+
     #[test]
     fn position_index_to_vertex_mapping() {
         assert_eq!(
@@ -357,4 +454,34 @@ mod tests {
                 .len()
         )
     }
+
+    #[test]
+    fn uv_seam() {
+        let sphere = UvSphere::new(8, 4);
+        let (_, vertices) = sphere
+            .polygons_with_position_and_uv::<Point3<R64>, Point2<R64>>()
+            .index_vertices::<BoundedPolygon<usize>, _>(HashIndexer::default());
+        let positions = sphere
+            .polygons::<Position<Point3<R64>>>()
+            .index_vertices::<BoundedPolygon<usize>, _>(HashIndexer::default())
+            .1;
+
+        // Vertices are split along the seam and at the poles.
+        assert_eq!(26, positions.len());
+        assert_eq!(43, vertices.len());
+        assert!(vertices.iter().all(|(_, uv)| {
+            let (u, v) = (uv.x.into_inner(), uv.y.into_inner());
+            (0.0..=1.0).contains(&u) && (0.0..=1.0).contains(&v)
+        }));
+    }
+
+    #[test]
+    fn position_and_normal() {
+        for (position, normal) in UvSphere::new(8, 4)
+            .polygons_with_position_and_normal::<E3>()
+            .vertices()
+        {
+            assert!((position.coords.normalize() - normal.get()).norm() < 1e-9);
+        }
+    }
 }